@@ -1,3 +1,4 @@
+use crate::bounds::Bounds;
 use leptos::prelude::*;
 
 /// Calculates the width and height of a chart.
@@ -158,6 +159,13 @@ impl EnvCalc {
 }
 
 impl KnownAspectRatio {
+    /// Bounds of a chart without edges where the inner and outer areas are the same.
+    pub fn bounds(&self) -> Bounds {
+        match self {
+            Self::Inner(vars) | Self::Outer(vars) => Bounds::new(vars.width(), vars.height()),
+        }
+    }
+
     pub fn inner_width_signal(known: Memo<Self>, left: Memo<f64>, right: Memo<f64>) -> Memo<f64> {
         Memo::new(move |_| match known.get() {
             Self::Inner(vars) => vars.width(),
@@ -0,0 +1,343 @@
+use crate::{
+    chart::{FONT_HEIGHT, FONT_WIDTH},
+    ticks::format_value,
+    Colour, ColourScheme, Period, HEATMAP_GRADIENT,
+};
+use chrono::{prelude::*, Duration};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// Default size of a calendar day in pixels.
+pub const CALENDAR_CELL_SIZE: f64 = 12.0;
+/// Default gap ratio between calendar days.
+pub const CALENDAR_GAP: f64 = 0.2;
+/// Default colour of days without a value.
+pub const CALENDAR_EMPTY_COLOUR: Colour = Colour::from_rgb(0xEB, 0xED, 0xF0);
+/// Minimum number of weeks between month labels.
+const MONTH_LABEL_WEEKS: usize = 3;
+
+/// Describes how to draw a [CalendarHeatmap].
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # use chrono::Weekday;
+/// let calendar = Calendar::default()
+///     .with_gradient(LINEAR_GRADIENT)
+///     .with_week_start(Weekday::Sun);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Calendar {
+    /// Colour scheme that day values are mapped through. Default is [HEATMAP_GRADIENT].
+    pub gradient: RwSignal<ColourScheme>,
+    /// Colour of days without a value. Default is [CALENDAR_EMPTY_COLOUR].
+    pub empty_colour: RwSignal<Colour>,
+    /// Size of each day in pixels. Default is [CALENDAR_CELL_SIZE].
+    pub cell_size: RwSignal<f64>,
+    /// Gap between days. Clamped to 0.0 and 1.0. Default is [CALENDAR_GAP].
+    pub gap: RwSignal<f64>,
+    /// First day of each week (the top row). Default is Monday.
+    pub week_start: RwSignal<Weekday>,
+}
+
+impl Calendar {
+    /// Sets the colour scheme that day values are mapped through e.g., a [SequentialGradient](crate::SequentialGradient).
+    pub fn with_gradient(self, gradient: impl Into<ColourScheme>) -> Self {
+        self.gradient.set(gradient.into());
+        self
+    }
+
+    /// Sets the colour of days without a value.
+    pub fn with_empty_colour(self, colour: impl Into<Colour>) -> Self {
+        self.empty_colour.set(colour.into());
+        self
+    }
+
+    /// Sets the size of each day in pixels.
+    pub fn with_cell_size(self, size: f64) -> Self {
+        self.cell_size.set(size);
+        self
+    }
+
+    /// Sets the gap between days. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Sets the first day of each week.
+    pub fn with_week_start(self, week_start: Weekday) -> Self {
+        self.week_start.set(week_start);
+        self
+    }
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            gradient: RwSignal::new(HEATMAP_GRADIENT.into()),
+            empty_colour: RwSignal::new(CALENDAR_EMPTY_COLOUR),
+            cell_size: RwSignal::new(CALENDAR_CELL_SIZE),
+            gap: RwSignal::new(CALENDAR_GAP),
+            week_start: RwSignal::new(Weekday::Mon),
+        }
+    }
+}
+
+/// A day of the calendar grid.
+#[derive(Clone, Debug, PartialEq)]
+struct Day {
+    date: NaiveDate,
+    /// Column of the grid.
+    week: usize,
+    /// Row of the grid. Days since the start of the week.
+    weekday: usize,
+    /// Sum of the day's values. None if the day has no values.
+    value: Option<f64>,
+}
+
+/// Days of a calendar laid out as a week by weekday grid.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Grid {
+    weeks: usize,
+    days: Vec<Day>,
+    /// Week (column) that each labelled month starts on.
+    months: Vec<(usize, NaiveDate)>,
+    /// (min, max) of the day values.
+    range: (f64, f64),
+}
+
+/// Lays out every day from the first to the last timestamp. Values on the same day are summed.
+fn grid<Tz: TimeZone>(data: &[(DateTime<Tz>, f64)], week_start: Weekday) -> Grid {
+    let (Some(first), Some(last)) = (
+        data.iter().map(|(at, _)| at).min(),
+        data.iter().map(|(at, _)| at).max(),
+    ) else {
+        return Grid::default();
+    };
+    let (Some(from), Some(to)) = (
+        Period::Day.truncate_at(first.clone()),
+        Period::Day.truncate_at(last.clone()),
+    ) else {
+        return Grid::default();
+    };
+    let to = to + Period::Day;
+
+    // Sum values by day
+    let mut values = HashMap::<NaiveDate, f64>::new();
+    for (at, value) in data {
+        if value.is_finite() {
+            *values.entry(at.date_naive()).or_default() += value;
+        }
+    }
+
+    // The grid starts on the week containing the first day
+    let first_day = from.date_naive();
+    let offset =
+        (first_day.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday()) % 7;
+    let grid_start = first_day - Duration::days(offset as i64);
+    let place = |date: NaiveDate| {
+        let days = (date - grid_start).num_days() as usize;
+        (days / 7, days % 7)
+    };
+
+    let days = (Period::Day.iter_aligned_range(from.clone(), to.clone()))
+        .map(|at| at.date_naive())
+        .map(|date| {
+            let (week, weekday) = place(date);
+            Day {
+                date,
+                week,
+                weekday,
+                value: values.get(&date).copied(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let weeks = days.last().map_or(0, |day| day.week + 1);
+
+    // Label month starts. Label the first (partial) month too if there's room
+    let mut months = (Period::Month.iter_aligned_range(from, to))
+        .map(|at| (place(at.date_naive()).0, at.date_naive()))
+        .collect::<Vec<_>>();
+    if months
+        .first()
+        .is_none_or(|&(week, _)| week >= MONTH_LABEL_WEEKS)
+    {
+        months.insert(0, (0, first_day));
+    }
+
+    let range = (values.values().copied()).fold((f64::NAN, f64::NAN), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+    Grid {
+        weeks,
+        days,
+        months,
+        range,
+    }
+}
+
+/// Renders a calendar heatmap: days laid out in a week by weekday grid (like a contributions graph) and coloured by value. Drawn at a fixed size from the [cell size](Calendar::cell_size).
+///
+/// Each (timestamp, value) is summed into its day in the timestamp's time zone. Every day from the first to the last timestamp is drawn. Months are labelled along the top and every other weekday along the left. Hovering a day shows its date and value.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+/// use chrono::prelude::*;
+///
+/// # #[component]
+/// # fn CalendarComponent() -> impl IntoView {
+/// let deploys: Signal<Vec<(DateTime<Utc>, f64)>> = Signal::default();
+/// view! {
+///     <CalendarHeatmap calendar=Calendar::default() data=deploys />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn CalendarHeatmap<Tz>(
+    /// How to draw the calendar. See [Calendar] for details.
+    #[prop(into, optional)]
+    calendar: Calendar,
+    /// The height of the font used for labels. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<Signal<f64>>,
+    /// The width of a monospaced character in the font used for labels. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<Signal<f64>>,
+    /// Values to draw. Need not be sorted.
+    #[prop(into)]
+    data: Signal<Vec<(DateTime<Tz>, f64)>>,
+) -> impl IntoView
+where
+    Tz: TimeZone + Send + Sync + 'static,
+    Tz::Offset: Send + Sync,
+{
+    let font_height = Memo::new(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
+    let font_width = Memo::new(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
+    let week_start = calendar.week_start;
+    let grid = Memo::new(move |_| data.with(|data| grid(data, week_start.get())));
+
+    // Room for labels: three letter weekdays on the left and months on the top
+    let left = Signal::derive(move || font_width.get() * 4.0);
+    let top = Signal::derive(move || font_height.get() * 1.25);
+    let cell = calendar.cell_size;
+    let width = Signal::derive(move || left.get() + grid.read().weeks as f64 * cell.get());
+    let height = Signal::derive(move || top.get() + 7.0 * cell.get());
+
+    let days = {
+        let calendar = calendar.clone();
+        move || {
+            let (left, top, cell) = (left.get(), top.get(), cell.get());
+            let size = cell * (1.0 - calendar.gap.get().clamp(0.0, 1.0));
+            let empty = calendar.empty_colour.get();
+            let grid = grid.get();
+            calendar.gradient.with(|gradient| {
+                (grid.days.into_iter())
+                    .map(|day| {
+                        let fill = day
+                            .value
+                            .map_or(empty, |value| gradient.by_value(value, grid.range));
+                        let value = day.value.map(format_value).unwrap_or("-".to_string());
+                        view! {
+                            <rect
+                                x=left + day.week as f64 * cell
+                                y=top + day.weekday as f64 * cell
+                                width=size
+                                height=size
+                                fill=fill.to_string()>
+                                <title>{format!("{}: {value}", day.date)}</title>
+                            </rect>
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    };
+
+    let months = move || {
+        let (left, cell) = (left.get(), cell.get());
+        (grid.get().months.into_iter())
+            .map(|(week, date)| {
+                view! {
+                    <text x=left + week as f64 * cell y=0.0 dominant-baseline="hanging">
+                        {date.format("%b").to_string()}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    let weekdays = move || {
+        let (top, cell) = (top.get(), cell.get());
+        let week_start = week_start.get();
+        // Every other day from the second
+        [1, 3, 5]
+            .map(|row| {
+                let weekday = (0..row).fold(week_start, |day, _| day.succ());
+                view! {
+                    <text x=0.0 y=top + (row as f64 + 0.5) * cell dominant-baseline="middle">
+                        {weekday.to_string()}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <svg
+            class="_chartistry_calendar"
+            width=move || format!("{}px", width.get())
+            height=move || format!("{}px", height.get())
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            style="display: block; overflow: visible;">
+            <g class="_chartistry_calendar_labels" font-size=move || cell.get().min(font_height.get())>
+                {months}
+                {weekdays}
+            </g>
+            <g class="_chartistry_calendar_days">{days}</g>
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        let data = [
+            // Wednesday
+            (at(1, 31, 9), 2.0),
+            (at(1, 31, 17), 3.0),
+            (at(2, 2, 0), f64::NAN),
+            (at(2, 5, 12), 1.0),
+            // Monday
+            (at(2, 26, 0), 7.0),
+        ];
+        let grid = grid(&data, Weekday::Mon);
+        // Grid starts on Monday 29th of January
+        assert_eq!(grid.weeks, 5);
+        assert_eq!(grid.days.len(), 27);
+        let first = &grid.days[0];
+        assert_eq!((first.week, first.weekday, first.value), (0, 2, Some(5.0)));
+        assert_eq!(grid.days[2].value, None);
+        let last = grid.days.last().unwrap();
+        assert_eq!((last.week, last.weekday, last.value), (4, 0, Some(7.0)));
+        assert_eq!(grid.range, (1.0, 7.0));
+        // February starts in the first week so January isn't labelled
+        let february = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        assert_eq!(grid.months, vec![(0, february)]);
+
+        // Weeks starting on Sunday
+        let grid = super::grid(&data, Weekday::Sun);
+        assert_eq!(grid.days[0].weekday, 3);
+        assert_eq!(grid.days.last().unwrap().weekday, 1);
+
+        // Nothing to show
+        assert_eq!(super::grid::<Utc>(&[], Weekday::Mon), Grid::default());
+    }
+}
@@ -3,12 +3,12 @@ use crate::{
     debug::DebugRect,
     inner::InnerLayout,
     layout::{EdgeLayout, Layout},
-    overlay::tooltip::Tooltip,
+    overlay::{brush::Brush, tooltip::Tooltip, zoom::Zoom},
     projection::Projection,
-    series::{RenderData, UseData},
+    series::{Range, RenderData, UseData},
     state::{PreState, State},
     use_watched_node::{use_watched_node, UseWatchedNode},
-    AspectRatio, Padding, Series, Tick,
+    AspectRatio, Orientation, Padding, Scale, Series, Tick,
 };
 use leptos::{html::Div, prelude::*};
 
@@ -165,6 +165,13 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     #[prop(into, optional)]
     tooltip: Tooltip<X, Y>,
 
+    /// Zoom and pan interaction on the inner chart area. Drag to zoom in to a range, hold shift and drag to pan (a plain drag selects), scroll to zoom around the cursor, and double-click to reset. See [Zoom](crate::Zoom) for details. Default is none.
+    #[prop(into, optional)]
+    zoom: Option<Zoom<X>>,
+    /// Range selector over all data, turning this chart into an overview. See [Brush](crate::Brush) for details. Default is none.
+    #[prop(into, optional)]
+    brush: Option<Brush<X>>,
+
     /// Series to render. Maps `T` to lines, bars, etc. See [Series] for details.
     #[prop(into)]
     series: Series<T, X, Y>,
@@ -172,14 +179,124 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     #[prop(into)]
     data: Signal<Vec<T>>,
 ) -> impl IntoView {
-    let root = NodeRef::<Div>::new();
-    let watch = use_watched_node(root);
+    let env = ChartEnv::new(aspect_ratio, font_height, font_width, debug, padding);
+    let (top, left) = layout_order(top, left);
+
+    // Build data
+    let x_window = zoom.as_ref().map(|zoom| zoom.range);
+    let x_window = Signal::derive(move || x_window.and_then(|window| window.get()));
+    let data = UseData::new(series, data, x_window);
+    let pre = env.pre_state(data);
+
+    view! {
+        <ChartShell env=env.clone() label="Chart">
+            <RenderChart
+                watch=env.watch.clone()
+                pre_state=pre.clone()
+                aspect_ratio=env.calc
+                top=top.clone()
+                right=right.clone()
+                bottom=bottom.clone()
+                left=left.clone()
+                inner=inner.clone()
+                tooltip=tooltip.clone()
+                zoom=zoom.clone()
+                brush=brush.clone()
+            />
+        </ChartShell>
+    }
+}
+
+/// Signals shared by chart components: the watched root node, its known dimensions, and the font, padding, and debug props with defaults applied. Rendered by [ChartShell].
+#[derive(Clone)]
+pub(crate) struct ChartEnv {
+    root: NodeRef<Div>,
+    aspect_ratio: Signal<AspectRatio>,
+    pub watch: UseWatchedNode,
+    /// Aspect ratio calculated from the root node's dimensions.
+    pub calc: Memo<KnownAspectRatio>,
+    pub debug: Memo<bool>,
+    pub font_height: Memo<f64>,
+    pub font_width: Memo<f64>,
+    pub padding: Memo<Padding>,
+}
+
+impl ChartEnv {
+    /// Applies defaults to a chart component's common props.
+    pub fn new(
+        aspect_ratio: Signal<AspectRatio>,
+        font_height: Option<Signal<f64>>,
+        font_width: Option<Signal<f64>>,
+        debug: Signal<bool>,
+        padding: Option<Signal<Padding>>,
+    ) -> Self {
+        let root = NodeRef::<Div>::new();
+        let watch = use_watched_node(root);
+
+        // Aspect ratio signal
+        let width = Memo::new(move |_| watch.bounds.get().unwrap_or_default().width());
+        let height = Memo::new(move |_| watch.bounds.get().unwrap_or_default().height());
+        let calc = AspectRatio::known_signal(aspect_ratio, width, height);
+
+        let debug = Memo::new(move |_| debug.get());
+        let font_height = Memo::new(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
+        let font_width = Memo::new(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
+        let padding = Memo::new(move |_| {
+            padding
+                .map(|p| p.get())
+                .unwrap_or_else(move || Padding::from(font_width.get()))
+        });
+
+        Self {
+            root,
+            aspect_ratio,
+            watch,
+            calc,
+            debug,
+            font_height,
+            font_width,
+            padding,
+        }
+    }
 
-    // Aspect ratio signal
+    /// Builds the state that edges are composed from.
+    pub fn pre_state<X: Tick, Y: Tick>(&self, data: UseData<X, Y>) -> PreState<X, Y> {
+        PreState::new(
+            self.debug.into(),
+            self.font_height,
+            self.font_width,
+            self.padding.into(),
+            data,
+        )
+    }
+}
+
+/// Edges are added top to bottom, left to right. Layout composes inside out so reverses the top and left edges.
+pub(crate) fn layout_order<X: Tick, Y: Tick>(
+    mut top: Vec<EdgeLayout<X>>,
+    mut left: Vec<EdgeLayout<Y>>,
+) -> (Vec<EdgeLayout<X>>, Vec<EdgeLayout<Y>>) {
+    top.reverse();
+    left.reverse();
+    (top, left)
+}
+
+/// Root element of a chart component. Watches its size and renders its children once it's known.
+#[component]
+pub(crate) fn ChartShell(
+    env: ChartEnv,
+    /// Debug label of the chart.
+    label: &'static str,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let ChartEnv {
+        root,
+        aspect_ratio,
+        watch,
+        debug,
+        ..
+    } = env;
     let have_dimensions = Memo::new(move |_| watch.bounds.get().is_some());
-    let width = Memo::new(move |_| watch.bounds.get().unwrap_or_default().width());
-    let height = Memo::new(move |_| watch.bounds.get().unwrap_or_default().height());
-    let calc = AspectRatio::known_signal(aspect_ratio, width, height);
     let env_size = move || {
         if aspect_ratio.get().is_env() {
             "100%"
@@ -187,26 +304,6 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
             "fit-content"
         }
     };
-
-    let debug = Memo::new(move |_| debug.get());
-    let font_height = Memo::new(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
-    let font_width = Memo::new(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
-    let padding = Memo::new(move |_| {
-        padding
-            .map(|p| p.get())
-            .unwrap_or_else(move || Padding::from(font_width.get()))
-    });
-
-    // Edges are added top to bottom, left to right. Layout compoeses inside out:
-    let mut top = top;
-    let mut left = left;
-    top.reverse();
-    left.reverse();
-
-    // Build data
-    let data = UseData::new(series, data);
-    let pre = PreState::new(debug.into(), font_height, font_width, padding.into(), data);
-
     view! {
         <div
             node_ref=root
@@ -214,19 +311,9 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
             style:width=env_size
             style:height=env_size
             style="overflow: visible;">
-            <DebugRect label="Chart" debug=debug />
+            <DebugRect label=label debug=debug />
             <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
-                <RenderChart
-                    watch=watch.clone()
-                    pre_state=pre.clone()
-                    aspect_ratio=calc
-                    top=top.clone()
-                    right=right.clone()
-                    bottom=bottom.clone()
-                    left=left.clone()
-                    inner=inner.clone()
-                    tooltip=tooltip.clone()
-                />
+                {children()}
             </Show>
         </div>
     }
@@ -243,6 +330,8 @@ fn RenderChart<X: Tick, Y: Tick>(
     left: Vec<EdgeLayout<Y>>,
     inner: Vec<InnerLayout<X, Y>>,
     tooltip: Tooltip<X, Y>,
+    zoom: Option<Zoom<X>>,
+    brush: Option<Brush<X>>,
 ) -> impl IntoView {
     let debug = pre_state.debug;
 
@@ -250,22 +339,41 @@ fn RenderChart<X: Tick, Y: Tick>(
     let (layout, edges) = Layout::compose(&top, &right, &bottom, &left, aspect_ratio, &pre_state);
 
     // Finalise state
-    let projection = {
+    let mk_projection = |range_y: Memo<Range<Y>>, y_scale: RwSignal<Scale>| {
         let range_x = pre_state.data.range_x;
-        let range_y = pre_state.data.range_y;
         let includes_bars = pre_state.data.includes_bars;
+        let orientation = pre_state.data.orientation;
+        let x_scale = pre_state.data.x_scale;
         Memo::new(move |_| {
             let mut inner = layout.inner.get();
-            // If we include bars, shrink the sides by half the width of X
-            if includes_bars.get() {
-                let half = layout.x_width.get() / 2.0;
-                inner = inner.shrink(0.0, half, 0.0, half);
+            let range_x = (range_x.get().positions(), x_scale.get());
+            let range_y = (range_y.get().positions(), y_scale.get());
+            // If we include bars, shrink the sides of the X axis by half the width of X
+            let half = if includes_bars.get() {
+                layout.x_width.get() / 2.0
+            } else {
+                0.0
+            };
+            // Same for Y bands e.g., heatmap rows
+            let half_y = layout.y_width.get() / 2.0;
+            match orientation {
+                Orientation::Vertical => {
+                    inner = inner.shrink(half_y, half, half_y, half);
+                    Projection::scaled(inner, false, range_x, range_y)
+                }
+                Orientation::Horizontal => {
+                    inner = inner.shrink(half, half_y, half, half_y);
+                    Projection::scaled(inner, true, range_x, range_y)
+                }
             }
-
-            Projection::new(inner, range_x.get().positions(), range_y.get().positions())
         })
     };
-    let state = State::new(pre_state, &watch, layout, projection);
+    let projection = mk_projection(pre_state.data.range_y, pre_state.data.y_scale);
+    let secondary_projection = mk_projection(
+        pre_state.data.secondary_range_y,
+        pre_state.data.secondary_y_scale,
+    );
+    let state = State::new(pre_state, &watch, layout, projection, secondary_projection);
 
     // Render edges
     let edges = edges
@@ -291,6 +399,8 @@ fn RenderChart<X: Tick, Y: Tick>(
             {inner}
             {edges}
             <RenderData state=state.clone() />
+            {zoom.map(|zoom| view!(<Zoom zoom=zoom state=state.clone() />))}
+            {brush.map(|brush| view!(<Brush brush=brush state=state.clone() />))}
         </svg>
         <Tooltip tooltip=tooltip state=state />
     }
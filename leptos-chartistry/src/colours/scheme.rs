@@ -104,6 +104,39 @@ impl ColourScheme {
 
         before.interpolate(after, ratio)
     }
+
+    /// Interpolate a colour from a ratio (0.0 to 1.0) along the whole scheme. Useful for mapping a value to a colour. The ratio is clamped and NaN maps to the first colour.
+    pub fn by_ratio(&self, ratio: f64) -> Colour {
+        let last = self.swatches.len() - 1;
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        let position = ratio * last as f64;
+        let before = position.floor() as usize;
+        if before >= last {
+            return self.swatches[last];
+        }
+        self.swatches[before].interpolate(self.swatches[before + 1], position - before as f64)
+    }
+
+    /// Interpolate a colour from a value in a (min, max) range. Diverging gradients map zero to their central colour when the range crosses zero. Otherwise the same as [by_ratio](Self::by_ratio).
+    pub fn by_value(&self, value: f64, (min, max): (f64, f64)) -> Colour {
+        match self.zero {
+            Some(zero) if min < 0.0 && max > 0.0 => {
+                // Ratio of the central colour
+                let centre = zero as f64 / (self.swatches.len() - 1).max(1) as f64;
+                let ratio = if value < 0.0 {
+                    centre * (1.0 - value / min)
+                } else {
+                    centre + (1.0 - centre) * value / max
+                };
+                self.by_ratio(ratio)
+            }
+            _ => self.by_ratio((value - min) / (max - min)),
+        }
+    }
 }
 
 #[component]
@@ -290,6 +323,51 @@ mod tests {
         assert_eq!(scheme10.line_to_prior_swatch_index(1, 0), 0);
     }
 
+    #[test]
+    fn test_by_ratio() {
+        let scheme3 = scheme3();
+        assert_eq!(scheme3.by_ratio(0.0), Colour::from_rgb(0, 0, 0));
+        assert_eq!(scheme3.by_ratio(0.25), Colour::from_rgb(128, 128, 128));
+        assert_eq!(scheme3.by_ratio(0.5), Colour::from_rgb(255, 255, 255));
+        assert_eq!(scheme3.by_ratio(1.0), Colour::from_rgb(0, 0, 0));
+        // Out of range
+        assert_eq!(scheme3.by_ratio(-1.0), Colour::from_rgb(0, 0, 0));
+        assert_eq!(scheme3.by_ratio(2.0), Colour::from_rgb(0, 0, 0));
+        assert_eq!(scheme3.by_ratio(f64::NAN), Colour::from_rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn test_by_value() {
+        let scheme3 = scheme3();
+        assert_eq!(
+            scheme3.by_value(5.0, (0.0, 20.0)),
+            Colour::from_rgb(128, 128, 128)
+        );
+        assert_eq!(scheme3.by_value(1.0, (1.0, 1.0)), Colour::from_rgb(0, 0, 0));
+        // Zero is centred on a diverging gradient
+        let black = ColourScheme::from([Colour::from_rgb(0, 0, 0)]);
+        let white =
+            ColourScheme::from([Colour::from_rgb(255, 255, 255), Colour::from_rgb(0, 0, 0)]);
+        let diverging = ColourScheme::diverging_gradient(black, white);
+        assert_eq!(
+            diverging.by_value(0.0, (-1.0, 10.0)),
+            Colour::from_rgb(255, 255, 255)
+        );
+        assert_eq!(
+            diverging.by_value(-1.0, (-1.0, 10.0)),
+            Colour::from_rgb(0, 0, 0)
+        );
+        assert_eq!(
+            diverging.by_value(5.0, (-1.0, 10.0)),
+            Colour::from_rgb(128, 128, 128)
+        );
+        // Range doesn't cross zero
+        assert_eq!(
+            diverging.by_value(5.0, (0.0, 10.0)),
+            Colour::from_rgb(255, 255, 255)
+        );
+    }
+
     #[test]
     fn test_scheme_interpolation() {
         let scheme3 = scheme3();
@@ -1,6 +1,6 @@
 use crate::{
-    colours::Colour, debug::DebugRect, projection::Projection, state::State, ticks::GeneratedTicks,
-    Tick, TickLabels,
+    bounds::Bounds, colours::Colour, debug::DebugRect, projection::Projection, state::State,
+    ticks::GeneratedTicks, Orientation, Tick, TickLabels,
 };
 use leptos::prelude::*;
 
@@ -78,11 +78,17 @@ impl_use_grid_line!(UseYGridLine);
 impl<X: Tick> XGridLine<X> {
     pub(crate) fn use_horizontal<Y: Tick>(self, state: &State<X, Y>) -> UseXGridLine<X> {
         let inner = state.layout.inner;
-        let avail_width = Signal::derive(move || inner.with(|inner| inner.width()));
+        let orientation = state.pre.data.orientation;
+        let avail = Signal::derive(move || {
+            inner.with(|inner| match orientation {
+                Orientation::Vertical => inner.width(),
+                Orientation::Horizontal => inner.height(),
+            })
+        });
         UseXGridLine {
             width: self.width,
             colour: self.colour,
-            ticks: self.ticks.generate_x(&state.pre, avail_width),
+            ticks: self.ticks.generate_x(&state.pre, avail),
         }
     }
 }
@@ -90,11 +96,17 @@ impl<X: Tick> XGridLine<X> {
 impl<Y: Tick> YGridLine<Y> {
     pub(crate) fn use_vertical<X: Tick>(self, state: &State<X, Y>) -> UseYGridLine<Y> {
         let inner = state.layout.inner;
-        let avail_height = Signal::derive(move || inner.with(|inner| inner.height()));
+        let orientation = state.pre.data.orientation;
+        let avail = Signal::derive(move || {
+            inner.with(|inner| match orientation {
+                Orientation::Vertical => inner.height(),
+                Orientation::Horizontal => inner.width(),
+            })
+        });
         UseYGridLine {
             width: self.width,
             colour: self.colour,
-            ticks: self.ticks.generate_y(&state.pre, avail_height),
+            ticks: self.ticks.generate_y(&state.pre, avail),
         }
     }
 }
@@ -110,16 +122,16 @@ pub(super) fn XGridLine<X: Tick, Y: Tick>(
     let colour = line.colour;
 
     let lines = move || {
-        for_ticks(line.ticks, proj, true)
+        for_ticks(line.ticks, proj, inner, true)
             .into_iter()
-            .map(|(x, label)| {
+            .map(|(line, label)| {
                 view! {
                     <DebugRect label=format!("grid_line_x/{}", label) debug=debug />
                     <line
-                        x1=x
-                        y1=move || inner.get().top_y()
-                        x2=x
-                        y2=move || inner.get().bottom_y() />
+                        x1=line.left_x()
+                        y1=line.top_y()
+                        x2=line.right_x()
+                        y2=line.bottom_y() />
                 }
             })
             .collect_view()
@@ -147,16 +159,16 @@ pub(super) fn YGridLine<X: Tick, Y: Tick>(
     let colour = line.colour;
 
     let lines = move || {
-        for_ticks(line.ticks, proj, false)
+        for_ticks(line.ticks, proj, inner, false)
             .into_iter()
-            .map(|(y, label)| {
+            .map(|(line, label)| {
                 view! {
                     <DebugRect label=format!("grid_line_y/{}", label) debug=debug />
                     <line
-                        x1=move || inner.get().left_x()
-                        y1=y
-                        x2=move || inner.get().right_x()
-                        y2=y />
+                        x1=line.left_x()
+                        y1=line.top_y()
+                        x2=line.right_x()
+                        y2=line.bottom_y() />
                 }
             })
             .collect_view()
@@ -173,25 +185,35 @@ pub(super) fn YGridLine<X: Tick, Y: Tick>(
     }
 }
 
+/// Returns a line across the inner chart for each tick.
 fn for_ticks<XY: Tick>(
     ticks: Memo<GeneratedTicks<XY>>,
     proj: Memo<Projection>,
+    inner: Memo<Bounds>,
     is_x: bool,
-) -> Vec<(f64, String)> {
+) -> Vec<(Bounds, String)> {
     ticks.with(move |ticks| {
         let proj = proj.get();
+        let inner = inner.get();
+        // X lines are vertical unless the chart is horizontal
+        let vertical = is_x != proj.is_horizontal();
         ticks
             .ticks
             .iter()
             .map(|tick| {
                 let label = ticks.state.format(tick);
                 let tick = tick.position();
-                let tick = if is_x {
-                    proj.position_to_svg(tick, 0.0).0
+                let svg = if is_x {
+                    proj.x_to_svg(tick)
+                } else {
+                    proj.y_to_svg(tick)
+                };
+                let line = if vertical {
+                    Bounds::from_points(svg, inner.top_y(), svg, inner.bottom_y())
                 } else {
-                    proj.position_to_svg(0.0, tick).1
+                    Bounds::from_points(inner.left_x(), svg, inner.right_x(), svg)
                 };
-                (tick, label)
+                (line, label)
             })
             .collect::<Vec<_>>()
     })
@@ -1,4 +1,4 @@
-use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Orientation, Tick};
 use leptos::prelude::*;
 use std::str::FromStr;
 
@@ -119,17 +119,24 @@ pub(super) fn XGuideLine<X: Tick, Y: Tick>(
     let nearest_svg_x = Memo::new(move |_| {
         nearest_pos_x
             .get()
-            .map(|pos_x| state.projection.get().position_to_svg(pos_x, 0.0).0)
+            .map(|pos_x| state.projection.get().x_to_svg(pos_x))
     });
 
+    let horizontal = state.pre.data.orientation == Orientation::Horizontal;
     let pos = Signal::derive(move || {
-        let (mouse_x, _) = mouse_chart.get();
-        let x = match line.align.get() {
-            AlignOver::Data => nearest_svg_x.get().unwrap_or(mouse_x),
-            AlignOver::Mouse => mouse_x,
+        let (mouse_x, mouse_y) = mouse_chart.get();
+        // Horizontal charts draw X top to bottom
+        let mouse = if horizontal { mouse_y } else { mouse_x };
+        let svg = match line.align.get() {
+            AlignOver::Data => nearest_svg_x.get().unwrap_or(mouse),
+            AlignOver::Mouse => mouse,
         };
         let inner = inner.get();
-        Bounds::from_points(x, inner.top_y(), x, inner.bottom_y())
+        if horizontal {
+            Bounds::from_points(inner.left_x(), svg, inner.right_x(), svg)
+        } else {
+            Bounds::from_points(svg, inner.top_y(), svg, inner.bottom_y())
+        }
     });
 
     view! {
@@ -146,10 +153,16 @@ pub(super) fn YGuideLine<X: Tick, Y: Tick>(
     let inner = state.layout.inner;
     let mouse_chart = state.mouse_chart;
     // TODO align over
+    let horizontal = state.pre.data.orientation == Orientation::Horizontal;
     let pos = Signal::derive(move || {
-        let (_, mouse_y) = mouse_chart.get();
+        let (mouse_x, mouse_y) = mouse_chart.get();
         let inner = inner.get();
-        Bounds::from_points(inner.left_x(), mouse_y, inner.right_x(), mouse_y)
+        // Horizontal charts draw Y left to right
+        if horizontal {
+            Bounds::from_points(mouse_x, inner.top_y(), mouse_x, inner.bottom_y())
+        } else {
+            Bounds::from_points(inner.left_x(), mouse_y, inner.right_x(), mouse_y)
+        }
     });
     view! {
         <GuideLine id="y" width=line.width colour=line.colour state=state pos=pos />
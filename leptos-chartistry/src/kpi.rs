@@ -0,0 +1,392 @@
+use crate::{
+    pie::slice_path, projection::turn_point, ticks::format_value, Colour, ColourScheme,
+    SERIES_COLOUR_SCHEME,
+};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default colour scheme of qualitative bands. Dark (e.g., poor) to light (e.g., good).
+pub const KPI_BAND_COLOUR_SCHEME: [Colour; 2] = [
+    Colour::from_rgb(0xA3, 0xA3, 0xA3),
+    Colour::from_rgb(0xE5, 0xE5, 0xE5),
+];
+/// Default colour of a KPI's value. The first colour of the [series colour scheme](SERIES_COLOUR_SCHEME).
+pub const KPI_VALUE_COLOUR: Colour = SERIES_COLOUR_SCHEME[0];
+/// Default colour of a KPI's target marker.
+pub const KPI_TARGET_COLOUR: Colour = Colour::from_rgb(0x1F, 0x29, 0x37);
+
+type FormatValue = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Describes a single KPI drawn by a [BulletChart] or [Gauge]: its range, target, and qualitative bands.
+///
+/// # Example
+/// An uptime KPI with poor, fair, and good bands:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let kpi = Kpi::new(95.0, 100.0)
+///     .with_bands([99.0, 99.9])
+///     .with_target(99.95);
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Kpi {
+    /// The (min, max) range that values are drawn against. Values outside are clamped.
+    pub range: RwSignal<(f64, f64)>,
+    /// Value of the target marker. Default is `None`.
+    pub target: RwSignal<Option<f64>>,
+    /// Edges between qualitative bands e.g., `[50.0, 80.0]` gives three bands: min to 50, 50 to 80, and 80 to max. Default is none (a single band).
+    pub bands: RwSignal<Vec<f64>>,
+    /// Colour scheme of the bands. Interpolated from the first band to the last. Default is [KPI_BAND_COLOUR_SCHEME].
+    pub band_colours: RwSignal<ColourScheme>,
+    /// Colour of the value. Default is [KPI_VALUE_COLOUR].
+    pub colour: RwSignal<Colour>,
+    /// Colour of the target marker. Default is [KPI_TARGET_COLOUR].
+    pub target_colour: RwSignal<Colour>,
+    /// Formats the value shown by a [Gauge]. Defaults to at most two decimal places.
+    pub format: RwSignal<FormatValue>,
+}
+
+impl Kpi {
+    /// Creates a new KPI drawn against the range of `min` to `max`.
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            range: RwSignal::new((min, max)),
+            target: RwSignal::default(),
+            bands: RwSignal::default(),
+            band_colours: RwSignal::new(KPI_BAND_COLOUR_SCHEME.into()),
+            colour: RwSignal::new(KPI_VALUE_COLOUR),
+            target_colour: RwSignal::new(KPI_TARGET_COLOUR),
+            format: RwSignal::new(Arc::new(format_value)),
+        }
+    }
+
+    /// Sets the target marker.
+    pub fn with_target(self, target: impl Into<Option<f64>>) -> Self {
+        self.target.set(target.into());
+        self
+    }
+
+    /// Sets the edges between qualitative bands. See [bands](Self::bands).
+    pub fn with_bands(self, bands: impl IntoIterator<Item = f64>) -> Self {
+        self.bands.set(bands.into_iter().collect());
+        self
+    }
+
+    /// Sets the colour scheme of the bands.
+    pub fn with_band_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.band_colours.set(colours.into());
+        self
+    }
+
+    /// Sets the colour of the value.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the colour of the target marker.
+    pub fn with_target_colour(self, colour: impl Into<Colour>) -> Self {
+        self.target_colour.set(colour.into());
+        self
+    }
+
+    /// Sets how the value shown by a [Gauge] is formatted.
+    pub fn with_format(self, format: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        self.format.set(Arc::new(format));
+        self
+    }
+
+    /// Ratio of a value along the range. Clamped to 0.0 and 1.0. NaN and empty ranges map to 0.0.
+    fn ratio(&self, value: f64) -> f64 {
+        let (min, max) = self.range.get();
+        range_ratio(value, (min, max))
+    }
+
+    /// Bands as (start, end, colour) ratios along the range.
+    fn band_ratios(&self) -> Vec<(f64, f64, Colour)> {
+        let range = self.range.get();
+        let bands = self.bands.with(|bands| band_edges(range, bands));
+        let last = bands.len().saturating_sub(1).max(1) as f64;
+        self.band_colours.with(|colours| {
+            (bands.into_iter().enumerate())
+                .map(|(i, (start, end))| {
+                    let colour = colours.by_ratio(i as f64 / last);
+                    (range_ratio(start, range), range_ratio(end, range), colour)
+                })
+                .collect()
+        })
+    }
+}
+
+fn range_ratio(value: f64, (min, max): (f64, f64)) -> f64 {
+    let ratio = (value - min) / (max - min);
+    if ratio.is_finite() {
+        ratio.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Splits a (min, max) range into bands at each edge. Edges outside the range or that aren't finite are ignored.
+fn band_edges((min, max): (f64, f64), edges: &[f64]) -> Vec<(f64, f64)> {
+    let mut edges = (edges.iter().copied())
+        .filter(|&edge| edge > min && edge < max)
+        .collect::<Vec<_>>();
+    edges.sort_by(f64::total_cmp);
+    edges.dedup();
+    let starts = std::iter::once(min).chain(edges.iter().copied());
+    let ends = edges.iter().copied().chain(std::iter::once(max));
+    starts.zip(ends).collect()
+}
+
+/// Renders a bullet chart: a horizontal bar of a value against a target marker and qualitative bands. Drawn at a fixed pixel size.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # #[component]
+/// # fn BulletComponent() -> impl IntoView {
+/// let revenue: Signal<f64> = Signal::default();
+/// view! {
+///     <BulletChart
+///         width=300.0
+///         height=30.0
+///         kpi=Kpi::new(0.0, 300.0).with_bands([150.0, 225.0]).with_target(250.0)
+///         value=revenue
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn BulletChart(
+    /// Width of the chart in pixels.
+    #[prop(into)]
+    width: Signal<f64>,
+    /// Height of the chart in pixels.
+    #[prop(into)]
+    height: Signal<f64>,
+    /// The range, target, and bands to draw the value against. See [Kpi] for details.
+    kpi: Kpi,
+    /// Value to draw.
+    #[prop(into)]
+    value: Signal<f64>,
+) -> impl IntoView {
+    let bands = {
+        let kpi = kpi.clone();
+        move || {
+            let (width, height) = (width.get(), height.get());
+            (kpi.band_ratios().into_iter())
+                .map(|(start, end, colour)| {
+                    view! {
+                        <rect
+                            x=start * width
+                            y=0.0
+                            width=(end - start) * width
+                            height=height
+                            fill=colour.to_string() />
+                    }
+                })
+                .collect_view()
+        }
+    };
+
+    let bar = {
+        let kpi = kpi.clone();
+        move || {
+            let (width, height) = (width.get(), height.get());
+            view! {
+                <rect
+                    x=0.0
+                    y=height / 3.0
+                    width=kpi.ratio(value.get()) * width
+                    height=height / 3.0
+                    fill=kpi.colour.get().to_string() />
+            }
+        }
+    };
+
+    let target = {
+        let kpi = kpi.clone();
+        move || {
+            let (width, height) = (width.get(), height.get());
+            kpi.target.get().map(|target| {
+                let x = kpi.ratio(target) * width;
+                view! {
+                    <line
+                        x1=x
+                        y1=height / 6.0
+                        x2=x
+                        y2=height * 5.0 / 6.0
+                        stroke=kpi.target_colour.get().to_string()
+                        stroke-width=2.0 />
+                }
+            })
+        }
+    };
+
+    view! {
+        <svg
+            class="_chartistry_bullet"
+            width=move || format!("{}px", width.get())
+            height=move || format!("{}px", height.get())
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            style="display: inline-block; overflow: visible; vertical-align: middle;">
+            <g class="_chartistry_bullet_bands">{bands}</g>
+            {bar}
+            {target}
+        </svg>
+    }
+}
+
+/// Turn (clockwise from the top) of a ratio along a gauge's arc. The arc runs from left to right over the top.
+fn gauge_turn(ratio: f64) -> f64 {
+    0.75 + ratio * 0.5
+}
+
+/// Renders a radial gauge: a semicircular arc of a value against a target marker and qualitative bands. The value is shown in the middle. Drawn at a fixed pixel size.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # #[component]
+/// # fn GaugeComponent() -> impl IntoView {
+/// let cpu: Signal<f64> = Signal::default();
+/// view! {
+///     <Gauge
+///         width=160.0
+///         height=100.0
+///         kpi=Kpi::new(0.0, 100.0).with_bands([60.0, 85.0]).with_format(|v| format!("{v:.0}%"))
+///         value=cpu
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Gauge(
+    /// Width of the gauge in pixels.
+    #[prop(into)]
+    width: Signal<f64>,
+    /// Height of the gauge in pixels.
+    #[prop(into)]
+    height: Signal<f64>,
+    /// The range, target, and bands to draw the value against. See [Kpi] for details.
+    kpi: Kpi,
+    /// Value to draw.
+    #[prop(into)]
+    value: Signal<f64>,
+) -> impl IntoView {
+    // Centre and radius of the largest semicircle that fits
+    let geometry = Memo::new(move |_| {
+        let (width, height) = (width.get(), height.get());
+        let radius = (width / 2.0).min(height).max(0.0);
+        ((width / 2.0, (height + radius) / 2.0), radius)
+    });
+
+    let bands = {
+        let kpi = kpi.clone();
+        move || {
+            let (centre, radius) = geometry.get();
+            (kpi.band_ratios().into_iter())
+                .map(|(start, end, colour)| {
+                    let (start, end) = (gauge_turn(start), gauge_turn(end));
+                    view! {
+                        <path
+                            d=slice_path(centre, radius, radius * 0.6, start, end)
+                            fill=colour.to_string() />
+                    }
+                })
+                .collect_view()
+        }
+    };
+
+    let arc = {
+        let kpi = kpi.clone();
+        move || {
+            let (centre, radius) = geometry.get();
+            let end = gauge_turn(kpi.ratio(value.get()));
+            view! {
+                <path
+                    d=slice_path(centre, radius * 0.9, radius * 0.7, gauge_turn(0.0), end)
+                    fill=kpi.colour.get().to_string() />
+            }
+        }
+    };
+
+    let target = {
+        let kpi = kpi.clone();
+        move || {
+            let (centre, radius) = geometry.get();
+            kpi.target.get().map(|target| {
+                let turn = gauge_turn(kpi.ratio(target));
+                let (x1, y1) = turn_point(centre, radius * 0.55, turn);
+                let (x2, y2) = turn_point(centre, radius, turn);
+                view! {
+                    <line
+                        x1=x1
+                        y1=y1
+                        x2=x2
+                        y2=y2
+                        stroke=kpi.target_colour.get().to_string()
+                        stroke-width=2.0 />
+                }
+            })
+        }
+    };
+
+    let label = move || {
+        let ((x, y), radius) = geometry.get();
+        let text = kpi.format.with(|format| format(value.get()));
+        view! {
+            <text
+                x=x
+                y=y
+                font-size=radius * 0.3
+                text-anchor="middle"
+                dominant-baseline="text-after-edge">
+                {text}
+            </text>
+        }
+    };
+
+    view! {
+        <svg
+            class="_chartistry_gauge"
+            width=move || format!("{}px", width.get())
+            height=move || format!("{}px", height.get())
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            style="display: inline-block; overflow: visible; vertical-align: middle;">
+            <g class="_chartistry_gauge_bands">{bands}</g>
+            {arc}
+            {target}
+            {label}
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_edges() {
+        assert_eq!(band_edges((0.0, 10.0), &[]), vec![(0.0, 10.0)]);
+        assert_eq!(
+            band_edges((0.0, 10.0), &[8.0, 5.0, 5.0, -1.0, 10.0, f64::NAN]),
+            vec![(0.0, 5.0), (5.0, 8.0), (8.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn test_range_ratio() {
+        assert_eq!(range_ratio(5.0, (0.0, 10.0)), 0.5);
+        assert_eq!(range_ratio(15.0, (0.0, 10.0)), 1.0);
+        assert_eq!(range_ratio(-5.0, (0.0, 10.0)), 0.0);
+        assert_eq!(range_ratio(f64::NAN, (0.0, 10.0)), 0.0);
+        assert_eq!(range_ratio(5.0, (5.0, 5.0)), 0.0);
+        assert_eq!(gauge_turn(0.5), 1.0);
+    }
+}
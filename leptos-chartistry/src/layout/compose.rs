@@ -1,10 +1,10 @@
-use super::{EdgeLayout, UseLayout};
+use super::{AxisRanges, EdgeLayout, UseLayout};
 use crate::{
     aspect_ratio::KnownAspectRatio,
     bounds::Bounds,
     edge::Edge,
     state::{PreState, State},
-    Tick,
+    Orientation, Tick,
 };
 use leptos::prelude::*;
 
@@ -17,7 +17,10 @@ pub struct Layout {
     pub bottom: Vec<Memo<Bounds>>,
     pub left: Vec<Memo<Bounds>>,
     pub inner: Memo<Bounds>,
+    /// Width of each X value. Measured along the Y axis for horizontal charts.
     pub x_width: Memo<f64>,
+    /// Height of each Y band e.g., a heatmap row. Zero if there are no Y bands. Measured along the X axis for horizontal charts.
+    pub y_width: Memo<f64>,
 }
 
 #[derive(Clone)]
@@ -49,6 +52,7 @@ impl Layout {
     ///  - Calculate the bounds: outer, inner, edges, edge components. Adhere to aspect ratio.
     ///  - Return state (Layout) and a deferred renderer (ComposedLayout).
     ///
+    /// Horizontal charts draw the X axis vertically. The X edges (top / bottom) are swapped with the Y edges (right / left).
     pub fn compose<X: Tick, Y: Tick>(
         top: &[EdgeLayout<X>],
         right: &[EdgeLayout<Y>],
@@ -56,6 +60,31 @@ impl Layout {
         left: &[EdgeLayout<Y>],
         aspect_ratio: Memo<KnownAspectRatio>,
         state: &PreState<X, Y>,
+    ) -> (Layout, Vec<DeferredRender>) {
+        let range_x = (state.data.range_x, state.data.range_x);
+        let range_y = (state.data.range_y, state.data.secondary_range_y);
+        match state.data.orientation {
+            Orientation::Vertical => Self::compose_edges(
+                (top, right, bottom, left),
+                (range_x, range_y),
+                aspect_ratio,
+                state,
+            ),
+            Orientation::Horizontal => Self::compose_edges(
+                (right, top, left, bottom),
+                (range_y, range_x),
+                aspect_ratio,
+                state,
+            ),
+        }
+    }
+
+    /// Composes edges where `H` is drawn horizontally (top / bottom) and `V` vertically (left / right).
+    fn compose_edges<H: Tick, V: Tick, X: Tick, Y: Tick>(
+        (top, right, bottom, left): Edges<H, V>,
+        (range_h, range_v): (AxisRanges<H>, AxisRanges<V>),
+        aspect_ratio: Memo<KnownAspectRatio>,
+        state: &PreState<X, Y>,
     ) -> (Layout, Vec<DeferredRender>) {
         // Horizontal options
         let top_heights = collect_heights(top, state);
@@ -66,9 +95,9 @@ impl Layout {
             KnownAspectRatio::inner_height_signal(aspect_ratio, top_height, bottom_height);
 
         // Vertical options
-        let (left_widths, left) = use_vertical(left, state, inner_height);
+        let (left_widths, left) = use_vertical(left, state, range_v, inner_height);
         let left_width = sum_sizes(left_widths.clone());
-        let (right_widths, right) = use_vertical(right, state, inner_height);
+        let (right_widths, right) = use_vertical(right, state, range_v, inner_height);
         let right_width = sum_sizes(right_widths.clone());
         let avail_width =
             KnownAspectRatio::inner_width_signal(aspect_ratio, left_width, right_width);
@@ -108,9 +137,29 @@ impl Layout {
             Bounds::from_points(outer.get().left_x(), i.top_y(), i.left_x(), i.bottom_y())
         });
 
-        // Find the width of each X
-        let data_len = state.data.len;
-        let x_width = Memo::new(move |_| inner.get().width() / data_len.get() as f64);
+        // Find the width of each X along the axis it's drawn on
+        let x_bands = state.data.x_bands;
+        let orientation = state.data.orientation;
+        let x_width = Memo::new(move |_| {
+            let inner = inner.get();
+            let length = match orientation {
+                Orientation::Vertical => inner.width(),
+                Orientation::Horizontal => inner.height(),
+            };
+            length / x_bands.get() as f64
+        });
+        let y_bands = state.data.y_bands;
+        let y_width = Memo::new(move |_| {
+            let inner = inner.get();
+            let length = match orientation {
+                Orientation::Vertical => inner.height(),
+                Orientation::Horizontal => inner.width(),
+            };
+            match y_bands.get() {
+                0 => 0.0,
+                bands => length / bands as f64,
+            }
+        });
 
         // State signals
         let layout = Layout {
@@ -121,6 +170,7 @@ impl Layout {
             left: option_bounds(Edge::Left, left_bounds, left_widths),
             inner,
             x_width,
+            y_width,
         };
 
         let vertical = |edge, bounds: &[Memo<Bounds>], items: Vec<_>| {
@@ -130,7 +180,7 @@ impl Layout {
                 .map(move |(index, opt)| (edge, bounds[index], opt))
                 .collect::<Vec<_>>()
         };
-        let horizontal = |edge: Edge, bounds: &[Memo<Bounds>], items: &[EdgeLayout<X>]| {
+        let horizontal = |edge: Edge, bounds: &[Memo<Bounds>], items: &[EdgeLayout<H>]| {
             items
                 .iter()
                 .enumerate()
@@ -138,7 +188,7 @@ impl Layout {
                     (
                         edge,
                         bounds[index],
-                        opt.to_horizontal_use(state, avail_width),
+                        opt.to_horizontal_use(state, range_h, avail_width),
                     )
                 })
                 .collect::<Vec<_>>()
@@ -161,8 +211,16 @@ impl Layout {
     }
 }
 
-fn collect_heights<X: Tick, Y: Tick>(
-    items: &[EdgeLayout<X>],
+/// Top, right, bottom, and left edges.
+type Edges<'a, H, V> = (
+    &'a [EdgeLayout<H>],
+    &'a [EdgeLayout<V>],
+    &'a [EdgeLayout<H>],
+    &'a [EdgeLayout<V>],
+);
+
+fn collect_heights<XY: Tick, X: Tick, Y: Tick>(
+    items: &[EdgeLayout<XY>],
     state: &PreState<X, Y>,
 ) -> Vec<Signal<f64>> {
     items
@@ -171,15 +229,16 @@ fn collect_heights<X: Tick, Y: Tick>(
         .collect::<Vec<_>>()
 }
 
-fn use_vertical<X: Tick, Y: Tick>(
-    items: &[EdgeLayout<Y>],
+fn use_vertical<XY: Tick, X: Tick, Y: Tick>(
+    items: &[EdgeLayout<XY>],
     state: &PreState<X, Y>,
+    range: AxisRanges<XY>,
     avail_height: Memo<f64>,
 ) -> (Vec<Signal<f64>>, Vec<UseLayout>) {
     items
         .iter()
         .map(|c| {
-            let vert = c.to_vertical_use(state, avail_height);
+            let vert = c.to_vertical_use(state, range, avail_height);
             (vert.width, vert.layout)
         })
         .unzip()
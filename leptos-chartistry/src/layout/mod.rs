@@ -8,6 +8,7 @@ pub use compose::Layout;
 use crate::{
     bounds::Bounds,
     edge::Edge,
+    series::Range,
     state::{PreState, State},
     Tick,
 };
@@ -25,6 +26,9 @@ pub enum EdgeLayout<XY: Tick> {
     TickLabels(tick_labels::TickLabels<XY>),
 }
 
+/// Ranges of the primary and secondary axis that an edge describes. X edges use the same range for both.
+type AxisRanges<XY> = (Memo<Range<XY>>, Memo<Range<XY>>);
+
 struct UseVerticalLayout {
     width: Signal<f64>,
     layout: UseLayout,
@@ -58,8 +62,8 @@ impl UseLayout {
     }
 }
 
-impl<X: Tick> EdgeLayout<X> {
-    fn fixed_height<Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
+impl<XY: Tick> EdgeLayout<XY> {
+    fn fixed_height<X: Tick, Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
         match self {
             Self::Legend(inner) => inner.fixed_height(state),
             Self::RotatedLabel(inner) => inner.fixed_height(state),
@@ -67,29 +71,29 @@ impl<X: Tick> EdgeLayout<X> {
         }
     }
 
-    fn to_horizontal_use<Y: Tick>(
+    fn to_horizontal_use<X: Tick, Y: Tick>(
         &self,
         state: &PreState<X, Y>,
+        range: AxisRanges<XY>,
         avail_width: Memo<f64>,
     ) -> UseLayout {
         match self {
             Self::Legend(inner) => inner.to_horizontal_use(),
             Self::RotatedLabel(inner) => inner.to_horizontal_use(),
-            Self::TickLabels(inner) => inner.to_horizontal_use(state, avail_width),
+            Self::TickLabels(inner) => inner.to_horizontal_use(state, range, avail_width),
         }
     }
-}
 
-impl<Y: Tick> EdgeLayout<Y> {
-    fn to_vertical_use<X: Tick>(
+    fn to_vertical_use<X: Tick, Y: Tick>(
         &self,
         state: &PreState<X, Y>,
+        range: AxisRanges<XY>,
         avail_height: Memo<f64>,
     ) -> UseVerticalLayout {
         match self {
             Self::Legend(inner) => inner.to_vertical_use(state),
             Self::RotatedLabel(inner) => inner.to_vertical_use(state),
-            Self::TickLabels(inner) => inner.to_vertical_use(state, avail_height),
+            Self::TickLabels(inner) => inner.to_vertical_use(state, range, avail_height),
         }
     }
 }
@@ -1,14 +1,15 @@
-use super::{UseLayout, UseVerticalLayout};
+use super::{AxisRanges, UseLayout, UseVerticalLayout};
 use crate::{
     bounds::Bounds,
     debug::DebugRect,
     edge::Edge,
+    series::Range,
     state::{PreState, State},
     ticks::{
-        AlignedFloats, GeneratedTicks, HorizontalSpan, TickFormat, TickFormatFn, TickGen,
-        Timestamps, VerticalSpan,
+        AlignedFloats, Category, CategoryTicks, GeneratedTicks, HorizontalSpan, LogFloats,
+        TickFormat, TickFormatFn, TickGen, Timestamps, VerticalSpan,
     },
-    Tick,
+    Orientation, Tick, YAxis,
 };
 use chrono::prelude::*;
 use leptos::prelude::*;
@@ -28,11 +29,14 @@ pub struct TickLabels<XY: Tick> {
     pub format: RwSignal<Arc<TickFormatFn<XY>>>,
     /// Tick generator for the labels.
     pub generator: RwSignal<Arc<dyn TickGen<Tick = XY> + Send + Sync>>,
+    /// The Y axis that the labels describe. Ignored on X axis edges. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
 }
 
 #[derive(Clone)]
 pub struct UseTickLabels {
     ticks: Signal<Vec<(f64, String)>>,
+    y_axis: RwSignal<YAxis>,
 }
 
 impl<XY: Tick> Clone for TickLabels<XY> {
@@ -41,6 +45,7 @@ impl<XY: Tick> Clone for TickLabels<XY> {
             min_chars: self.min_chars,
             format: self.format,
             generator: self.generator,
+            y_axis: self.y_axis,
         }
     }
 }
@@ -56,6 +61,18 @@ impl TickLabels<f64> {
     pub fn aligned_floats() -> Self {
         Self::from_generator(AlignedFloats::default())
     }
+
+    /// Creates a new tick label generator for floating point numbers on a log scale. See [LogFloats] for details.
+    pub fn log_floats() -> Self {
+        Self::from_generator(LogFloats::default())
+    }
+}
+
+impl TickLabels<Category> {
+    /// Creates a new tick label generator for categories. See [CategoryTicks] for details.
+    pub fn categories() -> Self {
+        Self::from_generator(CategoryTicks::default())
+    }
 }
 
 impl<Tz> TickLabels<DateTime<Tz>>
@@ -70,12 +87,13 @@ where
 }
 
 impl<XY: Tick> TickLabels<XY> {
-    /// Creates a new tick label generator from a tick generator. Use [AlignedFloats] or [Timestamps] for available generators.
+    /// Creates a new tick label generator from a tick generator. Use [AlignedFloats], [Timestamps], or [CategoryTicks] for available generators.
     pub fn from_generator(gen: impl TickGen<Tick = XY> + 'static) -> Self {
         Self {
             min_chars: RwSignal::default(),
             format: RwSignal::new(HorizontalSpan::identity_format()),
             generator: RwSignal::new(Arc::new(gen)),
+            y_axis: RwSignal::default(),
         }
     }
 
@@ -96,6 +114,12 @@ impl<XY: Tick> TickLabels<XY> {
         self
     }
 
+    /// Sets the Y axis that the labels describe. Use [YAxis::Secondary] to label the secondary Y axis, usually on the right edge. Ignored on X axis edges.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+
     fn map_ticks(&self, gen: Memo<GeneratedTicks<XY>>) -> Signal<Vec<(f64, String)>> {
         let format = self.format;
         Signal::derive(move || {
@@ -120,93 +144,122 @@ where
     }
 }
 
-impl<X: Tick> TickLabels<X> {
-    pub(crate) fn generate_x<Y: Tick>(
+impl<XY: Tick> TickLabels<XY> {
+    /// Generates ticks for a data range. Horizontal ticks are sized by their labels while vertical ticks are sized by the font height.
+    fn generate<X: Tick, Y: Tick>(
         &self,
         state: &PreState<X, Y>,
-        avail_width: Signal<f64>,
-    ) -> Memo<GeneratedTicks<X>> {
+        range: Signal<Range<XY>>,
+        avail: Signal<f64>,
+        vertical: bool,
+    ) -> Memo<GeneratedTicks<XY>> {
         let font_width = state.font_width;
+        let font_height = state.font_height;
         let padding = state.padding;
-        let range_x = state.data.range_x;
         let TickLabels {
             min_chars,
             format,
             generator,
+            ..
         } = self.clone();
         Memo::new(move |_| {
-            range_x
+            range
                 .get()
                 .range()
                 .map(|(first, last)| {
-                    let span = HorizontalSpan::new(
-                        font_width.get(),
-                        min_chars.get(),
-                        padding.get().width(),
-                        avail_width.get(),
-                        format.get(),
-                    );
-                    generator.get().generate(first, last, &span)
+                    if vertical {
+                        let span = VerticalSpan::new(
+                            font_height.get() + padding.get().height(),
+                            avail.get(),
+                        );
+                        generator.get().generate(first, last, &span)
+                    } else {
+                        let span = HorizontalSpan::new(
+                            font_width.get(),
+                            min_chars.get(),
+                            padding.get().width(),
+                            avail.get(),
+                            format.get(),
+                        );
+                        generator.get().generate(first, last, &span)
+                    }
                 })
                 .unwrap_or_else(GeneratedTicks::none)
         })
     }
 
-    pub(super) fn fixed_height<Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
+    pub(super) fn fixed_height<X: Tick, Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
         let font_height = state.font_height;
         let padding = state.padding;
         Signal::derive(move || font_height.get() + padding.get().height())
     }
 
-    pub(super) fn to_horizontal_use<Y: Tick>(
-        &self,
-        state: &PreState<X, Y>,
-        avail_width: Memo<f64>,
-    ) -> UseLayout {
-        UseLayout::TickLabels(UseTickLabels {
-            ticks: self.map_ticks(self.generate_x(state, avail_width.into())),
+    /// Picks the range of our Y axis.
+    fn axis_range(&self, (primary, secondary): AxisRanges<XY>) -> Signal<Range<XY>> {
+        let y_axis = self.y_axis;
+        Signal::derive(move || match y_axis.get() {
+            YAxis::Primary => primary.get(),
+            YAxis::Secondary => secondary.get(),
         })
     }
-}
 
-impl<Y: Tick> TickLabels<Y> {
-    pub(crate) fn generate_y<X: Tick>(
+    pub(super) fn to_horizontal_use<X: Tick, Y: Tick>(
         &self,
         state: &PreState<X, Y>,
-        avail_height: Signal<f64>,
-    ) -> Memo<GeneratedTicks<Y>> {
-        let font_height = state.font_height;
-        let padding = state.padding;
-        let range_y = state.data.range_y;
-        let generator = self.generator;
-        Memo::new(move |_| {
-            range_y
-                .get()
-                .range()
-                .map(|(first, last)| {
-                    let span = VerticalSpan::new(
-                        font_height.get() + padding.get().height(),
-                        avail_height.get(),
-                    );
-                    generator.get().generate(first, last, &span)
-                })
-                .unwrap_or_else(GeneratedTicks::none)
+        range: AxisRanges<XY>,
+        avail_width: Memo<f64>,
+    ) -> UseLayout {
+        let range = self.axis_range(range);
+        let ticks = self.generate(state, range, avail_width.into(), false);
+        UseLayout::TickLabels(UseTickLabels {
+            ticks: self.map_ticks(ticks),
+            y_axis: self.y_axis,
         })
     }
 
-    pub(super) fn to_vertical_use<X: Tick>(
+    pub(super) fn to_vertical_use<X: Tick, Y: Tick>(
         &self,
         state: &PreState<X, Y>,
+        range: AxisRanges<XY>,
         avail_height: Memo<f64>,
     ) -> UseVerticalLayout {
-        let ticks = self.map_ticks(self.generate_y(state, avail_height.into()));
+        let range = self.axis_range(range);
+        let ticks = self.map_ticks(self.generate(state, range, avail_height.into(), true));
         UseVerticalLayout {
             width: mk_width(self.min_chars, state, ticks),
-            layout: UseLayout::TickLabels(UseTickLabels { ticks }),
+            layout: UseLayout::TickLabels(UseTickLabels {
+                ticks,
+                y_axis: self.y_axis,
+            }),
         }
     }
 }
 
+impl<X: Tick> TickLabels<X> {
+    /// Generates X ticks. The available length is along the axis X is drawn on.
+    pub(crate) fn generate_x<Y: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        avail: Signal<f64>,
+    ) -> Memo<GeneratedTicks<X>> {
+        let vertical = state.data.orientation == Orientation::Horizontal;
+        self.generate(state, state.data.range_x.into(), avail, vertical)
+    }
+}
+
+impl<Y: Tick> TickLabels<Y> {
+    /// Generates Y ticks over the range of our [Y axis](Self::y_axis). The available length is along the axis Y is drawn on.
+    pub(crate) fn generate_y<X: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        avail: Signal<f64>,
+    ) -> Memo<GeneratedTicks<Y>> {
+        let vertical = state.data.orientation == Orientation::Vertical;
+        let range = self.axis_range((state.data.range_y, state.data.secondary_range_y));
+        self.generate(state, range, avail, vertical)
+    }
+}
+
 fn mk_width<X: Tick, Y: Tick>(
     min_chars: RwSignal<usize>,
     state: &PreState<X, Y>,
@@ -252,6 +305,8 @@ pub(super) fn TickLabels<X: Tick, Y: Tick>(
     bounds: Memo<Bounds>,
     state: State<X, Y>,
 ) -> impl IntoView {
+    // Position against our Y axis. X axis edges are unaffected as both axes share X.
+    let state = state.on_y_axis(ticks.y_axis);
     let ticks = move || {
         // Align vertical labels
         let ticks = ticks.ticks.get();
@@ -301,16 +356,20 @@ fn TickLabel<X: Tick, Y: Tick>(
 
         let proj = projection.get();
         let outer = outer.get();
+        // Horizontal charts draw X ticks on vertical edges
+        let svg = if edge.is_vertical() == proj.is_horizontal() {
+            proj.x_to_svg(position)
+        } else {
+            proj.y_to_svg(position)
+        };
         match edge {
             Edge::Top | Edge::Bottom => {
-                let (x, _) = proj.position_to_svg(position, 0.0);
-                let x = x - width / 2.0;
+                let x = svg - width / 2.0;
                 Bounds::from_points(x, outer.top_y(), x + width, outer.bottom_y())
             }
 
             Edge::Left | Edge::Right => {
-                let (_, y) = proj.position_to_svg(0.0, position);
-                let y = y - height / 2.0;
+                let y = svg - height / 2.0;
                 Bounds::from_points(outer.left_x(), y, outer.right_x(), y + height)
             }
         }
@@ -351,3 +410,33 @@ fn TickLabel<X: Tick, Y: Tick>(
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{series::UseData, Line, Padding, Series};
+
+    #[test]
+    fn test_generate_y_axis() {
+        let series = Series::new(|d: &(f64, f64, f64)| d.0)
+            .line(|d: &(f64, f64, f64)| d.1)
+            .line(Line::new(|d: &(f64, f64, f64)| d.2).with_y_axis(YAxis::Secondary));
+        let data = vec![(0.0, 0.0, 0.0), (1.0, 10_000.0, 0.08)];
+        let data = UseData::new(
+            series,
+            Signal::derive(move || data.clone()),
+            Signal::default(),
+        );
+        let font = Memo::new(|_| 10.0);
+        let state = PreState::new(Signal::default(), font, font, Padding::zero().into(), data);
+
+        // Each axis is generated over its own range
+        let format = |ticks: TickLabels<f64>| {
+            let generated = ticks.generate_y(&state, Signal::derive(|| 100.0));
+            (ticks.format.get())(&0.05, generated.get().state.as_ref())
+        };
+        assert_eq!(format(TickLabels::aligned_floats()), "0");
+        let secondary = TickLabels::aligned_floats().with_y_axis(YAxis::Secondary);
+        assert_eq!(format(secondary), "0.050");
+    }
+}
@@ -53,21 +53,30 @@
 
 mod aspect_ratio;
 mod bounds;
+mod calendar;
 mod chart;
 mod colours;
 mod debug;
 mod edge;
 mod inner;
+mod kpi;
 mod layout;
 mod overlay;
 mod padding;
+mod pie;
+mod polar;
 mod projection;
+mod sankey;
 mod series;
+mod sparkline;
 mod state;
 mod ticks;
 mod use_watched_node;
 
 pub use aspect_ratio::AspectRatio;
+pub use calendar::{
+    Calendar, CalendarHeatmap, CALENDAR_CELL_SIZE, CALENDAR_EMPTY_COLOUR, CALENDAR_GAP,
+};
 pub use chart::Chart;
 pub use colours::{Colour, ColourScheme, DivergingGradient, SequentialGradient};
 pub use edge::Edge;
@@ -78,16 +87,38 @@ pub use inner::{
     legend::InsetLegend,
     InnerLayout, IntoInner, IntoInner as _,
 };
+pub use kpi::{
+    BulletChart, Gauge, Kpi, KPI_BAND_COLOUR_SCHEME, KPI_TARGET_COLOUR, KPI_VALUE_COLOUR,
+};
 pub use layout::{
     legend::Legend,
     rotated_label::{Anchor, RotatedLabel},
     tick_labels::TickLabels,
     EdgeLayout, IntoEdge, IntoEdge as _,
 };
-pub use overlay::tooltip::{Tooltip, TooltipPlacement, TooltipSortBy, TOOLTIP_CURSOR_DISTANCE};
+pub use overlay::{
+    brush::{Brush, BRUSH_COLOUR},
+    tooltip::{Tooltip, TooltipPlacement, TooltipSortBy, TOOLTIP_CURSOR_DISTANCE},
+    zoom::{Zoom, ZOOM_SELECTION_COLOUR},
+};
 pub use padding::Padding;
+pub use pie::{Pie, PieChart, SliceLabel, DONUT_INNER_RADIUS, PIE_LABEL_COLOUR, PIE_OTHER_COLOUR};
+pub use polar::{Polar, PolarChart};
+pub use projection::Scale;
+pub use sankey::{Sankey, SankeyChart, SANKEY_LINK_OPACITY, SANKEY_NODE_GAP, SANKEY_NODE_WIDTH};
 pub use series::{
-    Bar, BarPlacement, Interpolation, Line, Marker, MarkerShape, Series, Stack, Step, BAR_GAP,
-    BAR_GAP_INNER, DIVERGING_GRADIENT, LINEAR_GRADIENT, SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+    AreaFill, Band, Bar, BarPlacement, Binning, BoxPlot, BoxStats, Candlestick, CandlestickStyle,
+    Heatmap, HistogramBin, Interpolation, Line, Marker, MarkerShape, Orientation, Scatter, Series,
+    Stack, Step, WaterfallChange, WaterfallStep, YAxis, AREA_FILL_OPACITY, BAND_OPACITY, BAR_GAP,
+    BAR_GAP_INNER, BAR_NEGATIVE_COLOUR_SCHEME, BOX_PLOT_OPACITY, CANDLESTICK_DOWN_COLOUR,
+    CANDLESTICK_UP_COLOUR, DIVERGING_GRADIENT, ERROR_BAR_COLOUR, HEATMAP_GAP, HEATMAP_GRADIENT,
+    HISTOGRAM_MAX_BINS_PER_SAMPLE, LINEAR_GRADIENT, SCATTER_OPACITY, SCATTER_SIZE,
+    SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME, WATERFALL_INCREASE_COLOUR, WATERFALL_TOTAL_COLOUR,
+};
+pub use sparkline::{
+    Sparkline, SparklineMarkers, SPARKLINE_MARKER_SIZE, SPARKLINE_MAX_COLOUR, SPARKLINE_MIN_COLOUR,
+};
+pub use ticks::{
+    AlignedFloats, AlignedIntegers, Categories, Category, CategoryTicks, GeneratedTicks, LogFloats,
+    MappedTicks, Period, Tick, TickFormat, TickGen, TickSpan, Timestamps,
 };
-pub use ticks::{AlignedFloats, Period, Tick, TickFormat, Timestamps};
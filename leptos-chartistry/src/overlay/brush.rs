@@ -0,0 +1,289 @@
+use super::zoom::{event_svg, pan_window, Zoom};
+use crate::{bounds::Bounds, colours::Colour, state::State, Tick};
+use leptos::{ev, prelude::*, svg};
+
+/// Default colour of the brush selection window.
+pub const BRUSH_COLOUR: Colour = Colour::from_rgb(0x12, 0xA5, 0xED);
+
+/// Distance (in pixels) from the edge of the selection window that grabs it for resizing.
+const HANDLE_WIDTH: f64 = 6.0;
+
+/// Range selector for an overview chart. Pass to the `brush` prop of a (usually compact) [Chart](crate::Chart) to draw a selection window over all of its data:
+///
+/// - Drag outside the window to select a new range.
+/// - Drag the window to move it.
+/// - Drag the window's edges to resize it.
+/// - Double-click to clear the selection.
+///
+/// The selected X range is published as [Brush::range] and snapped to the nearest data. A brush converts into a [Zoom] sharing the same range so a main chart can follow (and update) the selection:
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # use chrono::prelude::*;
+/// # #[derive(Clone)]
+/// # struct Reading { at: DateTime<Utc>, value: f64 }
+/// # #[component]
+/// # fn Overview(data: Signal<Vec<Reading>>) -> impl IntoView {
+/// let brush = Brush::new();
+/// let series = Series::new(|r: &Reading| r.at).line(|r: &Reading| r.value);
+/// view! {
+///     // Main chart shows the selected range
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         series=series.clone()
+///         data=data
+///         zoom=brush.clone()
+///     />
+///     // Overview chart
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 60.0)
+///         series=series
+///         data=data
+///         brush=brush
+///     />
+/// }
+/// # }
+/// ```
+///
+/// The overview chart always shows all data. Avoid passing the same chart both a brush and a zoom.
+///
+/// The range is an `RwSignal<Option<(X, X)>>` rather than an `RwSignal<(X, X)>`: there's no X to start from before a selection is made (or before data has loaded) and double-clicking clears the selection. Read it with `brush.range.get()` and treat `None` as all data. Write `Some((first, last))` to move the selection from outside the chart. Prefer converting into a [Zoom] over [Series::with_x_range](crate::Series::with_x_range) to drive a main chart: X ranges only extend the axis whereas a zoom restricts it to the selection.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Brush<X: Tick> {
+    /// Selected X range (inclusive). `None` selects all data e.g., before a selection is made or after it's cleared.
+    pub range: RwSignal<Option<(X, X)>>,
+    /// Colour of the selection window.
+    pub colour: RwSignal<Colour>,
+}
+
+/// Part of the selection window being dragged.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Grab {
+    /// A new selection from the data index.
+    New(usize),
+    /// Moving the window. Holds the SVG coords it was last moved to.
+    Move((f64, f64)),
+    /// Resizing the first edge.
+    First,
+    /// Resizing the last edge.
+    Last,
+}
+
+impl<X: Tick> Default for Brush<X> {
+    fn default() -> Self {
+        Self {
+            range: RwSignal::default(),
+            colour: RwSignal::new(BRUSH_COLOUR),
+        }
+    }
+}
+
+impl<X: Tick> Brush<X> {
+    /// Creates a new brush selecting all data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the selected X range (inclusive). `None` selects all data.
+    pub fn with_range(self, range: impl Into<Option<(X, X)>>) -> Self {
+        self.range.set(range.into());
+        self
+    }
+
+    /// Sets the colour of the selection window.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+impl<X: Tick> From<Brush<X>> for Zoom<X> {
+    fn from(brush: Brush<X>) -> Self {
+        Zoom {
+            range: brush.range,
+            ..Zoom::default()
+        }
+    }
+}
+
+/// Resizes one edge of a window of data indexes. Keeps at least two data points.
+fn resize_window((first, last): (usize, usize), index: usize, grab_first: bool) -> (usize, usize) {
+    if grab_first {
+        (index.min(last.saturating_sub(1)), last)
+    } else {
+        (first, index.max(first + 1))
+    }
+}
+
+#[component]
+pub fn Brush<X: Tick, Y: Tick>(brush: Brush<X>, state: State<X, Y>) -> impl IntoView {
+    let Brush { range, colour } = brush;
+    let data = state.pre.data.clone();
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let mouse = state.mouse_chart;
+    let grab = RwSignal::new(None::<Grab>);
+
+    let rect = NodeRef::<svg::Rect>::new();
+    let event_svg = move |ev: &ev::MouseEvent| event_svg(rect, inner.get_untracked(), ev);
+    // Distance along the X axis of SVG coords
+    let along_x = move |(x, y): (f64, f64)| {
+        if proj.get_untracked().is_horizontal() {
+            y
+        } else {
+            x
+        }
+    };
+    let nearest = {
+        let data = data.clone();
+        move |(x, y): (f64, f64)| {
+            let position = proj.get_untracked().svg_to_position(x, y).0;
+            data.nearest_index_untracked(position)
+        }
+    };
+    let window = {
+        let data = data.clone();
+        move || data.window_untracked(range.get_untracked())
+    };
+    let set_window = {
+        let data = data.clone();
+        move |window| range.set(data.window_range_untracked(window))
+    };
+
+    // Selection window along the X axis in SVG coords
+    let selection = Memo::new(move |_| {
+        let (first, last) = range.get()?;
+        let proj = proj.get();
+        let (first, last) = (
+            proj.x_to_svg(first.position()),
+            proj.x_to_svg(last.position()),
+        );
+        Some((first.min(last), first.max(last)))
+    });
+    let selection_bounds = Memo::new(move |_| {
+        let (start, end) = selection.get()?;
+        let inner = inner.get();
+        Some(if proj.get().is_horizontal() {
+            Bounds::from_points(inner.left_x(), start, inner.right_x(), end)
+        } else {
+            Bounds::from_points(start, inner.top_y(), end, inner.bottom_y())
+        })
+    });
+
+    // Which part of the selection window is at the SVG coords
+    let hit = move |svg: (f64, f64)| {
+        let (start, end) = selection.get_untracked()?;
+        let along = along_x(svg);
+        if (along - start).abs() <= HANDLE_WIDTH {
+            Some(Grab::First)
+        } else if (along - end).abs() <= HANDLE_WIDTH {
+            Some(Grab::Last)
+        } else if start < along && along < end {
+            Some(Grab::Move(svg))
+        } else {
+            None
+        }
+    };
+
+    let on_mousedown = {
+        let nearest = nearest.clone();
+        move |ev: ev::MouseEvent| {
+            if ev.button() != 0 {
+                return;
+            }
+            ev.prevent_default();
+            let svg = event_svg(&ev);
+            let new = || nearest(svg).map(Grab::New);
+            grab.set(hit(svg).or_else(new));
+        }
+    };
+
+    let on_mousemove = move |ev: ev::MouseEvent| {
+        let (Some(grabbed), Some(current)) = (grab.get_untracked(), window()) else {
+            return;
+        };
+        let svg = event_svg(&ev);
+        let Some(index) = nearest(svg) else {
+            return;
+        };
+        match grabbed {
+            Grab::New(anchor) => {
+                if index != anchor {
+                    set_window((anchor.min(index), anchor.max(index)));
+                }
+            }
+            Grab::Move(last) => {
+                // Window follows the mouse
+                let Some(from) = nearest(last) else {
+                    return;
+                };
+                let offset = index as isize - from as isize;
+                if offset != 0 {
+                    set_window(pan_window(current, offset, data.len_untracked()));
+                    grab.set(Some(Grab::Move(svg)));
+                }
+            }
+            Grab::First => set_window(resize_window(current, index, true)),
+            Grab::Last => set_window(resize_window(current, index, false)),
+        }
+    };
+
+    // Cursor hints at what a drag would do
+    let cursor = move || {
+        let horizontal = proj.get().is_horizontal();
+        let grabbed = grab.get().or_else(|| hit(mouse.get()));
+        match grabbed {
+            Some(Grab::First | Grab::Last) if horizontal => "ns-resize",
+            Some(Grab::First | Grab::Last) => "ew-resize",
+            Some(Grab::Move(_)) => "move",
+            _ => "crosshair",
+        }
+    };
+
+    view! {
+        <g class="_chartistry_brush">
+            <Show when=move || selection_bounds.get().is_some()>
+                <rect
+                    class="_chartistry_brush_selection"
+                    x=move || selection_bounds.get().unwrap_or_default().left_x()
+                    y=move || selection_bounds.get().unwrap_or_default().top_y()
+                    width=move || selection_bounds.get().unwrap_or_default().width()
+                    height=move || selection_bounds.get().unwrap_or_default().height()
+                    fill=move || colour.get().to_string()
+                    fill-opacity="0.2"
+                    stroke=move || colour.get().to_string()
+                    stroke-width=2
+                    pointer-events="none" />
+            </Show>
+            <rect
+                node_ref=rect
+                x=move || inner.get().left_x()
+                y=move || inner.get().top_y()
+                width=move || inner.get().width()
+                height=move || inner.get().height()
+                fill="transparent"
+                style:cursor=cursor
+                on:mousedown=on_mousedown
+                on:mousemove=on_mousemove
+                on:mouseup=move |_| grab.set(None)
+                on:mouseleave=move |_| grab.set(None)
+                on:dblclick=move |_| range.set(None) />
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_window() {
+        assert_eq!(resize_window((10, 20), 5, true), (5, 20));
+        assert_eq!(resize_window((10, 20), 30, false), (10, 30));
+        // Keeps at least two points
+        assert_eq!(resize_window((10, 20), 25, true), (19, 20));
+        assert_eq!(resize_window((10, 20), 3, false), (10, 11));
+    }
+}
@@ -1 +1,3 @@
+pub mod brush;
 pub mod tooltip;
+pub mod zoom;
@@ -1,8 +1,8 @@
 use crate::{
     debug::DebugRect,
-    series::{Snippet, UseY},
+    series::{format_ohlc, Snippet, UseY},
     state::State,
-    Tick, TickLabels, AXIS_MARKER_COLOUR,
+    Orientation, Padding, Tick, TickLabels, YAxis, AXIS_MARKER_COLOUR,
 };
 use leptos::prelude::*;
 use std::cmp::{Ordering, Reverse};
@@ -10,6 +10,9 @@ use std::cmp::{Ordering, Reverse};
 /// Default gap distance from cursor to tooltip when shown.
 pub const TOOLTIP_CURSOR_DISTANCE: f64 = 10.0;
 
+/// Style shared by tooltips. Placed by [TooltipFrame].
+const TOOLTIP_STYLE: &str = "position: absolute; z-index: 1; width: max-content; height: max-content; transform: translateY(-50%); background-color: #fff; white-space: pre; font-family: monospace;";
+
 /// Builds a mouse tooltip that shows X and Y values for the nearest data. Drawn in HTML as an overlay.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -29,6 +32,8 @@ pub struct Tooltip<X: Tick, Y: Tick> {
     pub x_ticks: TickLabels<X>,
     /// Y axis formatter.
     pub y_ticks: TickLabels<Y>,
+    /// Secondary Y axis formatter. Used by series on [YAxis::Secondary].
+    pub secondary_y_ticks: TickLabels<Y>,
 }
 
 /// Where the tooltip is place when shown.
@@ -77,6 +82,7 @@ impl<X: Tick, Y: Tick> Tooltip<X, Y> {
             TickLabels::from_generator(X::tooltip_generator()),
             TickLabels::from_generator(Y::tooltip_generator()),
         )
+        .with_secondary_y_ticks(TickLabels::from_generator(Y::tooltip_generator()))
     }
 
     /// Creates a new tooltip left of the cursor. Uses default X and Y ticks.
@@ -84,6 +90,14 @@ impl<X: Tick, Y: Tick> Tooltip<X, Y> {
         Self::from_placement(TooltipPlacement::LeftCursor)
     }
 
+    /// Sets the formatter of values on the [secondary Y axis](YAxis::Secondary).
+    pub fn with_secondary_y_ticks(self, ticks: impl Into<TickLabels<Y>>) -> Self {
+        Self {
+            secondary_y_ticks: ticks.into().with_y_axis(YAxis::Secondary),
+            ..self
+        }
+    }
+
     /// Sets the sort order of the Y value table.
     pub fn with_sort_by(self, sort_by: impl Into<TooltipSortBy>) -> Self {
         self.sort_by.set(sort_by.into());
@@ -119,6 +133,7 @@ impl<X: Tick, Y: Tick> Default for Tooltip<X, Y> {
             show_x_ticks: RwSignal::new(true),
             x_ticks: TickLabels::default(),
             y_ticks: TickLabels::default(),
+            secondary_y_ticks: TickLabels::default().with_y_axis(YAxis::Secondary),
         }
     }
 }
@@ -211,17 +226,27 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         show_x_ticks,
         x_ticks,
         y_ticks,
+        secondary_y_ticks,
     } = tooltip;
     let debug = state.pre.debug;
     let font_height = state.pre.font_height;
     let font_width = state.pre.font_width;
     let padding = state.pre.padding;
     let inner = state.layout.inner;
+    // Horizontal charts draw X top to bottom
+    let horizontal = state.pre.data.orientation == Orientation::Horizontal;
 
     let x_body = {
         let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
         let x_format = x_ticks.format;
-        let avail_width = Signal::derive(move || inner.read().width());
+        let avail_width = Signal::derive(move || {
+            let inner = inner.read();
+            if horizontal {
+                inner.height()
+            } else {
+                inner.width()
+            }
+        });
         let x_ticks = x_ticks.generate_x(&state.pre, avail_width);
         move || {
             // Hide ticks?
@@ -236,16 +261,32 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         }
     };
 
-    let format_y_value = {
-        let avail_height = Signal::derive(move || inner.read().height());
-        let y_format = y_ticks.format;
-        let y_ticks = y_ticks.generate_y(&state.pre, avail_height);
-        move |y_value: Option<Y>| {
-            let y_format = y_format.get();
-            y_value.as_ref().map_or_else(
-                || "-".to_string(),
-                |y_value| (y_format)(y_value, y_ticks.read().state.as_ref()),
-            )
+    let format_y = {
+        let avail_height = Signal::derive(move || {
+            let inner = inner.read();
+            if horizontal {
+                inner.width()
+            } else {
+                inner.height()
+            }
+        });
+        // Each Y axis is formatted against its own range
+        let mk_format = |y_ticks: TickLabels<Y>| {
+            let y_format = y_ticks.format;
+            let y_ticks = y_ticks.generate_y(&state.pre, avail_height);
+            move |y_value: Option<Y>| {
+                let y_format = y_format.get();
+                y_value.as_ref().map_or_else(
+                    || "-".to_string(),
+                    |y_value| (y_format)(y_value, y_ticks.read().state.as_ref()),
+                )
+            }
+        };
+        let primary = mk_format(y_ticks);
+        let secondary = mk_format(secondary_y_ticks);
+        move |y_axis: YAxis, y_value: Option<Y>| match y_axis {
+            YAxis::Primary => primary(y_value),
+            YAxis::Secondary => secondary(y_value),
         }
     };
 
@@ -266,12 +307,57 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         })
     };
 
+    let nearest_data_base = state.pre.data.nearest_data_base(state.hover_position_x);
+    let nearest_data_extra = state.pre.data.nearest_data_extra(state.hover_position_x);
+    let nearest_cells =
+        (state.pre.data).nearest_cells(state.hover_position_x, state.hover_position_y);
     let nearest_data_y = move || {
         nearest_y_values
             .get()
             .into_iter()
             .map(|(line, y_value)| {
-                let y_value = format_y_value(y_value);
+                let y_axis = line.y_axis.get();
+                let format_y_value = |y_value| format_y(y_axis, y_value);
+                let y_value = if line.is_band() {
+                    // Show both the lower and upper value
+                    let lower = nearest_data_base.with(|bases| bases.get(&line.id).cloned());
+                    format!("{} – {}", format_y_value(lower), format_y_value(y_value))
+                } else if line.is_candlestick() {
+                    let open = nearest_data_base.with(|bases| bases.get(&line.id).cloned());
+                    nearest_data_extra.with(|extras| {
+                        let extra = extras.get(&line.id).map(Vec::as_slice);
+                        format_ohlc(open, extra, y_value, format_y_value)
+                    })
+                } else if let Some(heatmap) = line.heatmap() {
+                    // Report the cell under the mouse
+                    let cell = nearest_cells.with(|cells| cells.get(&line.id).cloned());
+                    cell.map_or_else(
+                        || "-".to_string(),
+                        |(row, value)| {
+                            format!(
+                                "{}: {}",
+                                format_y_value(Some(row)),
+                                heatmap.format_value(value)
+                            )
+                        },
+                    )
+                } else if line.is_box_plot() {
+                    // Median is the value, min, q1, q3, and max are the first extra values
+                    let [min, q1, q3, max] = nearest_data_extra.with(|extras| {
+                        let extra = extras.get(&line.id);
+                        [0, 1, 2, 3].map(|i| extra.and_then(|extra| extra.get(i).cloned()))
+                    });
+                    format!(
+                        "Min {} Q1 {} Med {} Q3 {} Max {}",
+                        format_y_value(min),
+                        format_y_value(q1),
+                        format_y_value(y_value),
+                        format_y_value(q3),
+                        format_y_value(max)
+                    )
+                } else {
+                    format_y_value(y_value)
+                };
                 (line, y_value)
             })
             .collect::<Vec<_>>()
@@ -280,47 +366,99 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
     let series_tr = {
         let state = state.clone();
         move |(series, y_value): (UseY, String)| {
+            let state = state.clone();
             view! {
-                <tr>
-                    <td><Snippet series=series state=state.clone() /></td>
-                    <td
-                        style="white-space: pre; font-family: monospace; text-align: right;"
-                        style:padding-top=move || format!("{}px", font_height.get() / 4.0)
-                        style:padding-left=move || format!("{}px", font_width.get())>
-                        {y_value}
-                    </td>
-                </tr>
+                <TooltipRow value=y_value font_height=font_height font_width=font_width>
+                    <Snippet series=series state=state />
+                </TooltipRow>
             }
         }
     };
 
     view! {
-        <Show when=move || state.hover_inner.get() && placement.get() != TooltipPlacement::Hide>
+        <TooltipFrame
+            show=state.hover_inner
+            placement=placement
+            cursor_distance=cursor_distance
+            mouse_page=state.mouse_page
+            heading=Signal::derive(x_body)
+            padding=padding
+            font_height=font_height
+            debug=debug>
+            <For
+                each=nearest_data_y
+                key=|(series, y_value)| (series.id, y_value.to_owned())
+                children=series_tr.clone()
+            />
+        </TooltipFrame>
+    }
+}
+
+/// Draws a tooltip beside the mouse: a heading above a table of [TooltipRow]s. Shown while `show` is true and the placement isn't hidden. Shared by charts that build their own tooltip content.
+#[component]
+pub(crate) fn TooltipFrame(
+    #[prop(into)] show: Signal<bool>,
+    #[prop(into)] placement: Signal<TooltipPlacement>,
+    #[prop(into)] cursor_distance: Signal<f64>,
+    /// Mouse position relative to the page.
+    mouse_page: Signal<(f64, f64)>,
+    #[prop(into)] heading: Signal<String>,
+    #[prop(into)] padding: Signal<Padding>,
+    #[prop(into)] font_height: Signal<f64>,
+    #[prop(into)] debug: Signal<bool>,
+    /// Rows of the table.
+    children: ChildrenFn,
+) -> impl IntoView {
+    // Distance from the right of the page
+    let right = move || match placement.get() {
+        TooltipPlacement::Hide | TooltipPlacement::LeftCursor => format!(
+            "calc(100% - {}px + {}px)",
+            mouse_page.get().0,
+            cursor_distance.get()
+        ),
+    };
+    view! {
+        <Show when=move || show.get() && placement.get() != TooltipPlacement::Hide>
             <DebugRect label="tooltip" debug=debug />
             <aside
                 class="_chartistry_tooltip"
-                style="position: absolute; z-index: 1; width: max-content; height: max-content; transform: translateY(-50%); background-color: #fff; white-space: pre; font-family: monospace;"
+                style=TOOLTIP_STYLE
                 style:border=format!("1px solid {}", AXIS_MARKER_COLOUR)
-                style:top=move || format!("calc({}px)", state.mouse_page.get().1)
-                style:right=move || format!("calc(100% - {}px + {}px)", state.mouse_page.get().0, cursor_distance.get())
+                style:top=move || format!("calc({}px)", mouse_page.get().1)
+                style:right=right
                 style:padding=move || padding.get().to_css_style()>
                 <h2
                     style="margin: 0; text-align: center;"
                     style:font-size=move || format!("{}px", font_height.get())>
-                    {x_body}
+                    {heading}
                 </h2>
                 <table
                     style="border-collapse: collapse; border-spacing: 0; margin: 0 0 0 auto; padding: 0;"
                     style:font-size=move || format!("{}px", font_height.get())>
-                    <tbody>
-                        <For
-                            each=nearest_data_y
-                            key=|(series, y_value)| (series.id, y_value.to_owned())
-                            children=series_tr.clone()
-                        />
-                    </tbody>
+                    <tbody>{children()}</tbody>
                 </table>
             </aside>
         </Show>
     }
 }
+
+/// A row of a [TooltipFrame]: a label (e.g., a series [Snippet]) followed by its value.
+#[component]
+pub(crate) fn TooltipRow(
+    value: String,
+    #[prop(into)] font_height: Signal<f64>,
+    #[prop(into)] font_width: Signal<f64>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <tr>
+            <td>{children()}</td>
+            <td
+                style="white-space: pre; font-family: monospace; text-align: right;"
+                style:padding-top=move || format!("{}px", font_height.get() / 4.0)
+                style:padding-left=move || format!("{}px", font_width.get())>
+                {value}
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{bounds::Bounds, colours::Colour, state::State, Tick};
+use leptos::{ev, prelude::*, svg};
+
+/// Default colour of the drag-to-zoom selection.
+pub const ZOOM_SELECTION_COLOUR: Colour = Colour::from_rgb(0x9A, 0x9A, 0x9A);
+
+/// Minimum distance (in pixels) the mouse must be dragged to select a range. Shorter drags are treated as clicks.
+const MIN_SELECTION: f64 = 4.0;
+/// Ratio to zoom by on each mouse wheel step.
+const WHEEL_STEP: f64 = 1.25;
+
+/// Zoom and pan interaction on the inner chart area. Pass to the `zoom` prop of a [Chart](crate::Chart) to enable:
+///
+/// - Drag to select an X range to zoom in to.
+/// - Shift and drag to pan. Plain drags select a range so panning needs the shift key.
+/// - Mouse wheel to zoom in and out around the cursor.
+/// - Double-click to reset.
+///
+/// The visible X range is a signal snapped to the nearest data. Clone a `Zoom` and pass it to several charts to keep them in sync:
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # use chrono::prelude::*;
+/// # struct Incident { at: DateTime<Utc>, errors: f64, latency: f64 }
+/// # #[component]
+/// # fn SyncedCharts(data: Signal<Vec<Incident>>) -> impl IntoView {
+/// let zoom = Zoom::default();
+/// // Read (or set) the visible range from your application
+/// let visible = zoom.range;
+/// view! {
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 200.0)
+///         series=Series::new(|i: &Incident| i.at).line(|i: &Incident| i.errors)
+///         data=data
+///         zoom=zoom.clone()
+///     />
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 200.0)
+///         series=Series::new(|i: &Incident| i.at).line(|i: &Incident| i.latency)
+///         data=data
+///         zoom=zoom
+///     />
+/// }
+/// # }
+/// ```
+///
+/// Data is assumed to be sorted by X. The Y axis continues to cover all data.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Zoom<X: Tick> {
+    /// Visible X range (inclusive). `None` shows all data.
+    pub range: RwSignal<Option<(X, X)>>,
+    /// Whether the mouse wheel zooms. Disable to keep the page scrolling over the chart. Default is true.
+    pub wheel: RwSignal<bool>,
+    /// Colour of the drag-to-zoom selection.
+    pub colour: RwSignal<Colour>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Drag {
+    /// SVG coords where the drag started.
+    start: (f64, f64),
+    /// SVG coords where the drag was last applied. Only used when panning.
+    last: (f64, f64),
+    pan: bool,
+}
+
+impl<X: Tick> Default for Zoom<X> {
+    fn default() -> Self {
+        Self {
+            range: RwSignal::default(),
+            wheel: RwSignal::new(true),
+            colour: RwSignal::new(ZOOM_SELECTION_COLOUR),
+        }
+    }
+}
+
+impl<X: Tick> Zoom<X> {
+    /// Creates a new zoom interaction showing all data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the visible X range (inclusive). `None` shows all data.
+    pub fn with_range(self, range: impl Into<Option<(X, X)>>) -> Self {
+        self.range.set(range.into());
+        self
+    }
+
+    /// Sets whether the mouse wheel zooms.
+    pub fn with_wheel(self, wheel: bool) -> Self {
+        self.wheel.set(wheel);
+        self
+    }
+
+    /// Sets the colour of the drag-to-zoom selection.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+/// Zooms a window of data indexes in or out around an anchor index. Zoomed in windows keep at least two data points. Zoomed out windows stop at the first and last data.
+fn zoom_window(
+    (first, last): (usize, usize),
+    anchor: usize,
+    zoom_in: bool,
+    len: usize,
+) -> (usize, usize) {
+    let anchor = anchor.clamp(first, last) as f64;
+    let ratio = if zoom_in {
+        1.0 / WHEEL_STEP
+    } else {
+        WHEEL_STEP
+    };
+    let new_first = anchor - (anchor - first as f64) * ratio;
+    let new_last = anchor + (last as f64 - anchor) * ratio;
+    // Round towards the anchor when zooming in and away when zooming out
+    let (new_first, new_last) = if zoom_in {
+        (new_first.ceil(), new_last.floor())
+    } else {
+        (new_first.floor(), new_last.ceil())
+    };
+    let max = len.saturating_sub(1) as f64;
+    let (new_first, new_last) = (new_first.max(0.0) as usize, new_last.min(max) as usize);
+    if new_last > new_first {
+        (new_first, new_last)
+    } else {
+        (first, last)
+    }
+}
+
+/// Shifts a window of data indexes by an offset. Stops at the first and last data.
+pub(super) fn pan_window(
+    (first, last): (usize, usize),
+    offset: isize,
+    len: usize,
+) -> (usize, usize) {
+    let max = len.saturating_sub(1) as isize;
+    let offset = offset.clamp(-(first as isize), max - last as isize);
+    (
+        (first as isize + offset) as usize,
+        (last as isize + offset) as usize,
+    )
+}
+
+/// SVG coords of a mouse event over a rect covering the inner chart. Note the chart's mouse signal updates after our handlers so lags by one event.
+pub(super) fn event_svg(
+    rect: NodeRef<svg::Rect>,
+    inner: Bounds,
+    ev: &ev::MouseEvent,
+) -> (f64, f64) {
+    let (left, top) = (rect.get_untracked())
+        .map(|rect| {
+            let bounds = rect.get_bounding_client_rect();
+            (bounds.left(), bounds.top())
+        })
+        .unwrap_or_default();
+    (
+        ev.client_x() as f64 - left + inner.left_x(),
+        ev.client_y() as f64 - top + inner.top_y(),
+    )
+}
+
+#[component]
+pub fn Zoom<X: Tick, Y: Tick>(zoom: Zoom<X>, state: State<X, Y>) -> impl IntoView {
+    let Zoom {
+        range,
+        wheel,
+        colour,
+    } = zoom;
+    let data = state.pre.data.clone();
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let mouse = state.mouse_chart;
+    let drag = RwSignal::new(None::<Drag>);
+
+    let rect = NodeRef::<svg::Rect>::new();
+    let event_svg = move |ev: &ev::MouseEvent| event_svg(rect, inner.get_untracked(), ev);
+    // X position of SVG coords
+    let position_x = move |(x, y): (f64, f64)| proj.get_untracked().svg_to_position(x, y).0;
+    // Current window of data indexes
+    let window = {
+        let data = data.clone();
+        move || data.window_untracked(range.get_untracked())
+    };
+    let set_window = {
+        let data = data.clone();
+        move |window| range.set(data.window_range_untracked(window))
+    };
+
+    let on_mousedown = move |ev: ev::MouseEvent| {
+        if ev.button() != 0 {
+            return;
+        }
+        ev.prevent_default();
+        let start = event_svg(&ev);
+        drag.set(Some(Drag {
+            start,
+            last: start,
+            pan: ev.shift_key(),
+        }));
+    };
+
+    let on_mousemove = {
+        let data = data.clone();
+        let window = window.clone();
+        let set_window = set_window.clone();
+        move |ev: ev::MouseEvent| {
+            let Some(Drag {
+                last, pan: true, ..
+            }) = drag.get_untracked()
+            else {
+                return;
+            };
+            let now = event_svg(&ev);
+            let nearest = |svg| data.nearest_index_untracked(position_x(svg));
+            let (Some(from), Some(to), Some(current)) = (nearest(last), nearest(now), window())
+            else {
+                return;
+            };
+            // Data follows the mouse
+            let offset = from as isize - to as isize;
+            if offset != 0 {
+                set_window(pan_window(current, offset, data.len_untracked()));
+                drag.update(|drag| {
+                    if let Some(drag) = drag {
+                        drag.last = now;
+                    }
+                });
+            }
+        }
+    };
+
+    let on_mouseup = {
+        let data = data.clone();
+        let set_window = set_window.clone();
+        move |ev: ev::MouseEvent| {
+            let Some(Drag { start, pan, .. }) = drag.get_untracked() else {
+                return;
+            };
+            drag.set(None);
+            let end = event_svg(&ev);
+            let dragged = if proj.get_untracked().is_horizontal() {
+                (end.1 - start.1).abs()
+            } else {
+                (end.0 - start.0).abs()
+            };
+            if pan || dragged < MIN_SELECTION {
+                return;
+            }
+            let (start, end) = (position_x(start), position_x(end));
+            let (min, max) = (start.min(end), start.max(end));
+            // Need at least two data points to zoom in to
+            if let Some(selected) = data
+                .index_range_untracked(min, max)
+                .filter(|(first, last)| last > first)
+            {
+                set_window(selected);
+            }
+        }
+    };
+
+    let on_wheel = move |ev: ev::WheelEvent| {
+        if !wheel.get_untracked() || ev.delta_y() == 0.0 {
+            return;
+        }
+        ev.prevent_default();
+        let anchor = data.nearest_index_untracked(position_x(event_svg(&ev)));
+        if let (Some(anchor), Some(current)) = (anchor, window()) {
+            let zoom_in = ev.delta_y() < 0.0;
+            set_window(zoom_window(current, anchor, zoom_in, data.len_untracked()));
+        }
+    };
+
+    // Drag-to-zoom selection along the X axis
+    let selection = Memo::new(move |_| {
+        let Drag { start, pan, .. } = drag.get()?;
+        if pan {
+            return None;
+        }
+        let end = mouse.get();
+        let inner = inner.get();
+        let clamp_x = |x: f64| x.clamp(inner.left_x(), inner.right_x());
+        let clamp_y = |y: f64| y.clamp(inner.top_y(), inner.bottom_y());
+        Some(if proj.get().is_horizontal() {
+            let (top, bottom) = (clamp_y(start.1), clamp_y(end.1));
+            Bounds::from_points(
+                inner.left_x(),
+                top.min(bottom),
+                inner.right_x(),
+                top.max(bottom),
+            )
+        } else {
+            let (left, right) = (clamp_x(start.0), clamp_x(end.0));
+            Bounds::from_points(
+                left.min(right),
+                inner.top_y(),
+                left.max(right),
+                inner.bottom_y(),
+            )
+        })
+    });
+
+    view! {
+        <g class="_chartistry_zoom">
+            <Show when=move || selection.get().is_some()>
+                <rect
+                    class="_chartistry_zoom_selection"
+                    x=move || selection.get().unwrap_or_default().left_x()
+                    y=move || selection.get().unwrap_or_default().top_y()
+                    width=move || selection.get().unwrap_or_default().width()
+                    height=move || selection.get().unwrap_or_default().height()
+                    fill=move || colour.get().to_string()
+                    fill-opacity="0.3"
+                    pointer-events="none" />
+            </Show>
+            <rect
+                node_ref=rect
+                x=move || inner.get().left_x()
+                y=move || inner.get().top_y()
+                width=move || inner.get().width()
+                height=move || inner.get().height()
+                fill="transparent"
+                style="cursor: crosshair;"
+                on:mousedown=on_mousedown
+                on:mousemove=on_mousemove
+                on:mouseup=on_mouseup
+                on:mouseleave=move |_| drag.set(None)
+                on:wheel=on_wheel
+                on:dblclick=move |_| range.set(None) />
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_window_in() {
+        // Around the anchor
+        assert_eq!(zoom_window((0, 100), 50, true, 101), (10, 90));
+        assert_eq!(zoom_window((0, 100), 0, true, 101), (0, 80));
+        assert_eq!(zoom_window((0, 3), 1, true, 101), (1, 2));
+        // Keeps at least two points
+        assert_eq!(zoom_window((4, 5), 4, true, 101), (4, 5));
+    }
+
+    #[test]
+    fn test_zoom_window_out() {
+        assert_eq!(zoom_window((10, 90), 50, false, 101), (0, 100));
+        assert_eq!(zoom_window((40, 60), 50, false, 101), (37, 63));
+        assert_eq!(zoom_window((4, 5), 4, false, 101), (4, 6));
+        // Stops at the data
+        assert_eq!(zoom_window((0, 5), 0, false, 6), (0, 5));
+    }
+
+    #[test]
+    fn test_pan_window() {
+        assert_eq!(pan_window((10, 20), 5, 101), (15, 25));
+        assert_eq!(pan_window((10, 20), -5, 101), (5, 15));
+        // Stops at the data
+        assert_eq!(pan_window((10, 20), -50, 101), (0, 10));
+        assert_eq!(pan_window((10, 20), 100, 101), (90, 100));
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{
+    aspect_ratio::KnownAspectRatio,
+    chart::{layout_order, ChartEnv, ChartShell},
+    debug::DebugRect,
+    layout::{EdgeLayout, Layout},
+    overlay::tooltip::{TooltipFrame, TooltipRow},
+    projection::{turn_point, turn_ratio, Projection},
+    series::{Snippet, UseData, UseY},
+    state::{PreState, State},
+    use_watched_node::UseWatchedNode,
+    AspectRatio, Colour, Padding, Scale, Series, Tick, Tooltip,
+};
+use leptos::prelude::*;
+
+/// Default inner radius of a donut. A ratio of the outer radius.
+pub const DONUT_INNER_RADIUS: f64 = 0.6;
+/// Default colour of the "other" slice.
+pub const PIE_OTHER_COLOUR: Colour = Colour::from_rgb(0x9C, 0xA3, 0xAF);
+/// Default colour of slice labels.
+pub const PIE_LABEL_COLOUR: Colour = Colour::from_rgb(0xFF, 0xFF, 0xFF);
+/// Slices smaller than this share of the total are not labelled.
+const LABEL_MIN_SHARE: f64 = 0.04;
+
+/// Describes how to draw a [PieChart]: its inner radius (for donuts), slice labels, and the "other" slice.
+///
+/// # Example
+/// A donut that groups slices under 2% into "Other":
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let pie = Pie::donut()
+///     .with_labels(SliceLabel::Percentage)
+///     .with_other(0.02);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Pie {
+    /// Radius of the hole in the middle. A ratio of the outer radius clamped to 0.0 and 1.0. A pie has no hole, a donut has one. Default is 0.0.
+    pub inner_radius: RwSignal<f64>,
+    /// What to label slices with. Default is [SliceLabel::NameAndPercentage].
+    pub labels: RwSignal<SliceLabel>,
+    /// Colour of slice labels. Default is [PIE_LABEL_COLOUR].
+    pub label_colour: RwSignal<Colour>,
+    /// Slices smaller than this share of the total (e.g., 0.02 for 2%) are grouped into an "other" slice. Only applies if there are two or more of them. Default is 0.0 (no grouping).
+    pub other_threshold: RwSignal<f64>,
+    /// Name of the "other" slice. Default is "Other".
+    pub other_name: RwSignal<String>,
+    /// Colour of the "other" slice. Default is [PIE_OTHER_COLOUR].
+    pub other_colour: RwSignal<Colour>,
+}
+
+/// What to label the slices of a [PieChart] with.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum SliceLabel {
+    /// Slices are not labelled.
+    Hide,
+    /// The name of the slice's line or bar.
+    Name,
+    /// The slice's percentage of the total.
+    Percentage,
+    /// Both the name and percentage.
+    #[default]
+    NameAndPercentage,
+}
+
+impl Pie {
+    /// Creates a new pie.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new donut with an inner radius of [DONUT_INNER_RADIUS].
+    pub fn donut() -> Self {
+        Self::default().with_inner_radius(DONUT_INNER_RADIUS)
+    }
+
+    /// Sets the inner radius as a ratio of the outer radius. Clamped to 0.0 and 1.0.
+    pub fn with_inner_radius(self, inner_radius: f64) -> Self {
+        self.inner_radius.set(inner_radius);
+        self
+    }
+
+    /// Sets what to label slices with.
+    pub fn with_labels(self, labels: impl Into<SliceLabel>) -> Self {
+        self.labels.set(labels.into());
+        self
+    }
+
+    /// Sets the colour of slice labels.
+    pub fn with_label_colour(self, colour: impl Into<Colour>) -> Self {
+        self.label_colour.set(colour.into());
+        self
+    }
+
+    /// Groups slices smaller than `threshold` (a share of the total e.g., 0.02 for 2%) into an "other" slice.
+    pub fn with_other(self, threshold: f64) -> Self {
+        self.other_threshold.set(threshold);
+        self
+    }
+
+    /// Sets the name of the "other" slice.
+    pub fn with_other_name(self, name: impl Into<String>) -> Self {
+        self.other_name.set(name.into());
+        self
+    }
+
+    /// Sets the colour of the "other" slice.
+    pub fn with_other_colour(self, colour: impl Into<Colour>) -> Self {
+        self.other_colour.set(colour.into());
+        self
+    }
+}
+
+impl Default for Pie {
+    fn default() -> Self {
+        Self {
+            inner_radius: RwSignal::new(0.0),
+            labels: RwSignal::default(),
+            label_colour: RwSignal::new(PIE_LABEL_COLOUR),
+            other_threshold: RwSignal::new(0.0),
+            other_name: RwSignal::new("Other".to_string()),
+            other_colour: RwSignal::new(PIE_OTHER_COLOUR),
+        }
+    }
+}
+
+/// A slice of the pie. Measured as a ratio of a full turn clockwise from the top.
+#[derive(Clone, Debug, PartialEq)]
+struct Slice {
+    /// Series in the slice. More than one for the "other" slice.
+    ids: Vec<usize>,
+    value: f64,
+    start: f64,
+    end: f64,
+}
+
+impl Slice {
+    fn is_other(&self) -> bool {
+        self.ids.len() > 1
+    }
+
+    fn share(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// Divides a pie into slices of each (series ID, value) in order. Values that aren't positive are left out. Values under the threshold share of the total are grouped into a final "other" slice when there are two or more of them.
+fn slices(values: &[(usize, f64)], other_threshold: f64) -> Vec<Slice> {
+    let values = (values.iter().copied())
+        .filter(|&(_, value)| value > 0.0 && value.is_finite())
+        .collect::<Vec<_>>();
+    let total = values.iter().map(|&(_, value)| value).sum::<f64>();
+    let (other, mut kept): (Vec<_>, Vec<_>) = (values.iter())
+        .map(|&(id, value)| (vec![id], value))
+        .partition(|&(_, value)| value / total < other_threshold);
+    if other.len() > 1 {
+        let ids = other.iter().flat_map(|(ids, _)| ids.clone()).collect();
+        kept.push((ids, other.iter().map(|&(_, value)| value).sum()));
+    } else {
+        // Nothing to group: keep in order
+        kept = (values.iter())
+            .map(|&(id, value)| (vec![id], value))
+            .collect();
+    }
+    let mut start = 0.0;
+    (kept.into_iter())
+        .map(|(ids, value)| {
+            let end = start + value / total;
+            let slice = Slice {
+                ids,
+                value,
+                start,
+                end,
+            };
+            start = end;
+            slice
+        })
+        .collect()
+}
+
+/// SVG path of an annular sector. Arcs are split in two so that a full turn can be drawn. An inner radius of zero draws a pie slice.
+pub(crate) fn slice_path(
+    centre: (f64, f64),
+    outer: f64,
+    inner: f64,
+    start: f64,
+    end: f64,
+) -> String {
+    let mid = start + (end - start) / 2.0;
+    let [(x1, y1), (x2, y2), (x3, y3)] = [start, mid, end].map(|r| turn_point(centre, outer, r));
+    let [(x4, y4), (x5, y5), (x6, y6)] = [end, mid, start].map(|r| turn_point(centre, inner, r));
+    format!(
+        "M {x1} {y1} A {outer} {outer} 0 0 1 {x2} {y2} A {outer} {outer} 0 0 1 {x3} {y3} \
+        L {x4} {y4} A {inner} {inner} 0 0 0 {x5} {y5} A {inner} {inner} 0 0 0 {x6} {y6} Z"
+    )
+}
+
+fn format_share(share: f64) -> String {
+    format!("{:.1}%", share * 100.0)
+}
+
+/// Renders a pie or donut chart of each line or bar's share of the total.
+///
+/// Takes the same [Series] as a [Chart](crate::Chart). Each line or bar is a slice sized by the sum of its Y values across the data (e.g., pass a single `T` to chart a snapshot). Slices take their name and colour from the series, are drawn clockwise from the top in the same order as the legend, and can be labelled (see [Pie]). Other series (e.g., candlesticks) are ignored.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # struct Traffic { day: f64, search: f64, social: f64, direct: f64 }
+/// # #[component]
+/// # fn DonutComponent() -> impl IntoView {
+/// let data: Signal<Vec<Traffic>> = Signal::default();
+/// view! {
+///     <PieChart
+///         aspect_ratio=AspectRatio::from_outer_ratio(400.0, 300.0)
+///         pie=Pie::donut().with_other(0.02)
+///         right=Legend::middle()
+///         tooltip=Tooltip::left_cursor()
+///         series=Series::new(|t: &Traffic| t.day)
+///             .line(Line::new(|t: &Traffic| t.search).with_name("Search"))
+///             .line(Line::new(|t: &Traffic| t.social).with_name("Social"))
+///             .line(Line::new(|t: &Traffic| t.direct).with_name("Direct"))
+///         data=data
+///     />
+/// }
+/// # }
+/// ```
+///
+/// Edges take the same components as a [Chart](crate::Chart) e.g., a [Legend](crate::Legend) or [RotatedLabel](crate::RotatedLabel). The tooltip shows the slice under the mouse and formats values with its Y ticks.
+#[component]
+pub fn PieChart<T: Send + Sync + 'static, X: Tick>(
+    /// Determines the width and height of the chart. The pie is drawn in the largest circle that fits the inner chart area. See [AspectRatio](AspectRatio) for details.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+
+    /// The height of the font used in the chart. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<Signal<f64>>,
+
+    /// The width of a monospaced character in the font used. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<Signal<f64>>,
+
+    /// Debug mode. If enabled shows lines around components. Default is false.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+
+    /// Padding adds spacing around chart components. Default is the font width.
+    #[prop(into, optional)]
+    padding: Option<Signal<Padding>>,
+
+    /// Top edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    top: Vec<EdgeLayout<X>>,
+    /// Right edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    right: Vec<EdgeLayout<f64>>,
+    /// Bottom edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    bottom: Vec<EdgeLayout<X>>,
+    /// Left edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    left: Vec<EdgeLayout<f64>>,
+
+    /// How to draw the pie. See [Pie] for details. Default is a labelled pie.
+    #[prop(into, optional)]
+    pie: Pie,
+    /// Tooltip to show on mouse hover. See [Tooltip](crate::Tooltip) for details. Default is hidden.
+    #[prop(into, optional)]
+    tooltip: Tooltip<X, f64>,
+
+    /// Series to render. Each line or bar is a slice. See [Series] for details.
+    #[prop(into)]
+    series: Series<T, X, f64>,
+    /// Data to render.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let env = ChartEnv::new(aspect_ratio, font_height, font_width, debug, padding);
+    let (top, left) = layout_order(top, left);
+
+    let data = UseData::new(series, data, Signal::default());
+    let pre = env.pre_state(data);
+
+    view! {
+        <ChartShell env=env.clone() label="PieChart">
+            <RenderPie
+                watch=env.watch.clone()
+                pre_state=pre.clone()
+                aspect_ratio=env.calc
+                top=top.clone()
+                right=right.clone()
+                bottom=bottom.clone()
+                left=left.clone()
+                pie=pie.clone()
+                tooltip=tooltip.clone()
+            />
+        </ChartShell>
+    }
+}
+
+#[component]
+fn RenderPie<X: Tick>(
+    watch: UseWatchedNode,
+    pre_state: PreState<X, f64>,
+    aspect_ratio: Memo<KnownAspectRatio>,
+    top: Vec<EdgeLayout<X>>,
+    right: Vec<EdgeLayout<f64>>,
+    bottom: Vec<EdgeLayout<X>>,
+    left: Vec<EdgeLayout<f64>>,
+    pie: Pie,
+    tooltip: Tooltip<X, f64>,
+) -> impl IntoView {
+    let debug = pre_state.debug;
+
+    // Compose edges. The pie doesn't project data so fill the inner area
+    let (layout, edges) = Layout::compose(&top, &right, &bottom, &left, aspect_ratio, &pre_state);
+    let inner = layout.inner;
+    let projection = Memo::new(move |_| {
+        let unscaled = (None, Scale::Linear);
+        Projection::scaled(inner.get(), false, unscaled, unscaled)
+    });
+    let state = State::new(pre_state, &watch, layout, projection, projection);
+
+    // Render edges
+    let edges = edges
+        .into_iter()
+        .map(|r| r.render(state.clone()))
+        .collect_view();
+
+    // Centre, outer radius, and inner radius
+    let geometry = Memo::new(move |_| {
+        let inner = inner.get();
+        let outer = inner.width().min(inner.height()).max(0.0) / 2.0;
+        let ratio = pie.inner_radius.get().clamp(0.0, 1.0);
+        ((inner.centre_x(), inner.centre_y()), outer, outer * ratio)
+    });
+
+    // Slices of series with a colour
+    let series = state.pre.data.series;
+    let totals = state.pre.data.series_totals();
+    let slices = Memo::new(move |_| {
+        let values = totals.with(|totals| {
+            (series.read().iter())
+                .filter(|use_y| use_y.colour().is_some())
+                .map(|use_y| (use_y.id, totals.get(&use_y.id).copied().unwrap_or_default()))
+                .collect::<Vec<_>>()
+        });
+        slices(&values, pie.other_threshold.get())
+    });
+    let find_series = move |id: usize| series.read().iter().find(|s| s.id == id).cloned();
+
+    let paths = move || {
+        let (centre, outer, inner) = geometry.get();
+        (slices.get().into_iter())
+            .map(|slice| {
+                let fill = if slice.is_other() {
+                    pie.other_colour.get()
+                } else {
+                    (find_series(slice.ids[0]))
+                        .and_then(|use_y| use_y.colour())
+                        .map(|colour| colour.get())
+                        .unwrap_or(pie.other_colour.get())
+                };
+                view! {
+                    <path
+                        d=slice_path(centre, outer, inner, slice.start, slice.end)
+                        fill=fill.to_string() />
+                }
+            })
+            .collect_view()
+    };
+
+    let labels = move || {
+        let (centre, outer, inner) = geometry.get();
+        // Middle of a donut's ring or towards the edge of a pie
+        let radius = if inner > 0.0 {
+            (outer + inner) / 2.0
+        } else {
+            outer * 0.6
+        };
+        let labels = pie.labels.get();
+        (slices.get().into_iter())
+            .filter(|slice| labels != SliceLabel::Hide && slice.share() >= LABEL_MIN_SHARE)
+            .map(|slice| {
+                let name = if slice.is_other() {
+                    pie.other_name.get()
+                } else {
+                    (find_series(slice.ids[0]))
+                        .map(|use_y| use_y.name.get())
+                        .unwrap_or_default()
+                };
+                let text = match labels {
+                    SliceLabel::Name => name,
+                    SliceLabel::Percentage => format_share(slice.share()),
+                    _ => format!("{name} {}", format_share(slice.share())),
+                };
+                let (x, y) = turn_point(centre, radius, slice.start + slice.share() / 2.0);
+                view! {
+                    <text x=x y=y text-anchor="middle" dominant-baseline="middle">
+                        {text}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    // Slice under the mouse
+    let (mouse_chart, hover_inner) = (state.mouse_chart, state.hover_inner);
+    let hovered = Memo::new(move |_| {
+        if !hover_inner.get() {
+            return None;
+        }
+        let ((cx, cy), outer, inner) = geometry.get();
+        let (mouse_x, mouse_y) = mouse_chart.get();
+        let (dx, dy) = (mouse_x - cx, mouse_y - cy);
+        let radius = dx.hypot(dy);
+        if radius < inner || radius > outer {
+            return None;
+        }
+        let ratio = turn_ratio(dx, dy);
+        (slices.get().into_iter()).find(|slice| slice.start <= ratio && ratio < slice.end)
+    });
+
+    let outer = state.layout.outer;
+    let font_height = state.pre.font_height;
+    view! {
+        <svg
+            width=move || format!("{}px", outer.get().width())
+            height=move || format!("{}px", outer.get().height())
+            viewBox=move || outer.with(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+            style="display: block; overflow: visible;">
+            <DebugRect label="RenderPie" debug=debug bounds=vec![outer.into()] />
+            {edges}
+            <g class="_chartistry_pie">{paths}</g>
+            <g
+                class="_chartistry_pie_labels"
+                fill=move || pie.label_colour.get().to_string()
+                font-size=move || font_height.get()>
+                {labels}
+            </g>
+        </svg>
+        <PieTooltip tooltip=tooltip pie=pie state=state hovered=hovered />
+    }
+}
+
+#[component]
+fn PieTooltip<X: Tick>(
+    tooltip: Tooltip<X, f64>,
+    pie: Pie,
+    state: State<X, f64>,
+    hovered: Memo<Option<Slice>>,
+) -> impl IntoView {
+    let Tooltip {
+        placement,
+        cursor_distance,
+        y_ticks,
+        ..
+    } = tooltip;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let padding = state.pre.padding;
+    let inner = state.layout.inner;
+    let series = state.pre.data.series;
+    let totals = state.pre.data.series_totals();
+
+    let y_format = y_ticks.format;
+    let avail_height = Signal::derive(move || inner.read().height());
+    let y_ticks = y_ticks.generate_y(&state.pre, avail_height);
+    let format_value = move |value: f64| (y_format.get())(&value, y_ticks.read().state.as_ref());
+
+    let heading = move || {
+        hovered.get().map(|slice| {
+            let share = format_share(slice.share());
+            if slice.is_other() {
+                format!("{} {share}", pie.other_name.get())
+            } else {
+                share
+            }
+        })
+    };
+
+    // Each series in the slice with its value and share of the total
+    let rows = move || {
+        let Some(slice) = hovered.get() else {
+            return Vec::new();
+        };
+        (series.get().into_iter())
+            .filter(|use_y| slice.ids.contains(&use_y.id))
+            .map(|use_y| {
+                let value = totals.with(|totals| totals.get(&use_y.id).copied());
+                let value = value.unwrap_or_default();
+                let share = value / (slice.value / slice.share());
+                let value = format!("{} ({})", format_value(value), format_share(share));
+                (use_y, value)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let series_tr = {
+        let state = state.clone();
+        move |(series, value): (UseY, String)| {
+            let state = state.clone();
+            view! {
+                <TooltipRow value=value font_height=font_height font_width=font_width>
+                    <Snippet series=series state=state />
+                </TooltipRow>
+            }
+        }
+    };
+
+    view! {
+        <TooltipFrame
+            show=Signal::derive(move || hovered.with(Option::is_some))
+            placement=placement
+            cursor_distance=cursor_distance
+            mouse_page=state.mouse_page
+            heading=Signal::derive(move || heading().unwrap_or_default())
+            padding=padding
+            font_height=font_height
+            debug=debug>
+            <For
+                each=rows
+                key=|(series, value)| (series.id, value.to_owned())
+                children=series_tr.clone()
+            />
+        </TooltipFrame>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shares(slices: &[Slice]) -> Vec<(Vec<usize>, f64)> {
+        (slices.iter())
+            .map(|slice| (slice.ids.clone(), slice.share()))
+            .collect()
+    }
+
+    #[test]
+    fn test_slices() {
+        let values = [
+            (0, 4.0),
+            (1, 1.0),
+            (2, 2.0),
+            (3, -4.0),
+            (4, 1.0),
+            (5, f64::NAN),
+        ];
+        // In order, skipping values that can't be shown
+        let all = slices(&values, 0.0);
+        assert_eq!(
+            shares(&all),
+            vec![
+                (vec![0], 0.5),
+                (vec![1], 0.125),
+                (vec![2], 0.25),
+                (vec![4], 0.125),
+            ]
+        );
+        assert_eq!(all[2].start, 0.625);
+        assert_eq!(all[3].end, 1.0);
+        // Small slices are grouped last
+        let grouped = slices(&values, 0.2);
+        assert_eq!(
+            shares(&grouped),
+            vec![(vec![0], 0.5), (vec![2], 0.25), (vec![1, 4], 0.25)]
+        );
+        assert_eq!(grouped[2].value, 2.0);
+        assert!(grouped[2].is_other());
+        // A single small slice isn't grouped
+        assert_eq!(slices(&values[..3], 0.2).len(), 3);
+        // Nothing to show
+        assert_eq!(slices(&[(0, 0.0)], 0.0), vec![]);
+    }
+}
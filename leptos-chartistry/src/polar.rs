@@ -0,0 +1,472 @@
+use crate::{
+    aspect_ratio::KnownAspectRatio,
+    chart::{layout_order, ChartEnv, ChartShell},
+    debug::DebugRect,
+    layout::{EdgeLayout, Layout},
+    overlay::tooltip::Tooltip,
+    pie::slice_path,
+    projection::{turn_point, PolarProjection, Projection},
+    series::{group_count, RadarPath, Range, UseData, UseY},
+    state::{PreState, State},
+    use_watched_node::UseWatchedNode,
+    AspectRatio, Colour, Padding, Scale, Series, Tick, TickLabels, YAxis, GRID_LINE_COLOUR,
+};
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+/// Describes the grid of a [PolarChart]: spokes and labels around the outside at X ticks and circular grid lines at Y ticks.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let polar: Polar<f64, f64> = Polar::default()
+///     .with_y_ticks(TickLabels::aligned_floats().with_format(|y, _| format!("{y}%")))
+///     .with_grid_colour(Colour::from_rgb(0xCC, 0xCC, 0xCC));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Polar<X: Tick, Y: Tick> {
+    /// Angular ticks. Labelled around the outside with a spoke from the centre to each. Default is generated from X.
+    pub x_ticks: TickLabels<X>,
+    /// Radial ticks. Labelled up the top spoke with a circular grid line at each. Default is generated from Y.
+    pub y_ticks: TickLabels<Y>,
+    /// Colour of the grid lines and spokes. Default is [GRID_LINE_COLOUR].
+    pub grid_colour: RwSignal<Colour>,
+    /// Width of the grid lines and spokes. Default is 1.0.
+    pub grid_width: RwSignal<f64>,
+}
+
+impl<X: Tick, Y: Tick> Polar<X, Y> {
+    /// Sets the angular ticks.
+    pub fn with_x_ticks(mut self, ticks: impl Into<TickLabels<X>>) -> Self {
+        self.x_ticks = ticks.into();
+        self
+    }
+
+    /// Sets the radial ticks.
+    pub fn with_y_ticks(mut self, ticks: impl Into<TickLabels<Y>>) -> Self {
+        self.y_ticks = ticks.into();
+        self
+    }
+
+    /// Sets the colour of the grid lines and spokes.
+    pub fn with_grid_colour(self, colour: impl Into<Colour>) -> Self {
+        self.grid_colour.set(colour.into());
+        self
+    }
+
+    /// Sets the width of the grid lines and spokes.
+    pub fn with_grid_width(self, width: f64) -> Self {
+        self.grid_width.set(width);
+        self
+    }
+}
+
+impl<X: Tick, Y: Tick> Default for Polar<X, Y> {
+    fn default() -> Self {
+        Self {
+            x_ticks: TickLabels::default(),
+            y_ticks: TickLabels::default(),
+            grid_colour: RwSignal::new(GRID_LINE_COLOUR),
+            grid_width: RwSignal::new(1.0),
+        }
+    }
+}
+
+/// SVG text anchors for a label placed around a circle at a ratio of a full turn. Labels on the right start at their point and labels on the left end at it.
+fn label_anchor(turn: f64) -> (&'static str, &'static str) {
+    let turn = turn.rem_euclid(1.0);
+    let horizontal = match turn {
+        t if t.abs() < 0.02 || (t - 0.5).abs() < 0.02 || t > 0.98 => "middle",
+        t if t < 0.5 => "start",
+        _ => "end",
+    };
+    let vertical = match turn {
+        t if (t - 0.25).abs() < 0.02 || (t - 0.75).abs() < 0.02 => "middle",
+        t if t > 0.25 && t < 0.75 => "hanging",
+        _ => "auto",
+    };
+    (horizontal, vertical)
+}
+
+/// The (outer, inner) radii of a bar's wedge between its value and base. Negative bars are below their base.
+fn wedge_radii(value: f64, base: f64) -> ((f64, f64), bool) {
+    ((value.max(base), value.min(base)), value < base)
+}
+
+/// Renders a polar chart: X is drawn as an angle clockwise from the top and Y as the distance from the centre.
+///
+/// Takes the same [Series] as a [Chart](crate::Chart). Lines are drawn as closed shapes giving a radar (spider) chart: use [Line::with_fill](crate::Line::with_fill) to fill them. Bars are drawn as wedges giving a rose (polar bar) chart and keep their gaps, groups, stacks, and negative colours. Wedges extend from zero or the centre when zero isn't shown. Error bars and other series (e.g., candlesticks) are ignored.
+///
+/// Each X value takes an equal share of the turn like the bands of a bar chart. This suits categories e.g., the dimensions of a radar chart. The bottom of the Y range is drawn at the centre: use [Series::with_min_y] to start from zero.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # struct Health { check: Category, api: f64, web: f64 }
+/// # #[component]
+/// # fn RadarComponent() -> impl IntoView {
+/// let data: Signal<Vec<Health>> = Signal::default();
+/// view! {
+///     <PolarChart
+///         aspect_ratio=AspectRatio::from_outer_ratio(400.0, 400.0)
+///         right=Legend::middle()
+///         tooltip=Tooltip::left_cursor()
+///         series=Series::new(|h: &Health| h.check.clone())
+///             .with_min_y(0.0)
+///             .with_max_y(100.0)
+///             .line(Line::new(|h: &Health| h.api).with_name("API").with_fill(AreaFill::Zero))
+///             .line(Line::new(|h: &Health| h.web).with_name("Web").with_fill(AreaFill::Zero))
+///         data=data
+///     />
+/// }
+/// # }
+/// ```
+///
+/// Edges take the same components as a [Chart](crate::Chart) e.g., a [Legend](crate::Legend) or [RotatedLabel](crate::RotatedLabel). The tooltip shows the X value nearest to the mouse's angle.
+#[component]
+pub fn PolarChart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+    /// Determines the width and height of the chart. The polar grid is drawn in the largest circle that fits the inner chart area with room for its labels. See [AspectRatio](AspectRatio) for details.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+
+    /// The height of the font used in the chart. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<Signal<f64>>,
+
+    /// The width of a monospaced character in the font used. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<Signal<f64>>,
+
+    /// Debug mode. If enabled shows lines around components. Default is false.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+
+    /// Padding adds spacing around chart components. Default is the font width.
+    #[prop(into, optional)]
+    padding: Option<Signal<Padding>>,
+
+    /// Top edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    top: Vec<EdgeLayout<X>>,
+    /// Right edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    right: Vec<EdgeLayout<Y>>,
+    /// Bottom edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    bottom: Vec<EdgeLayout<X>>,
+    /// Left edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
+    #[prop(into, optional)]
+    left: Vec<EdgeLayout<Y>>,
+
+    /// How to draw the polar grid. See [Polar] for details. Default is generated ticks.
+    #[prop(into, optional)]
+    polar: Polar<X, Y>,
+    /// Tooltip to show on mouse hover. See [Tooltip](crate::Tooltip) for details. Default is hidden.
+    #[prop(into, optional)]
+    tooltip: Tooltip<X, Y>,
+
+    /// Series to render. Lines are drawn as radar shapes and bars as wedges. See [Series] for details.
+    #[prop(into)]
+    series: Series<T, X, Y>,
+    /// Data to render. Must be sorted.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let env = ChartEnv::new(aspect_ratio, font_height, font_width, debug, padding);
+    let (top, left) = layout_order(top, left);
+
+    let data = UseData::new(series, data, Signal::default());
+    let pre = env.pre_state(data);
+
+    view! {
+        <ChartShell env=env.clone() label="PolarChart">
+            <RenderPolar
+                watch=env.watch.clone()
+                pre_state=pre.clone()
+                aspect_ratio=env.calc
+                top=top.clone()
+                right=right.clone()
+                bottom=bottom.clone()
+                left=left.clone()
+                polar=polar.clone()
+                tooltip=tooltip.clone()
+            />
+        </ChartShell>
+    }
+}
+
+#[component]
+fn RenderPolar<X: Tick, Y: Tick>(
+    watch: UseWatchedNode,
+    pre_state: PreState<X, Y>,
+    aspect_ratio: Memo<KnownAspectRatio>,
+    top: Vec<EdgeLayout<X>>,
+    right: Vec<EdgeLayout<Y>>,
+    bottom: Vec<EdgeLayout<X>>,
+    left: Vec<EdgeLayout<Y>>,
+    polar: Polar<X, Y>,
+    tooltip: Tooltip<X, Y>,
+) -> impl IntoView {
+    let debug = pre_state.debug;
+    let font_height = pre_state.font_height;
+    let font_width = pre_state.font_width;
+    let padding = pre_state.padding;
+
+    // Compose edges. Edges don't project data so fill the inner area
+    let (layout, edges) = Layout::compose(&top, &right, &bottom, &left, aspect_ratio, &pre_state);
+    let inner = layout.inner;
+    let projection = Memo::new(move |_| {
+        let unscaled = (None, Scale::Linear);
+        Projection::scaled(inner.get(), false, unscaled, unscaled)
+    });
+
+    // Angular ticks are sized by the largest circle that fits. Radial ticks by its radius
+    let x_format = polar.x_ticks.format;
+    let x_ticks = polar.x_ticks.generate_x(
+        &pre_state,
+        Signal::derive(move || inner.with(|inner| inner.width().min(inner.height())) * PI),
+    );
+    let x_labels = Memo::new(move |_| {
+        let format = x_format.get();
+        x_ticks.with(|gen| {
+            (gen.ticks.iter())
+                .map(|tick| (tick.position(), (format)(tick, gen.state.as_ref())))
+                .collect::<Vec<_>>()
+        })
+    });
+    let y_format = polar.y_ticks.format;
+    let y_ticks = polar.y_ticks.generate_y(
+        &pre_state,
+        Signal::derive(move || inner.with(|inner| inner.width().min(inner.height())) / 2.0),
+    );
+    let y_labels = Memo::new(move |_| {
+        let format = y_format.get();
+        y_ticks.with(|gen| {
+            (gen.ticks.iter())
+                .map(|tick| (tick.position(), (format)(tick, gen.state.as_ref())))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Largest circle that leaves room for the angular labels
+    let geometry = Memo::new(move |_| {
+        let inner = inner.get();
+        let chars = x_labels.with(|labels| {
+            (labels.iter())
+                .map(|(_, label)| label.chars().count())
+                .max()
+                .unwrap_or_default()
+        });
+        let gap = font_width.get() / 2.0;
+        let label_width = chars as f64 * font_width.get() + gap;
+        let label_height = font_height.get() + gap;
+        let radius = (inner.width() / 2.0 - label_width).min(inner.height() / 2.0 - label_height);
+        ((inner.centre_x(), inner.centre_y()), radius.max(0.0))
+    });
+
+    let data = pre_state.data.clone();
+    let mk_polar = |range_y: Memo<Range<Y>>, y_scale: RwSignal<Scale>| {
+        let (range_x, x_scale, x_bands) = (data.range_x, data.x_scale, data.x_bands);
+        Memo::new(move |_| {
+            let (centre, radius) = geometry.get();
+            let range_x = (range_x.read().positions(), x_scale.get());
+            let range_y = (range_y.read().positions(), y_scale.get());
+            PolarProjection::scaled(centre, radius, x_bands.get(), range_x, range_y)
+        })
+    };
+    let primary = mk_polar(data.range_y, data.y_scale);
+    let secondary = mk_polar(data.secondary_range_y, data.secondary_y_scale);
+
+    // Tooltips find data by the mouse's angle and distance
+    let mut state = State::new(pre_state, &watch, layout, projection, projection);
+    let mouse_chart = state.mouse_chart;
+    let hover_position = Memo::new(move |_| {
+        let (mouse_x, mouse_y) = mouse_chart.get();
+        primary.get().svg_to_position(mouse_x, mouse_y)
+    });
+    state.hover_position_x = Memo::new(move |_| hover_position.get().0);
+    state.hover_position_y = Memo::new(move |_| hover_position.get().1);
+
+    // Render edges
+    let edges = edges
+        .into_iter()
+        .map(|r| r.render(state.clone()))
+        .collect_view();
+
+    let rings = move || {
+        let proj = primary.get();
+        let ((cx, cy), radius) = (proj.centre(), proj.radius());
+        (y_labels.get().into_iter())
+            .map(|(position, _)| proj.y_to_radius(position))
+            .filter(|r| *r > 0.0 && *r < radius)
+            .chain(std::iter::once(radius))
+            .map(|r| view!(<circle cx=cx cy=cy r=r fill="none" />))
+            .collect_view()
+    };
+
+    let spokes = move || {
+        let proj = primary.get();
+        let (centre, radius) = (proj.centre(), proj.radius());
+        (x_labels.get().into_iter())
+            .map(|(position, _)| {
+                let (x, y) = turn_point(centre, radius, proj.x_to_turn(position));
+                view!(<line x1=centre.0 y1=centre.1 x2=x y2=y />)
+            })
+            .collect_view()
+    };
+
+    let angular_labels = move || {
+        let proj = primary.get();
+        let (centre, radius) = (proj.centre(), proj.radius());
+        let gap = font_width.get() / 2.0;
+        (x_labels.get().into_iter())
+            .map(|(position, label)| {
+                let turn = proj.x_to_turn(position);
+                let (x, y) = turn_point(centre, radius + gap, turn);
+                let (anchor, baseline) = label_anchor(turn);
+                view! {
+                    <text x=x y=y text-anchor=anchor dominant-baseline=baseline>
+                        {label}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    let radial_labels = move || {
+        let proj = primary.get();
+        let ((cx, cy), radius) = (proj.centre(), proj.radius());
+        let gap = padding.get().width() / 4.0;
+        (y_labels.get().into_iter())
+            .map(|(position, label)| (proj.y_to_radius(position), label))
+            .filter(|(r, _)| (0.0..=radius).contains(r))
+            .map(|(r, label)| {
+                view! {
+                    <text x=cx + gap y=cy - r dominant-baseline="middle">
+                        {label}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    // Lines and bars projected onto their Y axis
+    let series = data.series;
+    let groups = Memo::new(move |_| group_count(&series.get()));
+    let render_series = move |use_y: UseY| {
+        let y_axis = use_y.y_axis;
+        let proj = Signal::derive(move || match y_axis.get() {
+            YAxis::Primary => primary.get(),
+            YAxis::Secondary => secondary.get(),
+        });
+        let positions = data.series_positions(use_y.id);
+        if let Some(line) = use_y.line() {
+            let positions = Signal::derive(move || {
+                let proj = proj.get();
+                positions.with(|positions| {
+                    (positions.iter())
+                        .map(|&(x, y)| proj.position_to_svg(x, y))
+                        .collect::<Vec<_>>()
+                })
+            });
+            return Some(view!(<RadarPath line=line.clone() positions=positions />).into_any());
+        }
+        let bar = use_y.bar()?.clone();
+        let bases = data.series_bases(use_y.id);
+        let totals = data.series_is_total(use_y.id);
+        let wedges = move || {
+            let proj = proj.get();
+            let (centre, radius) = (proj.centre(), proj.radius());
+            let (offset, width) = bar.group_band(proj.band(), groups.get());
+            let bases = bases.get();
+            let totals = totals.get();
+            let to_radius = |y: f64| proj.y_to_radius(y).clamp(0.0, radius);
+            // Log scales can't show zero so use the centre instead
+            let zero = Some(to_radius(0.0)).filter(|r| !r.is_nan()).unwrap_or(0.0);
+            positions.with(|positions| {
+                (positions.iter().enumerate())
+                    .filter(|(_, (x, y))| !(x.is_nan() || y.is_nan()))
+                    .map(|(index, &(x, y))| {
+                        // Stacked bars start from the bar beneath. Others from zero
+                        let base = (bases.get(index).copied())
+                            .filter(|base| base.is_finite())
+                            .map_or(zero, to_radius);
+                        let ((outer, inner), negative) = wedge_radii(to_radius(y), base);
+                        let total = totals.get(index).copied().unwrap_or_default();
+                        let start = proj.x_to_turn(x) + offset;
+                        view! {
+                            <path
+                                d=slice_path(centre, outer, inner, start, start + width)
+                                fill=bar.fill(total, negative).to_string() />
+                        }
+                    })
+                    .collect_view()
+            })
+        };
+        Some(view!(<g class="_chartistry_rose_bar">{wedges}</g>).into_any())
+    };
+
+    let outer = state.layout.outer;
+    let (grid_colour, grid_width) = (polar.grid_colour, polar.grid_width);
+    view! {
+        <svg
+            width=move || format!("{}px", outer.get().width())
+            height=move || format!("{}px", outer.get().height())
+            viewBox=move || outer.with(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+            style="display: block; overflow: visible;">
+            <DebugRect label="RenderPolar" debug=debug bounds=vec![outer.into()] />
+            {edges}
+            <g
+                class="_chartistry_polar_grid"
+                stroke=move || grid_colour.get().to_string()
+                stroke-width=grid_width>
+                {rings}
+                {spokes}
+            </g>
+            <g class="_chartistry_polar_series">
+                <For
+                    each=move || series.get()
+                    key=|use_y| use_y.id
+                    children=render_series
+                />
+            </g>
+            <g class="_chartistry_polar_labels" font-size=move || font_height.get()>
+                {angular_labels}
+                {radial_labels}
+            </g>
+        </svg>
+        <Tooltip tooltip=tooltip state=state />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_anchor() {
+        assert_eq!(label_anchor(0.0), ("middle", "auto"));
+        assert_eq!(label_anchor(0.125), ("start", "auto"));
+        assert_eq!(label_anchor(0.25), ("start", "middle"));
+        assert_eq!(label_anchor(0.5), ("middle", "hanging"));
+        assert_eq!(label_anchor(0.75), ("end", "middle"));
+        assert_eq!(label_anchor(0.9), ("end", "auto"));
+        // Wraps around
+        assert_eq!(label_anchor(-0.25), ("end", "middle"));
+    }
+
+    #[test]
+    fn test_wedge_radii() {
+        assert_eq!(wedge_radii(50.0, 20.0), ((50.0, 20.0), false));
+        // Negative bars extend inwards from their base
+        let ((outer, inner), negative) = wedge_radii(20.0, 50.0);
+        assert_eq!((outer, inner, negative), (50.0, 20.0, true));
+        // Wedge of the first quarter turn starts on the base's ring at the top
+        let path = slice_path((100.0, 100.0), outer, inner, 0.0, 0.25);
+        assert!(path.starts_with("M 100 50 A 50 50"), "{path}");
+        assert!(path.contains("A 20 20"), "{path}");
+    }
+}
@@ -1,10 +1,67 @@
 use crate::bounds::Bounds;
+use std::f64::consts::{LN_10, TAU};
+
+/// How positions are mapped onto an axis. See [Series::with_x_scale](crate::Series::with_x_scale) and [Series::with_y_scale](crate::Series::with_y_scale).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Scale {
+    /// Evenly spaced values. This is the default.
+    #[default]
+    Linear,
+    /// Base 10 logarithmic scale. Values must be positive: zero and negative values are treated as missing data.
+    Log10,
+    /// Base 2 logarithmic scale. Values must be positive: zero and negative values are treated as missing data.
+    Log2,
+    /// Symmetric logarithmic scale. Behaves like a base 10 log scale for large values while handling zero and negative values. Uses `sign(v) * log10(1 + |v|)`.
+    Symlog,
+}
+
+impl Scale {
+    /// Maps a position onto the scale. Returns `f64::NAN` if the position can't be shown.
+    pub(crate) fn apply(self, v: f64) -> f64 {
+        match self {
+            Scale::Linear => v,
+            Scale::Log10 if v > 0.0 => v.log10(),
+            Scale::Log2 if v > 0.0 => v.log2(),
+            Scale::Log10 | Scale::Log2 => f64::NAN,
+            Scale::Symlog => v.signum() * v.abs().ln_1p() / LN_10,
+        }
+    }
+
+    /// Inverse of [Scale::apply].
+    pub(crate) fn invert(self, v: f64) -> f64 {
+        match self {
+            Scale::Linear => v,
+            Scale::Log10 => 10_f64.powf(v),
+            Scale::Log2 => v.exp2(),
+            Scale::Symlog => v.signum() * (10_f64.powf(v.abs()) - 1.0),
+        }
+    }
+
+    /// Returns true if the position can be shown on the scale.
+    pub(crate) fn contains(self, v: f64) -> bool {
+        self.apply(v).is_finite()
+    }
+}
+
+/// Maps a data range onto a scale. Ranges that can't be shown are empty.
+fn apply_range(range: Option<(f64, f64)>, scale: Scale) -> (f64, f64) {
+    range
+        .map(|(first, last)| (scale.apply(first), scale.apply(last)))
+        .filter(|(first, last)| first.is_finite() && last.is_finite())
+        .unwrap_or_default()
+}
 
 /// A projection converts between data and SVG coordinates. SVG has zero in the top left corner. Data coordinates have zero in the bottom left.
+///
+/// A horizontal projection draws the X axis vertically (top to bottom) and the Y axis horizontally (left to right).
 #[derive(Clone, Debug, PartialEq)]
 pub struct Projection {
     // SVG bounds
     bounds: Bounds,
+    horizontal: bool,
+    scale_x: Scale,
+    scale_y: Scale,
     // Data offset
     left_x: f64,
     bottom_y: f64,
@@ -14,16 +71,31 @@ pub struct Projection {
 }
 
 impl Projection {
-    pub fn new(bounds: Bounds, range_x: Option<(f64, f64)>, range_y: Option<(f64, f64)>) -> Self {
-        let (left_x, right_x) = range_x.unwrap_or_default();
-        let (bottom_y, top_y) = range_y.unwrap_or_default();
+    /// Creates a projection with a scale for each axis. Horizontal projections draw X top to bottom and Y left to right.
+    pub fn scaled(
+        bounds: Bounds,
+        horizontal: bool,
+        (range_x, scale_x): (Option<(f64, f64)>, Scale),
+        (range_y, scale_y): (Option<(f64, f64)>, Scale),
+    ) -> Self {
+        let (left_x, right_x) = apply_range(range_x, scale_x);
+        let (bottom_y, top_y) = apply_range(range_y, scale_y);
+        // SVG lengths of each data axis
+        let (x_length, y_length) = if horizontal {
+            (bounds.height(), bounds.width())
+        } else {
+            (bounds.width(), bounds.height())
+        };
         // If the range is zero, skip projection
         let width = right_x - left_x;
-        let x_mult = bounds.width() / if width == 0.0 { 0.5 } else { width };
+        let x_mult = x_length / if width == 0.0 { 0.5 } else { width };
         let height = top_y - bottom_y;
-        let y_mult = bounds.height() / if height == 0.0 { 0.5 } else { height };
+        let y_mult = y_length / if height == 0.0 { 0.5 } else { height };
         Projection {
             bounds,
+            horizontal,
+            scale_x,
+            scale_y,
             left_x,
             bottom_y,
             x_mult,
@@ -31,8 +103,19 @@ impl Projection {
         }
     }
 
+    /// Returns true if the X axis is drawn vertically.
+    pub fn is_horizontal(&self) -> bool {
+        self.horizontal
+    }
+
     /// Converts a data point to SVG view coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
     pub fn position_to_svg(&self, x: f64, y: f64) -> (f64, f64) {
+        let (x, y) = (self.scale_x.apply(x), self.scale_y.apply(y));
+        if self.horizontal {
+            let svg_x = self.bounds.left_x() + (y - self.bottom_y) * self.y_mult;
+            let svg_y = self.bounds.top_y() + (x - self.left_x) * self.x_mult;
+            return (svg_x, svg_y);
+        }
         let x = self.bounds.left_x() + (x - self.left_x) * self.x_mult;
         let y = self.bounds.bottom_y() - (y - self.bottom_y) * self.y_mult;
         (x, y)
@@ -40,16 +123,194 @@ impl Projection {
 
     /// Converts an SVG point to data coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
     pub fn svg_to_position(&self, x: f64, y: f64) -> (f64, f64) {
-        let x = self.left_x + (x - self.bounds.left_x()) / self.x_mult;
-        let y = self.bottom_y - (y - self.bounds.bottom_y()) / self.y_mult;
-        (x, y)
+        let (x, y) = if self.horizontal {
+            let pos_x = self.left_x + (y - self.bounds.top_y()) / self.x_mult;
+            let pos_y = self.bottom_y + (x - self.bounds.left_x()) / self.y_mult;
+            (pos_x, pos_y)
+        } else {
+            let x = self.left_x + (x - self.bounds.left_x()) / self.x_mult;
+            let y = self.bottom_y - (y - self.bounds.bottom_y()) / self.y_mult;
+            (x, y)
+        };
+        (self.scale_x.invert(x), self.scale_y.invert(y))
+    }
+
+    /// Converts the data origin to SVG coordinates. Scales that can't show zero (e.g., log scales) use the start of their axis instead: the bottom of Y or the left (top if horizontal) of X.
+    pub fn svg_zero(&self) -> (f64, f64) {
+        let (svg_x, svg_y) = self.position_to_svg(0.0, 0.0);
+        let (start_x, start_y) = if self.horizontal {
+            (self.bounds.left_x(), self.bounds.top_y())
+        } else {
+            (self.bounds.left_x(), self.bounds.bottom_y())
+        };
+        let or_start = |svg: f64, start: f64| if svg.is_finite() { svg } else { start };
+        (or_start(svg_x, start_x), or_start(svg_y, start_y))
+    }
+
+    /// Converts an X position to an SVG coordinate along the axis X is drawn on. This is the SVG x coordinate unless the projection is horizontal.
+    pub fn x_to_svg(&self, x: f64) -> f64 {
+        let (svg_x, svg_y) = self.position_to_svg(x, 0.0);
+        if self.horizontal {
+            svg_y
+        } else {
+            svg_x
+        }
+    }
+
+    /// Converts a Y position to an SVG coordinate along the axis Y is drawn on. This is the SVG y coordinate unless the projection is horizontal.
+    pub fn y_to_svg(&self, y: f64) -> f64 {
+        let (svg_x, svg_y) = self.position_to_svg(0.0, y);
+        if self.horizontal {
+            svg_x
+        } else {
+            svg_y
+        }
+    }
+}
+
+/// A polar projection converts between data and SVG coordinates around a centre. X is the angle measured clockwise from the top and Y is the distance from the centre.
+///
+/// The X axis is divided into bands (one per X value, like bars) that make up a full turn. This keeps the first and last X values apart. The bottom of the Y range is drawn at the centre and the top at the radius.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolarProjection {
+    centre: (f64, f64),
+    radius: f64,
+    scale_x: Scale,
+    scale_y: Scale,
+    // Data offset
+    left_x: f64,
+    bottom_y: f64,
+    // Turns per X
+    x_mult: f64,
+    // Radius per Y
+    y_mult: f64,
+    // Turns per X band
+    band: f64,
+}
+
+impl PolarProjection {
+    /// Creates a polar projection with a scale for each axis. The X range is divided into `bands` (at least one) around a full turn.
+    pub fn scaled(
+        centre: (f64, f64),
+        radius: f64,
+        bands: usize,
+        (range_x, scale_x): (Option<(f64, f64)>, Scale),
+        (range_y, scale_y): (Option<(f64, f64)>, Scale),
+    ) -> Self {
+        let (left_x, right_x) = apply_range(range_x, scale_x);
+        let (bottom_y, top_y) = apply_range(range_y, scale_y);
+        // The first to last X covers all but the last band
+        let band = 1.0 / bands.max(1) as f64;
+        let width = right_x - left_x;
+        let x_mult = if width == 0.0 {
+            0.0
+        } else {
+            (1.0 - band) / width
+        };
+        // If the range is zero, skip projection
+        let height = top_y - bottom_y;
+        let y_mult = radius / if height == 0.0 { 0.5 } else { height };
+        PolarProjection {
+            centre,
+            radius,
+            scale_x,
+            scale_y,
+            left_x,
+            bottom_y,
+            x_mult,
+            y_mult,
+            band,
+        }
+    }
+
+    /// SVG coordinates of the centre.
+    pub fn centre(&self) -> (f64, f64) {
+        self.centre
+    }
+
+    /// SVG length of the radius: the top of the Y range.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Width of an X band as a ratio of a full turn.
+    pub fn band(&self) -> f64 {
+        self.band
+    }
+
+    /// Converts an X position to a ratio of a full turn clockwise from the top.
+    pub fn x_to_turn(&self, x: f64) -> f64 {
+        (self.scale_x.apply(x) - self.left_x) * self.x_mult
+    }
+
+    /// Converts a Y position to an SVG distance from the centre.
+    pub fn y_to_radius(&self, y: f64) -> f64 {
+        (self.scale_y.apply(y) - self.bottom_y) * self.y_mult
+    }
+
+    /// Converts a data point to SVG view coordinates.
+    pub fn position_to_svg(&self, x: f64, y: f64) -> (f64, f64) {
+        turn_point(self.centre, self.y_to_radius(y), self.x_to_turn(x))
     }
+
+    /// Converts an SVG point to data coordinates. Angles past the middle of the last band wrap around to the first.
+    pub fn svg_to_position(&self, x: f64, y: f64) -> (f64, f64) {
+        let (dx, dy) = (x - self.centre.0, y - self.centre.1);
+        let mut turn = turn_ratio(dx, dy);
+        if turn > 1.0 - self.band / 2.0 {
+            turn -= 1.0;
+        }
+        let x = if self.x_mult == 0.0 {
+            self.left_x
+        } else {
+            self.left_x + turn / self.x_mult
+        };
+        let y = self.bottom_y + dx.hypot(dy) / self.y_mult;
+        (self.scale_x.invert(x), self.scale_y.invert(y))
+    }
+}
+
+/// Converts an offset from the centre to a ratio of a full turn clockwise from the top.
+pub(crate) fn turn_ratio(dx: f64, dy: f64) -> f64 {
+    (dy.atan2(dx) / TAU + 0.25).rem_euclid(1.0)
+}
+
+/// Converts a ratio of a full turn (clockwise from the top) and radius to an SVG point.
+pub(crate) fn turn_point((cx, cy): (f64, f64), radius: f64, ratio: f64) -> (f64, f64) {
+    let angle = (ratio - 0.25) * TAU;
+    (cx + radius * angle.cos(), cy + radius * angle.sin())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn linear(
+        bounds: Bounds,
+        range_x: Option<(f64, f64)>,
+        range_y: Option<(f64, f64)>,
+    ) -> Projection {
+        Projection::scaled(
+            bounds,
+            false,
+            (range_x, Scale::Linear),
+            (range_y, Scale::Linear),
+        )
+    }
+
+    fn horizontal(
+        bounds: Bounds,
+        range_x: Option<(f64, f64)>,
+        range_y: Option<(f64, f64)>,
+    ) -> Projection {
+        Projection::scaled(
+            bounds,
+            true,
+            (range_x, Scale::Linear),
+            (range_y, Scale::Linear),
+        )
+    }
+
     fn assert_coords(p: &Projection, pos: (f64, f64), svg: (f64, f64)) {
         assert_eq!(p.position_to_svg(pos.0, pos.1), (svg.0, svg.1), "to svg");
         assert_eq!(p.svg_to_position(svg.0, svg.1), (pos.0, pos.1), "to pos");
@@ -58,7 +319,7 @@ mod tests {
     #[test]
     fn test_projection() {
         let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
-        let p = Projection::new(bounds, Some((0.0, 100.0)), Some((0.0, 100.0)));
+        let p = linear(bounds, Some((0.0, 100.0)), Some((0.0, 100.0)));
 
         // Data range -> view bounds
         assert_coords(&p, (0.0, 0.0), (10.0, 90.0)); // Bottom left
@@ -71,7 +332,7 @@ mod tests {
     #[test]
     fn test_incl_zero() {
         let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
-        let p = Projection::new(bounds, Some((0.0, 200.0)), Some((0.0, 200.0)));
+        let p = linear(bounds, Some((0.0, 200.0)), Some((0.0, 200.0)));
         // Data range (0, 0) to (200, 200) -> view bounds
         assert_coords(&p, (0.0, 0.0), (10.0, 90.0)); // Bottom left
         assert_coords(&p, (200.0, 0.0), (90.0, 90.0)); // Bottom right
@@ -80,20 +341,107 @@ mod tests {
         assert_coords(&p, (100.0, 100.0), (50.0, 50.0)); // Centre
     }
 
+    #[test]
+    fn test_horizontal() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 50.0);
+        let p = horizontal(bounds, Some((0.0, 4.0)), Some((0.0, 100.0)));
+        // X runs top to bottom, Y runs left to right
+        assert_coords(&p, (0.0, 0.0), (10.0, 10.0)); // Top left
+        assert_coords(&p, (0.0, 100.0), (90.0, 10.0)); // Top right
+        assert_coords(&p, (4.0, 0.0), (10.0, 50.0)); // Bottom left
+        assert_coords(&p, (2.0, 50.0), (50.0, 30.0)); // Centre
+        assert_eq!(p.x_to_svg(1.0), 20.0);
+        assert_eq!(p.y_to_svg(25.0), 30.0);
+    }
+
+    #[test]
+    fn test_log_scale() {
+        let bounds = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let range = Some((1.0, 10_000.0));
+        let p = Projection::scaled(bounds, false, (range, Scale::Log10), (range, Scale::Log2));
+        // Decades are evenly spaced on X
+        assert_eq!(p.x_to_svg(1.0), 0.0);
+        assert_eq!(p.x_to_svg(10.0), 25.0);
+        assert_eq!(p.x_to_svg(1000.0), 75.0);
+        assert_eq!(p.svg_to_position(50.0, 100.0).0, 100.0);
+        // Can't show zero or negative values
+        assert!(p.x_to_svg(0.0).is_nan());
+        assert!(p.y_to_svg(-1.0).is_nan());
+        // Zero falls back to the start of each axis
+        assert_eq!(p.svg_zero(), (0.0, 100.0));
+        let p = Projection::scaled(bounds, true, (None, Scale::Linear), (range, Scale::Log10));
+        assert_eq!(p.svg_zero(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_symlog_scale() {
+        let bounds = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let range = Some((-99.0, 99.0));
+        let p = Projection::scaled(bounds, false, (None, Scale::Linear), (range, Scale::Symlog));
+        assert_coords(&p, (0.0, 0.0), (0.0, 50.0));
+        assert_eq!(p.y_to_svg(9.0), 25.0);
+        assert_eq!(p.y_to_svg(-9.0), 75.0);
+    }
+
     #[test]
     fn test_projection_zero_range() {
         let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
-        Projection::new(bounds, None, None);
+        linear(bounds, None, None);
     }
 
     #[test]
     fn test_partial_eq() {
         let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
-        let p = Projection::new(
+        let p = linear(
             bounds,
             Some((bounds.left_x(), bounds.right_x())),
             Some((bounds.bottom_y(), bounds.top_y())),
         );
         assert_eq!(p, p.clone());
     }
+
+    #[test]
+    fn test_turn_ratio() {
+        assert_eq!(turn_ratio(0.0, -1.0), 0.0);
+        assert_eq!(turn_ratio(1.0, 0.0), 0.25);
+        assert_eq!(turn_ratio(0.0, 1.0), 0.5);
+        assert_eq!(turn_ratio(-1.0, 0.0), 0.75);
+        let (x, y) = turn_point((10.0, 10.0), 5.0, 0.25);
+        assert_eq!((x.round(), y.round()), (15.0, 10.0));
+    }
+
+    #[test]
+    fn test_polar() {
+        let round = |(x, y): (f64, f64)| (x.round(), y.round());
+        // Four X values around a turn, Y from the centre to a radius of 40
+        let p = PolarProjection::scaled(
+            (50.0, 50.0),
+            40.0,
+            4,
+            (Some((0.0, 3.0)), Scale::Linear),
+            (Some((0.0, 100.0)), Scale::Linear),
+        );
+        assert_eq!(p.band(), 0.25);
+        assert_eq!(p.x_to_turn(3.0), 0.75);
+        assert_eq!(p.y_to_radius(50.0), 20.0);
+        assert_eq!(round(p.position_to_svg(0.0, 100.0)), (50.0, 10.0)); // Top
+        assert_eq!(round(p.position_to_svg(1.0, 50.0)), (70.0, 50.0)); // Right
+        assert_eq!(round(p.position_to_svg(2.0, 0.0)), (50.0, 50.0)); // Centre
+        assert_eq!(round(p.svg_to_position(50.0, 90.0)), (2.0, 100.0)); // Bottom
+        assert_eq!(round(p.svg_to_position(10.0, 50.0)), (3.0, 100.0)); // Left
+
+        // Near the top wraps to the first X
+        assert!(p.svg_to_position(49.0, 10.0).0 < 0.0);
+
+        // A single X value is drawn at the top
+        let p = PolarProjection::scaled(
+            (0.0, 0.0),
+            10.0,
+            1,
+            (Some((5.0, 5.0)), Scale::Linear),
+            (None, Scale::Linear),
+        );
+        assert_eq!(p.x_to_turn(5.0), 0.0);
+        assert_eq!(p.svg_to_position(0.0, -5.0).0, 5.0);
+    }
 }
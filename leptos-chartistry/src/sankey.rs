@@ -0,0 +1,600 @@
+use crate::{
+    aspect_ratio::KnownAspectRatio,
+    bounds::Bounds,
+    chart::{ChartEnv, ChartShell},
+    debug::DebugRect,
+    overlay::tooltip::{TooltipFrame, TooltipRow},
+    ticks::format_value,
+    AspectRatio, ColourScheme, Padding, TooltipPlacement, SERIES_COLOUR_SCHEME,
+    TOOLTIP_CURSOR_DISTANCE,
+};
+use leptos::prelude::*;
+use std::{collections::HashMap, sync::Arc};
+
+/// Default width of Sankey nodes in pixels.
+pub const SANKEY_NODE_WIDTH: f64 = 12.0;
+/// Default gap between Sankey nodes in the same column in pixels.
+pub const SANKEY_NODE_GAP: f64 = 8.0;
+/// Default opacity of Sankey links.
+pub const SANKEY_LINK_OPACITY: f64 = 0.4;
+
+type FormatValue = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Describes how to draw a [SankeyChart]: the size of its nodes and the colour of nodes and links.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let sankey = Sankey::default()
+///     .with_node_width(20.0)
+///     .with_format(|cost| format!("${cost:.0}"));
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Sankey {
+    /// Width of each node in pixels. Default is [SANKEY_NODE_WIDTH].
+    pub node_width: RwSignal<f64>,
+    /// Gap between nodes in the same column in pixels. Default is [SANKEY_NODE_GAP].
+    pub node_gap: RwSignal<f64>,
+    /// Colours of the nodes in the order they first appear in the links. Links take the colour of their source. Default is [SERIES_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+    /// Opacity of links. Default is [SANKEY_LINK_OPACITY].
+    pub link_opacity: RwSignal<f64>,
+    /// Whether to label nodes with their name. Default is true.
+    pub labels: RwSignal<bool>,
+    /// Formats values shown in the tooltip. Defaults to at most two decimal places.
+    pub format: RwSignal<FormatValue>,
+}
+
+impl Sankey {
+    /// Sets the width of each node in pixels.
+    pub fn with_node_width(self, width: f64) -> Self {
+        self.node_width.set(width);
+        self
+    }
+
+    /// Sets the gap between nodes in the same column in pixels.
+    pub fn with_node_gap(self, gap: f64) -> Self {
+        self.node_gap.set(gap);
+        self
+    }
+
+    /// Sets the colours of the nodes.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Sets the opacity of links.
+    pub fn with_link_opacity(self, opacity: f64) -> Self {
+        self.link_opacity.set(opacity);
+        self
+    }
+
+    /// Sets whether to label nodes with their name.
+    pub fn with_labels(self, labels: bool) -> Self {
+        self.labels.set(labels);
+        self
+    }
+
+    /// Sets how values shown in the tooltip are formatted.
+    pub fn with_format(self, format: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        self.format.set(Arc::new(format));
+        self
+    }
+}
+
+impl Default for Sankey {
+    fn default() -> Self {
+        Self {
+            node_width: RwSignal::new(SANKEY_NODE_WIDTH),
+            node_gap: RwSignal::new(SANKEY_NODE_GAP),
+            colours: RwSignal::new(SERIES_COLOUR_SCHEME.into()),
+            link_opacity: RwSignal::new(SANKEY_LINK_OPACITY),
+            labels: RwSignal::new(true),
+            format: RwSignal::new(Arc::new(format_value)),
+        }
+    }
+}
+
+/// A node of the diagram. Sized by the larger of its incoming and outgoing values.
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    name: String,
+    value: f64,
+    column: usize,
+    bounds: Bounds,
+}
+
+/// A link between two nodes. Drawn as a band from the right of the source node to the left of the target node.
+#[derive(Clone, Debug, PartialEq)]
+struct Link {
+    source: usize,
+    target: usize,
+    value: f64,
+    /// SVG x of the source and target ends.
+    x: (f64, f64),
+    /// SVG (top, bottom) of the source end.
+    source_y: (f64, f64),
+    /// SVG (top, bottom) of the target end.
+    target_y: (f64, f64),
+}
+
+/// Nodes and links laid out in columns.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Diagram {
+    nodes: Vec<Node>,
+    links: Vec<Link>,
+    /// Last column. Nodes without outgoing links are placed here.
+    last_column: usize,
+}
+
+/// Eases a link's Y values from its source to its target. Matches the cubic bezier used to draw links.
+fn ease(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+impl Link {
+    fn path(&self) -> String {
+        let ((x0, x1), (top0, bottom0), (top1, bottom1)) = (self.x, self.source_y, self.target_y);
+        let mid = (x0 + x1) / 2.0;
+        format!(
+            "M {x0} {top0} C {mid} {top0} {mid} {top1} {x1} {top1} \
+            L {x1} {bottom1} C {mid} {bottom1} {mid} {bottom0} {x0} {bottom0} Z"
+        )
+    }
+
+    /// Finds the SVG (top, bottom) of the link at an SVG x. Returns None if the link doesn't cross it.
+    fn edges_at(&self, x: f64) -> Option<(f64, f64)> {
+        let (x0, x1) = self.x;
+        if x < x0 || x > x1 || x0 >= x1 {
+            return None;
+        }
+        // Control points are half way along so X runs 1.5t - 1.5t² + t³. Find t by bisection
+        let ratio = (x - x0) / (x1 - x0);
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..32 {
+            let t: f64 = (low + high) / 2.0;
+            if 1.5 * t - 1.5 * t * t + t.powi(3) < ratio {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        let e = ease((low + high) / 2.0);
+        let top = self.source_y.0 + (self.target_y.0 - self.source_y.0) * e;
+        let bottom = self.source_y.1 + (self.target_y.1 - self.source_y.1) * e;
+        Some((top, bottom))
+    }
+}
+
+/// Lays out (source, target, value) links in bounds. Nodes are placed in columns by their longest path from a node without incoming links. Nodes without outgoing links are placed in the last column. Links that aren't positive or loop back to their source are skipped.
+fn diagram(
+    links: &[(String, String, f64)],
+    bounds: Bounds,
+    node_width: f64,
+    node_gap: f64,
+) -> Diagram {
+    // Nodes in the order they first appear
+    let mut names = Vec::<String>::new();
+    let mut ids = HashMap::<&str, usize>::new();
+    let mut kept = Vec::with_capacity(links.len());
+    for (source, target, value) in links {
+        if !(*value > 0.0 && value.is_finite()) || source == target {
+            continue;
+        }
+        let [source, target] = [source, target].map(|name| {
+            *ids.entry(name).or_insert_with(|| {
+                names.push(name.clone());
+                names.len() - 1
+            })
+        });
+        kept.push((source, target, *value));
+    }
+    let links = kept;
+    let count = names.len();
+    if count == 0 {
+        return Diagram::default();
+    }
+
+    let (mut incoming, mut outgoing) = (vec![0.0; count], vec![0.0; count]);
+    for &(source, target, value) in &links {
+        outgoing[source] += value;
+        incoming[target] += value;
+    }
+
+    // Longest path from a source. Cycles stop after every node has been visited
+    let mut columns = vec![0; count];
+    for _ in 0..count {
+        let mut changed = false;
+        for &(source, target, _) in &links {
+            if columns[target] < columns[source] + 1 {
+                columns[target] = columns[source] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let last_column = columns.iter().copied().max().unwrap_or_default();
+    for (column, &outgoing) in columns.iter_mut().zip(&outgoing) {
+        if outgoing == 0.0 {
+            *column = last_column;
+        }
+    }
+
+    // Scale values so that the fullest column fits
+    let values = (incoming.iter().zip(&outgoing))
+        .map(|(incoming, outgoing)| incoming.max(*outgoing))
+        .collect::<Vec<_>>();
+    let by_column = (0..=last_column)
+        .map(|column| (0..count).filter(|&id| columns[id] == column).collect())
+        .collect::<Vec<Vec<_>>>();
+    let scale = (by_column.iter())
+        .filter(|ids| !ids.is_empty())
+        .map(|ids| {
+            let gaps = node_gap * (ids.len() - 1) as f64;
+            let total = ids.iter().map(|&id| values[id]).sum::<f64>();
+            (bounds.height() - gaps) / total
+        })
+        .fold(f64::INFINITY, f64::min)
+        .max(0.0);
+
+    // Columns are spread across the width and centred vertically
+    let step = if last_column == 0 {
+        0.0
+    } else {
+        (bounds.width() - node_width) / last_column as f64
+    };
+    let mut nodes = (names.into_iter().enumerate())
+        .map(|(id, name)| Node {
+            name,
+            value: values[id],
+            column: columns[id],
+            bounds: Bounds::default(),
+        })
+        .collect::<Vec<_>>();
+    for (column, ids) in by_column.iter().enumerate() {
+        let used = ids.iter().map(|&id| values[id] * scale).sum::<f64>()
+            + node_gap * ids.len().saturating_sub(1) as f64;
+        let x = bounds.left_x() + column as f64 * step;
+        let mut y = bounds.top_y() + (bounds.height() - used) / 2.0;
+        for &id in ids {
+            let height = values[id] * scale;
+            nodes[id].bounds = Bounds::from_points(x, y, x + node_width, y + height);
+            y += height + node_gap;
+        }
+    }
+
+    // Stack links at each end in the order of the nodes at their other end
+    let mut links = (links.into_iter())
+        .map(|(source, target, value)| Link {
+            source,
+            target,
+            value,
+            x: (
+                nodes[source].bounds.right_x(),
+                nodes[target].bounds.left_x(),
+            ),
+            source_y: (0.0, 0.0),
+            target_y: (0.0, 0.0),
+        })
+        .collect::<Vec<_>>();
+    for (id, node) in nodes.iter().enumerate() {
+        let top = |id: usize| nodes[id].bounds.top_y();
+        let mut out = (0..links.len())
+            .filter(|&link| links[link].source == id)
+            .collect::<Vec<_>>();
+        out.sort_by(|&a, &b| top(links[a].target).total_cmp(&top(links[b].target)));
+        let mut y = node.bounds.top_y();
+        for link in out {
+            let height = links[link].value * scale;
+            links[link].source_y = (y, y + height);
+            y += height;
+        }
+        let mut into = (0..links.len())
+            .filter(|&link| links[link].target == id)
+            .collect::<Vec<_>>();
+        into.sort_by(|&a, &b| top(links[a].source).total_cmp(&top(links[b].source)));
+        let mut y = node.bounds.top_y();
+        for link in into {
+            let height = links[link].value * scale;
+            links[link].target_y = (y, y + height);
+            y += height;
+        }
+    }
+
+    Diagram {
+        nodes,
+        links,
+        last_column,
+    }
+}
+
+/// What the mouse is over.
+#[derive(Clone, Debug, PartialEq)]
+enum Hovered {
+    Node(usize),
+    Link(usize),
+}
+
+/// Renders a Sankey diagram of flows between stages e.g., traffic routing or cost attribution.
+///
+/// Takes a list of (source, target, value) links. Nodes are named by the links and laid out in columns from left to right: each is placed one column after its furthest source and nodes without outgoing links are placed in the last column. Nodes are sized by the larger of their incoming and outgoing values. Links are drawn as weighted bezier curves in the colour of their source node. Links should not form cycles.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # #[component]
+/// # fn SankeyComponent() -> impl IntoView {
+/// let links = Signal::derive(|| vec![
+///     ("Ingress".to_string(), "API".to_string(), 70.0),
+///     ("Ingress".to_string(), "Web".to_string(), 30.0),
+///     ("API".to_string(), "Database".to_string(), 50.0),
+///     ("API".to_string(), "Cache".to_string(), 20.0),
+/// ]);
+/// view! {
+///     <SankeyChart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         tooltip=TooltipPlacement::LeftCursor
+///         links=links
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn SankeyChart(
+    /// Determines the width and height of the chart. See [AspectRatio](AspectRatio) for details.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+
+    /// The height of the font used in the chart. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<Signal<f64>>,
+
+    /// The width of a monospaced character in the font used. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<Signal<f64>>,
+
+    /// Debug mode. If enabled shows lines around components. Default is false.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+
+    /// Padding adds spacing around the diagram. Default is the font width.
+    #[prop(into, optional)]
+    padding: Option<Signal<Padding>>,
+
+    /// How to draw the diagram. See [Sankey] for details.
+    #[prop(into, optional)]
+    sankey: Sankey,
+    /// Where to show a tooltip on mouse hover. Values are formatted by [Sankey::format]. Default is hidden.
+    #[prop(into, optional)]
+    tooltip: Signal<TooltipPlacement>,
+
+    /// Links to render: (source, target, value).
+    #[prop(into)]
+    links: Signal<Vec<(String, String, f64)>>,
+) -> impl IntoView {
+    let env = ChartEnv::new(aspect_ratio, font_height, font_width, debug, padding);
+    let ChartEnv {
+        watch,
+        calc,
+        debug,
+        font_height,
+        font_width,
+        padding,
+        ..
+    } = env.clone();
+
+    // There are no edges: the diagram fills the chart
+    let outer = Memo::new(move |_| calc.with(KnownAspectRatio::bounds));
+    let inner = Memo::new(move |_| padding.get().apply(outer.get()));
+    let layout = Memo::new(move |_| {
+        let (node_width, node_gap) = (sankey.node_width.get(), sankey.node_gap.get());
+        links.with(|links| diagram(links, inner.get(), node_width, node_gap))
+    });
+
+    let colour = move |id: usize| sankey.colours.with(|colours| colours.by_index(id));
+
+    let link_paths = move || {
+        (layout.get().links.into_iter())
+            .map(|link| {
+                view! {
+                    <path
+                        d=link.path()
+                        fill=colour(link.source).to_string()
+                        fill-opacity=sankey.link_opacity />
+                }
+            })
+            .collect_view()
+    };
+
+    let node_rects = move || {
+        (layout.get().nodes.into_iter().enumerate())
+            .map(|(id, node)| {
+                view! {
+                    <rect
+                        x=node.bounds.left_x()
+                        y=node.bounds.top_y()
+                        width=node.bounds.width()
+                        height=node.bounds.height()
+                        fill=colour(id).to_string() />
+                }
+            })
+            .collect_view()
+    };
+
+    // Labels sit beside their node: inside the diagram for the last column
+    let labels = move || {
+        if !sankey.labels.get() {
+            return None;
+        }
+        let layout = layout.get();
+        let gap = font_width.get() / 2.0;
+        let view = (layout.nodes.into_iter())
+            .map(|node| {
+                let last = node.column == layout.last_column && layout.last_column > 0;
+                let (x, anchor) = if last {
+                    (node.bounds.left_x() - gap, "end")
+                } else {
+                    (node.bounds.right_x() + gap, "start")
+                };
+                view! {
+                    <text x=x y=node.bounds.centre_y() text-anchor=anchor dominant-baseline="middle">
+                        {node.name}
+                    </text>
+                }
+            })
+            .collect_view();
+        Some(view)
+    };
+
+    // Node or link under the mouse. Nodes are drawn on top
+    let (mouse_chart, mouse_hover) = (watch.mouse_chart, watch.mouse_chart_hover);
+    let hovered = Memo::new(move |_| {
+        if !mouse_hover.get() {
+            return None;
+        }
+        let (x, y) = mouse_chart.get();
+        layout.with(|layout| {
+            let node = (layout.nodes.iter()).position(|node| node.bounds.contains(x, y));
+            let link = || {
+                (layout.links.iter()).position(|link| {
+                    link.edges_at(x)
+                        .is_some_and(|(top, bottom)| top <= y && y <= bottom)
+                })
+            };
+            (node.map(Hovered::Node)).or_else(|| link().map(Hovered::Link))
+        })
+    });
+
+    let tooltip_heading = move || {
+        let layout = layout.get();
+        hovered.get().map(|hovered| match hovered {
+            Hovered::Node(id) => layout.nodes[id].name.clone(),
+            Hovered::Link(id) => {
+                let link = &layout.links[id];
+                let name = |id: usize| layout.nodes[id].name.as_str();
+                format!("{} → {}", name(link.source), name(link.target))
+            }
+        })
+    };
+    let tooltip_value = move || {
+        let layout = layout.get();
+        let (id, value) = match hovered.get()? {
+            Hovered::Node(id) => (id, layout.nodes[id].value),
+            Hovered::Link(id) => (layout.links[id].source, layout.links[id].value),
+        };
+        let format = sankey.format.get();
+        Some((colour(id), (format)(value)))
+    };
+
+    let tooltip_row = move || {
+        tooltip_value().map(|(colour, value)| {
+            view! {
+                <TooltipRow value=value font_height=font_height font_width=font_width>
+                    <span style:color=colour.to_string()>"■"</span>
+                </TooltipRow>
+            }
+        })
+    };
+    view! {
+        <ChartShell env=env label="SankeyChart">
+            <svg
+                width=move || format!("{}px", outer.get().width())
+                height=move || format!("{}px", outer.get().height())
+                viewBox=move || outer.with(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+                style="display: block; overflow: visible;">
+                <DebugRect label="RenderSankey" debug=debug bounds=vec![outer.into(), inner.into()] />
+                <g class="_chartistry_sankey_links">{link_paths}</g>
+                <g class="_chartistry_sankey_nodes">{node_rects}</g>
+                <g class="_chartistry_sankey_labels" font-size=move || font_height.get()>
+                    {labels}
+                </g>
+            </svg>
+            <TooltipFrame
+                show=Signal::derive(move || hovered.with(Option::is_some))
+                placement=tooltip
+                cursor_distance=TOOLTIP_CURSOR_DISTANCE
+                mouse_page=watch.mouse_page
+                heading=Signal::derive(move || tooltip_heading().unwrap_or_default())
+                padding=padding
+                font_height=font_height
+                debug=debug>
+                {tooltip_row}
+            </TooltipFrame>
+        </ChartShell>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(source: &str, target: &str, value: f64) -> (String, String, f64) {
+        (source.to_string(), target.to_string(), value)
+    }
+
+    #[test]
+    fn test_diagram() {
+        let links = [
+            link("a", "b", 6.0),
+            link("a", "c", 2.0),
+            link("b", "c", 4.0),
+            link("b", "d", 2.0),
+            link("x", "x", 5.0),
+            link("c", "d", 0.0),
+        ];
+        let bounds = Bounds::new(110.0, 100.0);
+        let diagram = diagram(&links, bounds, 10.0, 10.0);
+        let summary = (diagram.nodes.iter())
+            .map(|node| (node.name.as_str(), node.column, node.value))
+            .collect::<Vec<_>>();
+        // Skips self links and zero values. Sinks are in the last column
+        assert_eq!(
+            summary,
+            vec![("a", 0, 8.0), ("b", 1, 6.0), ("c", 2, 6.0), ("d", 2, 2.0)]
+        );
+        assert_eq!(diagram.last_column, 2);
+        // Last column is fullest: 8 units fill 90px with one 10px gap
+        let [a, b, c, d] = [0, 1, 2, 3].map(|id| diagram.nodes[id].bounds);
+        assert_eq!((c.left_x(), c.right_x()), (100.0, 110.0));
+        assert_eq!((c.top_y(), c.bottom_y()), (0.0, 67.5));
+        assert_eq!((d.top_y(), d.bottom_y()), (77.5, 100.0));
+        // Other columns are centred
+        assert_eq!((a.left_x(), a.top_y(), a.bottom_y()), (0.0, 5.0, 95.0));
+        assert_eq!((b.left_x(), b.height()), (50.0, 67.5));
+        // Links stack at each end in the order of their other end: c is above b
+        let a_c = &diagram.links[1];
+        assert_eq!(a_c.x, (10.0, 100.0));
+        assert_eq!(a_c.source_y, (5.0, 27.5));
+        assert_eq!(diagram.links[0].source_y, (27.5, 95.0));
+        assert_eq!(a_c.target_y, (0.0, 22.5));
+        let b_c = &diagram.links[2];
+        assert_eq!(b_c.target_y, (22.5, 67.5));
+
+        // Nothing to show
+        assert_eq!(super::diagram(&[], bounds, 10.0, 10.0), Diagram::default());
+    }
+
+    #[test]
+    fn test_edges_at() {
+        let link = Link {
+            source: 0,
+            target: 1,
+            value: 1.0,
+            x: (0.0, 100.0),
+            source_y: (0.0, 10.0),
+            target_y: (50.0, 60.0),
+        };
+        assert_eq!(link.edges_at(-1.0), None);
+        assert_eq!(link.edges_at(101.0), None);
+        let round = |(top, bottom): (f64, f64)| (top.round(), bottom.round());
+        assert_eq!(link.edges_at(0.0).map(round), Some((0.0, 10.0)));
+        assert_eq!(link.edges_at(50.0).map(round), Some((25.0, 35.0)));
+        assert_eq!(link.edges_at(100.0).map(round), Some((50.0, 60.0)));
+    }
+}
@@ -0,0 +1,252 @@
+use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY, YAxis};
+use crate::{bounds::Bounds, colours::Colour, state::State, Interpolation, Tick};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default opacity of a band.
+pub const BAND_OPACITY: f64 = 0.3;
+
+/// Draws a translucent band between a lower and upper value. Useful for uncertainty, confidence intervals, and percentile envelopes.
+///
+/// # Example
+/// A p50 latency line inside a p5 to p95 envelope:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Latency { at: f64, p5: f64, p50: f64, p95: f64 }
+/// let series = Series::new(|l: &Latency| l.at)
+///     .band(Band::new(|l: &Latency| l.p5, |l: &Latency| l.p95).with_name("p5-p95"))
+///     .line(Line::new(|l: &Latency| l.p50).with_name("p50"));
+/// ```
+/// Tooltips show both the lower and upper value.
+#[non_exhaustive]
+pub struct Band<T, Y> {
+    get_lower: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    get_upper: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    /// Name of the band. Used in the legend.
+    pub name: RwSignal<String>,
+    /// Colour of the band. If not set, the next colour in the series will be used. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Opacity of the band. Default is [BAND_OPACITY].
+    pub opacity: RwSignal<f64>,
+    /// Interpolation of the band's lower and upper edges. Default is [Interpolation::Monotone].
+    pub interpolation: RwSignal<Interpolation>,
+    /// The Y axis the band is drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBand {
+    colour: Signal<Colour>,
+    opacity: RwSignal<f64>,
+    interpolation: RwSignal<Interpolation>,
+}
+
+impl<T, Y> Band<T, Y> {
+    /// Create a new band. Use `get_lower` and `get_upper` to extract the bounds of the band from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(
+        get_lower: impl Fn(&T) -> Y + Send + Sync + 'static,
+        get_upper: impl Fn(&T) -> Y + Send + Sync + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_lower: Arc::new(get_lower),
+            get_upper: Arc::new(get_upper),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            opacity: RwSignal::new(BAND_OPACITY),
+            interpolation: RwSignal::default(),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the band. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the band. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the opacity of the band. Default is [BAND_OPACITY].
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+
+    /// Set the interpolation of the band's edges.
+    pub fn with_interpolation(self, interpolation: impl Into<Interpolation>) -> Self {
+        self.interpolation.set(interpolation.into());
+        self
+    }
+
+    /// Set the Y axis the band is drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+}
+
+impl<T, Y> Clone for Band<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_lower: self.get_lower.clone(),
+            get_upper: self.get_upper.clone(),
+            name: self.name,
+            colour: self.colour,
+            opacity: self.opacity,
+            interpolation: self.interpolation,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+/// Gets the upper value of the band. The lower value is where the band is drawn from.
+struct GetBand<T, Y> {
+    lower: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    upper: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+}
+
+impl<T, Y> GetYValue<T, Y> for GetBand<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.upper)(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        (self.upper)(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<Y> {
+        Some((self.lower)(t))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Band<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_line(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseLine<T, Y> for Band<T, Y> {
+    fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let band = UseY::new(
+            id,
+            self.name,
+            self.y_axis,
+            UseBand {
+                colour,
+                opacity: self.opacity,
+                interpolation: self.interpolation,
+            },
+        );
+        let get_y = Arc::new(GetBand {
+            lower: self.get_lower.clone(),
+            upper: self.get_upper.clone(),
+        });
+        (band, get_y)
+    }
+}
+
+/// Lower edge of the band in SVG coords. Pairs the upper edge's positions along the X axis with the bases along the Y axis.
+fn lower_edge(positions: &[(f64, f64)], bases: &[f64], horizontal: bool) -> Vec<(f64, f64)> {
+    positions
+        .iter()
+        .zip(bases)
+        .map(|(&(x, y), &base)| if horizontal { (base, y) } else { (x, base) })
+        .collect()
+}
+
+#[component]
+pub fn RenderBand<X: Tick, Y: Tick>(
+    band: UseBand,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+    bases: Signal<Vec<f64>>,
+) -> impl IntoView {
+    let proj = state.projection;
+    let path = move || {
+        let horizontal = proj.get().is_horizontal();
+        bases.with(|bases| {
+            positions.with(|positions| {
+                let lower = lower_edge(positions, bases, horizontal);
+                band.interpolation.get().area_path(positions, &lower)
+            })
+        })
+    };
+    view! {
+        <path
+            class="_chartistry_band"
+            d=path
+            fill=move || band.colour.get().to_string()
+            fill-opacity=band.opacity
+            stroke="none" />
+    }
+}
+
+#[component]
+pub fn BandTaster(band: UseBand, bounds: Memo<Bounds>) -> impl IntoView {
+    view! {
+        <rect
+            class="_chartistry_band_taster"
+            x=move || bounds.get().left_x()
+            y=move || bounds.get().top_y()
+            width=move || bounds.get().width()
+            height=move || bounds.get().height()
+            fill=move || band.colour.get().to_string()
+            fill-opacity=band.opacity />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{series::UseData, Series};
+
+    #[test]
+    fn test_band_positions() {
+        let series = Series::new(|d: &(f64, f64, f64)| d.0).band(Band::new(
+            |d: &(f64, f64, f64)| d.1,
+            |d: &(f64, f64, f64)| d.2,
+        ));
+        let data = vec![(0.0, 1.0, 4.0), (1.0, f64::NAN, 5.0), (2.0, 2.0, f64::NAN)];
+        let data = UseData::new(
+            series,
+            Signal::derive(move || data.clone()),
+            Signal::default(),
+        );
+        let id = data.series.get()[0].id;
+        // Upper values are the positions and lower values are the bases
+        let upper = data.series_positions(id).get();
+        assert_eq!((upper[0], upper[1]), ((0.0, 4.0), (1.0, 5.0)));
+        assert!(upper[2].1.is_nan());
+        let lower = data.series_bases(id).get();
+        assert_eq!((lower[0], lower[2]), (1.0, 2.0));
+        assert!(lower[1].is_nan());
+        // Range covers both edges. Missing values are skipped
+        assert_eq!(data.range_y.get().positions(), Some((1.0, 5.0)));
+    }
+
+    #[test]
+    fn test_lower_edge() {
+        let positions = [(10.0, 20.0), (30.0, 40.0)];
+        let bases = [50.0, f64::NAN];
+        let lower = lower_edge(&positions, &bases, false);
+        assert_eq!(lower[0], (10.0, 50.0));
+        assert_eq!(lower[1].0, 30.0);
+        assert!(lower[1].1.is_nan());
+        // Horizontal charts draw Y along the SVG X axis
+        let lower = lower_edge(&positions, &bases, true);
+        assert_eq!(lower[0], (50.0, 20.0));
+        assert!(lower[1].0.is_nan());
+    }
+}
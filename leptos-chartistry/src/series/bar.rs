@@ -1,12 +1,19 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, UseY};
-use crate::{state::State, Colour, Tick};
+use super::{
+    error_bars::{ErrorBars, GetErrors, WithErrors, ERROR_BAR_COLOUR},
+    ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, UseY, YAxis,
+};
+use crate::{bounds::Bounds, state::State, Colour, Tick};
 use leptos::prelude::*;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
+
+type GetXSpan<T> = Arc<dyn Fn(&T) -> (f64, f64) + Send + Sync>;
 
 /// Default gap ratio between bars.
 pub const BAR_GAP: f64 = 0.1;
 /// Default gap ratio inside a group of bars.
 pub const BAR_GAP_INNER: f64 = 0.05;
+/// Default colour scheme for bars below the zero line. A single red shared by all bars.
+pub const BAR_NEGATIVE_COLOUR_SCHEME: [Colour; 1] = [Colour::from_rgb(0xEF, 0x44, 0x44)];
 
 /// Draws a bar on the chart.
 ///
@@ -20,15 +27,23 @@ pub const BAR_GAP_INNER: f64 = 0.05;
 ///     .bar(|data: &MyData| data.y2);
 /// ```
 /// See this in action with a [full bar chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#bar-chart).
+///
+/// Bars are drawn horizontally when the series uses [Orientation::Horizontal](crate::Orientation::Horizontal).
 #[non_exhaustive]
 pub struct Bar<T, Y> {
     get_y: Arc<dyn GetYValue<T, Y>>,
+    errors: Option<GetErrors<T, Y>>,
+    x_span: Option<GetXSpan<T>>,
     /// Set the name of the bar as used in the legend and tooltip.
     pub name: RwSignal<String>,
     /// Set the colour of the bar. If not set, the next colour in the series will be used. Default is `None`.
     pub colour: RwSignal<Option<Colour>>,
+    /// Set the colour of the bar when below the zero line (or the reference value of [BarPlacement::Value]). Bars drawn from a base (e.g., in a [Stack](crate::Stack) or a [waterfall](Self::waterfall)) use it when below their base. If not set, the next colour in the series' [negative colour scheme](crate::Series::with_negative_colours) will be used. Default is `None`.
+    pub negative_colour: RwSignal<Option<Colour>>,
+    /// Set the colour of total bars in a [waterfall](Self::waterfall). If not set, totals are coloured like any other bar. Default is `None`.
+    pub total_colour: RwSignal<Option<Colour>>,
     /// Sets where the bar's bottom is placed. Defaults to the zero line.
-    pub placement: RwSignal<BarPlacement>,
+    pub placement: RwSignal<BarPlacement<Y>>,
     /// Set the gap between group bars. Clamped to 0.0 and 1.0. Defaults to 0.1.
     ///
     /// The gap is the ratio of the available width for an X value. For example if the chart has a width of 200px and 5 items (`T`) that leaves 40px per item. So a gap of 0.1 (10%) would leave 4px between each item with 2px on either side.
@@ -37,26 +52,35 @@ pub struct Bar<T, Y> {
     ///
     /// The group gap is the ratio of the available width for a single bar in a group of bars (for a single X value). Carrying on the example from [gap](Self::gap) a group gap of 0.05 (5%) and two bars would result in 1px (40 / 2 * 0.05). This group gap becomes the space inbetween each bar.
     pub group_gap: RwSignal<f64>,
+    /// The Y axis the bar is drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+    /// Colour of the error bars. Default is [ERROR_BAR_COLOUR].
+    pub error_colour: RwSignal<Colour>,
 }
 
 /// The location of where the bar extends from.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 #[non_exhaustive]
-pub enum BarPlacement {
+pub enum BarPlacement<Y = f64> {
     /// The bar extends from the zero line.
     #[default]
     Zero,
-    /// The bar extends from the edge of the chart.
+    /// The bar extends from the bottom edge of the chart (the left edge of a horizontal chart).
     Edge,
+    /// The bar extends from a reference value e.g., a target or an average. Bars below the value use the negative colour. The Y axis is extended to include the value.
+    Value(Y),
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseBar {
-    group_id: usize,
-    colour: Signal<Colour>,
-    placement: RwSignal<BarPlacement>,
+    pub(super) group_id: usize,
+    pub(super) colour: Signal<Colour>,
+    negative_colour: Signal<Colour>,
+    total_colour: RwSignal<Option<Colour>>,
+    placement: Signal<BarPlacement>,
     gap: RwSignal<f64>,
     group_gap: RwSignal<f64>,
+    error_colour: RwSignal<Colour>,
 }
 
 impl<T, Y> Bar<T, Y> {
@@ -64,16 +88,30 @@ impl<T, Y> Bar<T, Y> {
     ///
     /// See the module documentation for examples.
     pub fn new(get_y: impl Fn(&T) -> Y + Send + Sync + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self::from_get_y(Arc::new(get_y))
+    }
+
+    /// Create a new bar from a Y getter that may be drawn from a base e.g., a waterfall step.
+    pub(super) fn from_get_y(get_y: Arc<dyn GetYValue<T, Y>>) -> Self
     where
         Y: Tick,
     {
         Self {
-            get_y: Arc::new(get_y),
+            get_y,
+            errors: None,
+            x_span: None,
             name: RwSignal::default(),
             colour: RwSignal::default(),
+            negative_colour: RwSignal::default(),
+            total_colour: RwSignal::default(),
             placement: RwSignal::default(),
             gap: RwSignal::new(BAR_GAP),
             group_gap: RwSignal::new(BAR_GAP_INNER),
+            y_axis: RwSignal::default(),
+            error_colour: RwSignal::new(ERROR_BAR_COLOUR),
         }
     }
 
@@ -89,8 +127,30 @@ impl<T, Y> Bar<T, Y> {
         self
     }
 
-    /// Set the placement of the bar.
-    pub fn with_placement(self, placement: impl Into<BarPlacement>) -> Self {
+    /// Set the colour of the bar when below the zero line. If not set, the next colour in the series' [negative colour scheme](crate::Series::with_negative_colours) will be used.
+    pub fn with_negative_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.negative_colour.set(colour.into());
+        self
+    }
+
+    /// Set the colour of total bars in a [waterfall](Self::waterfall). If not set, totals are coloured like any other bar.
+    pub fn with_total_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.total_colour.set(colour.into());
+        self
+    }
+
+    /// Set the placement of the bar. For example, to hang bars from a target value:
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, uptime: f64 }
+    /// let bar = Bar::new(|data: &MyData| data.uptime)
+    ///     .with_placement(BarPlacement::Value(99.9));
+    /// ```
+    pub fn with_placement(self, placement: impl Into<BarPlacement<Y>>) -> Self
+    where
+        Y: Tick,
+    {
         self.placement.set(placement.into());
         self
     }
@@ -106,17 +166,77 @@ impl<T, Y> Bar<T, Y> {
         self.group_gap.set(group_gap);
         self
     }
+
+    /// Set the Y axis the bar is drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+
+    /// Draws error bars from `lower` to `upper` at the centre of each bar e.g., `y - error` and `y + error`. The Y axis is extended to include them.
+    ///
+    /// Errors are drawn where given: bars in a [Stack](crate::Stack) don't offset them.
+    pub fn with_error_bars(
+        mut self,
+        lower: impl Fn(&T) -> Y + Send + Sync + 'static,
+        upper: impl Fn(&T) -> Y + Send + Sync + 'static,
+    ) -> Self {
+        self.errors = Some((Arc::new(lower), Arc::new(upper)));
+        self
+    }
+
+    /// Spans each bar from a start to an end X position instead of the width of an X value e.g., the edges of a histogram bin. The [gap](Self::gap) is taken from inside the span and spanned bars aren't grouped side by side with other bars.
+    ///
+    /// Positions are the X value's [Tick::position]. These are the values themselves for `f64`.
+    pub fn with_x_span(
+        mut self,
+        get_span: impl Fn(&T) -> (f64, f64) + Send + Sync + 'static,
+    ) -> Self {
+        self.x_span = Some(Arc::new(get_span));
+        self
+    }
+
+    /// Set the colour of the error bars. Default is [ERROR_BAR_COLOUR].
+    pub fn with_error_colour(self, colour: impl Into<Colour>) -> Self {
+        self.error_colour.set(colour.into());
+        self
+    }
+}
+
+impl<Y: Tick> BarPlacement<Y> {
+    /// Returns the reference value if placed on one.
+    fn value(&self) -> Option<Y> {
+        match self {
+            Self::Value(y) => Some(y.clone()),
+            _ => None,
+        }
+    }
+
+    /// Maps the reference value to its position on the Y axis.
+    fn to_position(&self) -> BarPlacement {
+        match self {
+            Self::Zero => BarPlacement::Zero,
+            Self::Edge => BarPlacement::Edge,
+            Self::Value(y) => BarPlacement::Value(y.position()),
+        }
+    }
 }
 
 impl<T, Y> Clone for Bar<T, Y> {
     fn clone(&self) -> Self {
         Self {
             get_y: self.get_y.clone(),
+            errors: self.errors.clone(),
+            x_span: self.x_span.clone(),
             placement: self.placement,
             gap: self.gap,
             group_gap: self.group_gap,
             name: self.name,
             colour: self.colour,
+            negative_colour: self.negative_colour,
+            total_colour: self.total_colour,
+            y_axis: self.y_axis,
+            error_colour: self.error_colour,
         }
     }
 }
@@ -127,97 +247,365 @@ impl<T, Y: Tick, F: Fn(&T) -> Y + Send + Sync + 'static> From<F> for Bar<T, Y> {
     }
 }
 
-impl<T, Y> ApplyUseSeries<T, Y> for Bar<T, Y> {
+impl<T: 'static, Y: Tick> ApplyUseSeries<T, Y> for Bar<T, Y> {
     fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
-        let colour = series.next_colour();
-        _ = series.push_bar(colour, (*self).clone());
+        let placement = self.placement;
+        series.extend_range_y(self.y_axis, move || placement.read().value());
+        let (colour, negative_colour) = series.next_bar_colours();
+        _ = series.push_bar(colour, negative_colour, (*self).clone());
     }
 }
 
-impl<T, Y> IntoUseBar<T, Y> for Bar<T, Y> {
+impl<T: 'static, Y: Tick> IntoUseBar<T, Y> for Bar<T, Y> {
     fn into_use_bar(
         self,
         id: usize,
         group_id: usize,
         colour: Memo<Colour>,
+        negative_colour: Memo<Colour>,
     ) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
         let override_colour = self.colour;
         let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
-        let bar = UseY::new_bar(
+        let override_negative = self.negative_colour;
+        let negative_colour =
+            Signal::derive(move || override_negative.get().unwrap_or(negative_colour.get()));
+        let placement = self.placement;
+        let placement = Signal::derive(move || placement.read().to_position());
+        let bar = UseY::new(
             id,
             self.name,
+            self.y_axis,
             UseBar {
                 group_id,
                 colour,
-                placement: self.placement,
+                negative_colour,
+                total_colour: self.total_colour,
+                placement,
                 gap: self.gap,
                 group_gap: self.group_gap,
+                error_colour: self.error_colour,
             },
         );
-        (bar, self.get_y.clone())
+        let get_y = WithXSpan::wrap(self.get_y.clone(), self.x_span.clone());
+        (bar, WithErrors::wrap(get_y, self.errors.clone()))
     }
 }
 
+/// Adds a (start, end) X span to each bar.
+struct WithXSpan<T, Y> {
+    inner: Arc<dyn GetYValue<T, Y>>,
+    span: GetXSpan<T>,
+}
+
+impl<T: 'static, Y: 'static> WithXSpan<T, Y> {
+    /// Wraps `inner` if there's a span.
+    fn wrap(
+        inner: Arc<dyn GetYValue<T, Y>>,
+        span: Option<GetXSpan<T>>,
+    ) -> Arc<dyn GetYValue<T, Y>> {
+        match span {
+            Some(span) => Arc::new(Self { inner, span }),
+            None => inner,
+        }
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for WithXSpan<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        self.inner.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        self.inner.stacked_value(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<Y> {
+        self.inner.stacked_base(t)
+    }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        self.inner.point_values(t)
+    }
+
+    fn is_total(&self, t: &T) -> bool {
+        self.inner.is_total(t)
+    }
+
+    fn x_span(&self, t: &T) -> Option<(f64, f64)> {
+        Some((self.span)(t))
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        self.inner.extra_values(t)
+    }
+
+    fn y_values(&self, t: &T) -> (Y, Y, Vec<Y>) {
+        self.inner.y_values(t)
+    }
+}
+
+impl UseBar {
+    /// Finds where the bar is drawn inside the width of an X band. Returns the offset of its start from the centre of the band and its width. See [group_band].
+    pub(crate) fn group_band(&self, x_width: f64, groups: usize) -> (f64, f64) {
+        let (gap, group_gap) = (self.gap.get(), self.group_gap.get());
+        group_band(x_width, gap, group_gap, groups, self.group_id)
+    }
+
+    /// Colour of a bar. See [bar_fill].
+    pub(crate) fn fill(&self, total: bool, negative: bool) -> Colour {
+        let colours = (self.colour.get(), self.negative_colour.get());
+        bar_fill(colours, self.total_colour.get(), total, negative)
+    }
+}
+
+/// Colour of a bar given its (colour, negative colour). Totals take the total colour (if set), then negative bars take the negative colour.
+fn bar_fill(
+    (colour, negative_colour): (Colour, Colour),
+    total_colour: Option<Colour>,
+    total: bool,
+    negative: bool,
+) -> Colour {
+    match total_colour {
+        Some(total_colour) if total => total_colour,
+        _ if negative => negative_colour,
+        _ => colour,
+    }
+}
+
+/// Keeps a bar's base inside the chart's (min, max) SVG coords along the Y axis. Log scales can't show zero (NaN) so use the edge instead.
+fn clamp_base(svg: f64, (min, max): (f64, f64), edge: f64) -> f64 {
+    if svg.is_nan() {
+        edge
+    } else {
+        svg.clamp(min, max)
+    }
+}
+
+/// Counts the number of groups in an X position e.g., bars side by side.
+pub(crate) fn group_count(series: &[UseY]) -> usize {
+    (series.iter())
+        .filter_map(UseY::group_id)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Finds where a group is drawn inside the width of an X position. Returns the offset of its start from the centre of the X position and its width. Gaps are clamped to 0.0 and 1.0.
+pub(super) fn group_band(
+    x_width: f64,
+    gap: f64,
+    group_gap: f64,
+    groups: usize,
+    group_id: usize,
+) -> (f64, f64) {
+    // Find width of each X position
+    // Note: this should possibly be on Layout
+    let width = x_width * (1.0 - gap.clamp(0.0, 1.0));
+    // Find width of each group in an X position
+    let group_gap = group_gap.clamp(0.0, 1.0);
+    let group_width = width / groups.max(1) as f64;
+    let group_width_inner = group_width * (1.0 - group_gap);
+    let offset = group_width * group_gap / 2.0 - width / 2.0 + group_width * group_id as f64;
+    (offset, group_width_inner)
+}
+
 #[component]
 pub fn RenderBar<X: Tick, Y: Tick>(
     bar: UseBar,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
+    bases: Signal<Vec<f64>>,
+    errors: Signal<Vec<(f64, f64)>>,
+    spans: Signal<Vec<(f64, f64)>>,
+    totals: Signal<Vec<bool>>,
 ) -> impl IntoView {
-    let bars = Memo::new(move |_| {
-        state
-            .pre
-            .data
-            .series
-            .get()
-            .iter()
-            .filter_map(|series| series.bar().map(|bar| (series.clone(), bar.clone())))
-            .collect::<Vec<_>>()
-            .len()
-    });
+    // Number of groups in an X position. Stacked bars share a group.
+    let groups = Memo::new(move |_| group_count(&state.pre.data.series.get()));
+    let colour = bar.colour;
 
     let rects = move || {
+        let bases = bases.get();
+        let spans = spans.get();
+        let totals = totals.get();
         positions.with(|positions| {
-            // Find the zero Y position of each bar
-            let zero_y = state.svg_zero.get().1;
-
-            // Find width of each X position
-            // Note: this should possibly be on Layout
-            let gap = bar.gap.get().clamp(0.0, 1.0);
-            let width = state.layout.x_width.get() * (1.0 - gap);
-            // Find width of each group in an X position
-            let group_gap = bar.group_gap.get().clamp(0.0, 1.0);
-            let group_width = width / bars.get() as f64;
-            let group_width_inner = group_width * (1.0 - group_gap);
-            let group_gap = group_width * group_gap;
-
-            let offset = group_gap / 2.0 - width / 2.0;
-            positions
+            let proj = state.projection.get();
+            let horizontal = proj.is_horizontal();
+            // Find where the bar extends from and the line that separates negative values. Measured along the Y axis.
+            let inner = state.layout.inner.get();
+            let edge = if horizontal {
+                inner.left_x()
+            } else {
+                inner.bottom_y()
+            };
+            let bounds = if horizontal {
+                (inner.left_x(), inner.right_x())
+            } else {
+                (inner.top_y(), inner.bottom_y())
+            };
+            let clamp = |svg: f64| clamp_base(svg, bounds, edge);
+            let zero = clamp(proj.y_to_svg(0.0));
+            let (base, reference) = match bar.placement.get() {
+                BarPlacement::Zero => (zero, zero),
+                BarPlacement::Edge => (edge, zero),
+                BarPlacement::Value(position) => {
+                    let value = clamp(proj.y_to_svg(position));
+                    (value, value)
+                }
+            };
+
+            let (offset, group_width_inner) = group_band(
+                state.layout.x_width.get(),
+                bar.gap.get(),
+                bar.group_gap.get(),
+                groups.get(),
+                bar.group_id,
+            );
+            // Centre of each bar for error bars
+            let mut centres = Vec::with_capacity(positions.len());
+            let rects = positions
                 .iter()
-                .map(|&(x, y)| {
-                    let (rect_y, height) = if y < zero_y {
-                        (y, (zero_y - y).abs()) // Bar extends upwards
+                .enumerate()
+                .map(|(index, &(x, y))| {
+                    // Split SVG coords into the X band and Y value
+                    let (band, value) = if horizontal { (y, x) } else { (x, y) };
+                    let (band, width) = match spans.get(index) {
+                        // Spanned bars take their gap from inside the span
+                        Some(&(start, end)) if !(start.is_nan() || end.is_nan()) => {
+                            let (start, end) = (proj.x_to_svg(start), proj.x_to_svg(end));
+                            let span = (end - start).abs();
+                            let gap = span * bar.gap.get().clamp(0.0, 1.0);
+                            (start.min(end) + gap / 2.0, span - gap)
+                        }
+                        _ => (band + offset, group_width_inner),
+                    };
+                    centres.push(band + width / 2.0);
+                    // Stacked bars extend from the previous bar
+                    let (base, reference) = match bases.get(index) {
+                        Some(&stacked) if stacked.is_finite() => {
+                            let stacked = clamp(stacked);
+                            (stacked, stacked)
+                        }
+                        _ => (base, reference),
+                    };
+                    let total = totals.get(index).copied().unwrap_or_default();
+                    let start = value.min(base);
+                    let length = (value - base).abs();
+                    // Bars below their base or the reference line use the negative colour. SVG's Y axis is inverted.
+                    let negative = if horizontal {
+                        value < reference
+                    } else {
+                        value > reference
+                    };
+                    let fill = bar.fill(total, negative);
+                    let (rect_x, rect_y, rect_width, rect_height) = if horizontal {
+                        (start, band, length, width)
                     } else {
-                        (zero_y, (y - zero_y).abs()) // Bar extends downwards
+                        (band, start, width, length)
                     };
-                    let fill_color = if y < zero_y { bar.colour.get().to_string() } else { "#EF4444".to_string() };
                     view! {
                         <rect
-                            x=x + group_width * bar.group_id as f64 + offset
+                            x=rect_x
                             y=rect_y
-                            width=group_width_inner
-                            height=height
-                            fill=fill_color />
+                            width=rect_width
+                            height=rect_height
+                            fill=fill.to_string() />
                     }
                 })
-                .collect::<Vec<_>>()
+                .collect::<Vec<_>>();
+            view! {
+                {rects}
+                <g stroke=move || bar.error_colour.get().to_string()>
+                    <ErrorBars
+                        bands=centres
+                        errors=errors.get()
+                        horizontal=horizontal
+                        cap_width=group_width_inner / 2.0 />
+                </g>
+            }
         })
     };
     view! {
         <g
             class="_chartistry_bar"
-            fill=move || bar.colour.get().to_string()>
+            fill=move || colour.get().to_string()>
             {rects}
         </g>
     }
 }
+
+/// Renders a bar in the legend. Shows the positive colour above a midline and the negative colour below it. Waterfalls with a total colour add a full height total bar.
+#[component]
+pub fn BarTaster(bar: UseBar, bounds: Memo<Bounds>) -> impl IntoView {
+    let parts = move || {
+        if bar.total_colour.read().is_some() {
+            3.0
+        } else {
+            2.0
+        }
+    };
+    let part = Signal::derive(move || bounds.get().width() / parts());
+    let total = move || {
+        bar.total_colour.get().map(|colour| {
+            view! {
+                <rect
+                    x=move || bounds.get().left_x() + part.get() * 2.0
+                    y=move || bounds.get().top_y()
+                    width=part
+                    height=move || bounds.get().height()
+                    fill=colour.to_string() />
+            }
+        })
+    };
+    view! {
+        <g class="_chartistry_bar_taster">
+            <rect
+                x=move || bounds.get().left_x()
+                y=move || bounds.get().top_y()
+                width=part
+                height=move || bounds.get().height() / 2.0
+                fill=move || bar.colour.get().to_string() />
+            <rect
+                x=move || bounds.get().left_x() + part.get()
+                y=move || bounds.get().centre_y()
+                width=part
+                height=move || bounds.get().height() / 2.0
+                fill=move || bar.negative_colour.get().to_string() />
+            {total}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_fill() {
+        let colours = (Colour::from_rgb(1, 1, 1), Colour::from_rgb(2, 2, 2));
+        let total = Colour::from_rgb(3, 3, 3);
+        assert_eq!(bar_fill(colours, Some(total), false, false), colours.0);
+        assert_eq!(bar_fill(colours, Some(total), false, true), colours.1);
+        // Totals take priority over negative values
+        assert_eq!(bar_fill(colours, Some(total), true, true), total);
+        // Totals without a total colour fall back
+        assert_eq!(bar_fill(colours, None, true, false), colours.0);
+        assert_eq!(bar_fill(colours, None, true, true), colours.1);
+    }
+
+    #[test]
+    fn test_clamp_base() {
+        assert_eq!(clamp_base(50.0, (10.0, 100.0), 100.0), 50.0);
+        assert_eq!(clamp_base(-20.0, (10.0, 100.0), 100.0), 10.0);
+        assert_eq!(clamp_base(120.0, (10.0, 100.0), 100.0), 100.0);
+        // Values a scale can't show fall back to the edge
+        assert_eq!(clamp_base(f64::NAN, (10.0, 100.0), 10.0), 10.0);
+    }
+
+    #[test]
+    fn test_group_band() {
+        // Two groups without gaps split the width
+        assert_eq!(group_band(10.0, 0.0, 0.0, 2, 0), (-5.0, 5.0));
+        assert_eq!(group_band(10.0, 0.0, 0.0, 2, 1), (0.0, 5.0));
+        // Gaps are clamped
+        assert_eq!(group_band(10.0, 2.0, 0.0, 1, 0), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,464 @@
+use super::{
+    bar::{group_band, group_count},
+    ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY, YAxis, BAR_GAP, BAR_GAP_INNER,
+};
+use crate::{bounds::Bounds, colours::Colour, state::State, Tick};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default opacity of the box between the first and third quartiles.
+pub const BOX_PLOT_OPACITY: f64 = 0.4;
+/// Radius of outlier points.
+const OUTLIER_RADIUS: f64 = 3.0;
+
+type GetStats<T, Y> = Arc<dyn Fn(&T) -> BoxStats<Y> + Send + Sync>;
+
+/// Draws a box-and-whisker plot of a distribution at each X value.
+///
+/// # Example
+/// From pre-computed quartiles:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Latency { day: f64, min: f64, q1: f64, median: f64, q3: f64, max: f64 }
+/// let series = Series::new(|l: &Latency| l.day).box_plot(BoxPlot::new(|l: &Latency| {
+///     BoxStats::new(l.min, l.q1, l.median, l.q3, l.max)
+/// }));
+/// ```
+/// Or computed from raw samples:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Trial { group: f64, samples: Vec<f64> }
+/// let series = Series::new(|t: &Trial| t.group)
+///     .box_plot(BoxPlot::from_samples(|t: &Trial| t.samples.clone()).with_name("trial"));
+/// ```
+/// Box plots share the width of each X value with [Bar](crate::Bar)s. Several box plots in a series are drawn side by side. Tooltips list the five number summary.
+#[non_exhaustive]
+pub struct BoxPlot<T, Y> {
+    get_stats: GetStats<T, Y>,
+    /// Name of the box plot. Used in the legend.
+    pub name: RwSignal<String>,
+    /// Colour of the box plot. If not set, the next colour in the series will be used. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Opacity of the box's fill. Default is [BOX_PLOT_OPACITY].
+    pub opacity: RwSignal<f64>,
+    /// Set the gap between groups of box plots. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP]. See [Bar::gap](crate::Bar::gap) for details.
+    pub gap: RwSignal<f64>,
+    /// Set the gap inside a group of box plots. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER]. See [Bar::group_gap](crate::Bar::group_gap) for details.
+    pub group_gap: RwSignal<f64>,
+    /// Width of the box's outline, whiskers, and median.
+    pub width: RwSignal<f64>,
+    /// The Y axis the box plot is drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+/// Summary of a distribution drawn by a [BoxPlot]. The whiskers extend to the min and max.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct BoxStats<Y> {
+    /// End of the lower whisker.
+    pub min: Y,
+    /// First quartile. Bottom of the box.
+    pub q1: Y,
+    /// Median. Drawn as a line across the box.
+    pub median: Y,
+    /// Third quartile. Top of the box.
+    pub q3: Y,
+    /// End of the upper whisker.
+    pub max: Y,
+    /// Values outside of the whiskers. Drawn as points.
+    pub outliers: Vec<Y>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBoxPlot {
+    pub(super) group_id: usize,
+    colour: Signal<Colour>,
+    opacity: RwSignal<f64>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+}
+
+impl<Y> BoxStats<Y> {
+    /// Create a new summary without outliers.
+    pub fn new(min: Y, q1: Y, median: Y, q3: Y, max: Y) -> Self {
+        Self {
+            min,
+            q1,
+            median,
+            q3,
+            max,
+            outliers: Vec::new(),
+        }
+    }
+
+    /// Set the values outside of the whiskers.
+    pub fn with_outliers(mut self, outliers: impl IntoIterator<Item = Y>) -> Self {
+        self.outliers = outliers.into_iter().collect();
+        self
+    }
+}
+
+impl BoxStats<f64> {
+    /// Computes a summary from raw samples. Quartiles are linearly interpolated and whiskers extend to the furthest samples within 1.5 times the interquartile range of the box (Tukey's fences). Samples beyond them are outliers. Non-finite samples are ignored and no samples gives NaN values.
+    pub fn from_samples(samples: &[f64]) -> Self {
+        let mut sorted = (samples.iter().copied())
+            .filter(|s| s.is_finite())
+            .collect::<Vec<_>>();
+        if sorted.is_empty() {
+            return Self::new(f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+        }
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let quantile = |p: f64| {
+            let pos = p * (sorted.len() - 1) as f64;
+            let (lower, upper) = (sorted[pos.floor() as usize], sorted[pos.ceil() as usize]);
+            lower + (upper - lower) * pos.fract()
+        };
+        let (q1, median, q3) = (quantile(0.25), quantile(0.5), quantile(0.75));
+        let fence = (q3 - q1) * 1.5;
+        let (lower, upper) = (q1 - fence, q3 + fence);
+        let inside = |s: &&f64| (lower..=upper).contains(*s);
+        let min = sorted.iter().find(inside).copied().unwrap_or(q1);
+        let max = sorted.iter().rev().find(inside).copied().unwrap_or(q3);
+        let outliers = (sorted.iter().copied()).filter(|s| !(lower..=upper).contains(s));
+        Self::new(min, q1, median, q3, max).with_outliers(outliers)
+    }
+}
+
+impl<T, Y> BoxPlot<T, Y> {
+    /// Create a new box plot. Use `get_stats` to extract a [BoxStats] summary from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(get_stats: impl Fn(&T) -> BoxStats<Y> + Send + Sync + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_stats: Arc::new(get_stats),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            opacity: RwSignal::new(BOX_PLOT_OPACITY),
+            gap: RwSignal::new(BAR_GAP),
+            group_gap: RwSignal::new(BAR_GAP_INNER),
+            width: RwSignal::new(1.0),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the box plot. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the box plot. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the opacity of the box's fill. Default is [BOX_PLOT_OPACITY].
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+
+    /// Set the gap between groups of box plots. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP].
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Set the gap inside a group of box plots. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER].
+    pub fn with_group_gap(self, group_gap: f64) -> Self {
+        self.group_gap.set(group_gap);
+        self
+    }
+
+    /// Set the width of the box's outline, whiskers, and median.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set the Y axis the box plot is drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+}
+
+impl<T> BoxPlot<T, f64> {
+    /// Create a new box plot from raw samples. Use `get_samples` to extract them from your struct. See [BoxStats::from_samples] for how they're summarised.
+    pub fn from_samples(get_samples: impl Fn(&T) -> Vec<f64> + Send + Sync + 'static) -> Self {
+        Self::new(move |t| BoxStats::from_samples(&get_samples(t)))
+    }
+}
+
+impl<T, Y> Clone for BoxPlot<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_stats: self.get_stats.clone(),
+            name: self.name,
+            colour: self.colour,
+            opacity: self.opacity,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+/// Gets the median of a box plot. The min, q1, q3, max, and outliers follow as extra values. Stats are computed once per datum by [GetYValue::y_values].
+struct GetBoxStats<T, Y> {
+    stats: GetStats<T, Y>,
+}
+
+impl<T, Y: Clone> GetYValue<T, Y> for GetBoxStats<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.stats)(t).median
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        self.value(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        self.y_values(t).2
+    }
+
+    fn y_values(&self, t: &T) -> (Y, Y, Vec<Y>) {
+        let stats = (self.stats)(t);
+        let mut extra = vec![stats.min, stats.q1, stats.q3, stats.max];
+        extra.extend(stats.outliers);
+        (stats.median.clone(), stats.median, extra)
+    }
+}
+
+/// A box plot assigned to a group of side by side box plots and bars.
+struct GroupedBoxPlot<T, Y> {
+    group_id: usize,
+    box_plot: BoxPlot<T, Y>,
+}
+
+impl<T: 'static, Y: Clone + 'static> ApplyUseSeries<T, Y> for BoxPlot<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        let group_id = series.next_group_id();
+        let box_plot = (*self).clone();
+        _ = series.push_line(colour, GroupedBoxPlot { group_id, box_plot });
+    }
+}
+
+impl<T: 'static, Y: Clone + 'static> IntoUseLine<T, Y> for GroupedBoxPlot<T, Y> {
+    fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let box_plot = self.box_plot;
+        let override_colour = box_plot.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let use_y = UseY::new(
+            id,
+            box_plot.name,
+            box_plot.y_axis,
+            UseBoxPlot {
+                group_id: self.group_id,
+                colour,
+                opacity: box_plot.opacity,
+                gap: box_plot.gap,
+                group_gap: box_plot.group_gap,
+                width: box_plot.width,
+            },
+        );
+        let get_y = Arc::new(GetBoxStats {
+            stats: box_plot.get_stats.clone(),
+        });
+        (use_y, get_y)
+    }
+}
+
+#[component]
+pub fn RenderBoxPlot<X: Tick, Y: Tick>(
+    use_y: UseY,
+    box_plot: UseBoxPlot,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+) -> impl IntoView {
+    let extras = state.pre.data.series_extras(use_y.id);
+    let groups = Memo::new(move |_| group_count(&state.pre.data.series.get()));
+    let proj = state.projection;
+    let boxes = move || {
+        let proj = proj.get();
+        let horizontal = proj.is_horizontal();
+        let (offset, width) = group_band(
+            state.layout.x_width.get(),
+            box_plot.gap.get(),
+            box_plot.group_gap.get(),
+            groups.get(),
+            box_plot.group_id,
+        );
+        let opacity = box_plot.opacity.get();
+        let extras = extras.get();
+        positions.with(|positions| {
+            positions
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &(x, y))| {
+                    // Split SVG coords into the X band and Y value
+                    let (band, median) = if horizontal { (y, x) } else { (x, y) };
+                    let extra = (extras.get(index)?.iter())
+                        .map(|&y| proj.y_to_svg(y))
+                        .collect::<Vec<_>>();
+                    let (stats, outliers) = match extra.as_slice() {
+                        &[min, q1, q3, max, ref outliers @ ..] => {
+                            ((min, q1, median, q3, max), outliers.to_vec())
+                        }
+                        _ => return None,
+                    };
+                    let (min, q1, median, q3, max) = stats;
+                    if [band, min, q1, median, q3, max].iter().any(|v| v.is_nan()) {
+                        return None;
+                    }
+                    Some(view! {
+                        <BoxAndWhisker
+                            horizontal=horizontal
+                            band=band + offset
+                            width=width
+                            stats=stats
+                            outliers=outliers
+                            opacity=opacity />
+                    })
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g
+            class="_chartistry_box_plot"
+            stroke=move || box_plot.colour.get().to_string()
+            stroke-width=box_plot.width
+            fill=move || box_plot.colour.get().to_string()>
+            {boxes}
+        </g>
+    }
+}
+
+/// Draws one box. Stats are (min, q1, median, q3, max) in SVG coords along the Y axis. The box starts at band and extends across width.
+#[component]
+fn BoxAndWhisker(
+    horizontal: bool,
+    band: f64,
+    width: f64,
+    stats: (f64, f64, f64, f64, f64),
+    outliers: Vec<f64>,
+    opacity: f64,
+) -> impl IntoView {
+    let (min, q1, median, q3, max) = stats;
+    // Swap coords on horizontal charts
+    let point = move |band: f64, value: f64| {
+        if horizontal {
+            (value, band)
+        } else {
+            (band, value)
+        }
+    };
+    let line = move |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+        view! { <line x1=x1 y1=y1 x2=x2 y2=y2 /> }
+    };
+    let centre = band + width / 2.0;
+    let (cap_start, cap_end) = (centre - width / 4.0, centre + width / 4.0);
+    let (start, end) = (q1.min(q3), q1.max(q3));
+    let (x, y) = point(band, start);
+    let (box_width, box_height) = point(width, end - start);
+    let outliers = (outliers.into_iter())
+        .filter(|outlier| !outlier.is_nan())
+        .map(|outlier| {
+            let (cx, cy) = point(centre, outlier);
+            view! { <circle cx=cx cy=cy r=OUTLIER_RADIUS fill="none" /> }
+        })
+        .collect_view();
+    view! {
+        <g>
+            {line(point(centre, min), point(centre, q1))}
+            {line(point(centre, q3), point(centre, max))}
+            {line(point(cap_start, min), point(cap_end, min))}
+            {line(point(cap_start, max), point(cap_end, max))}
+            <rect x=x y=y width=box_width height=box_height fill-opacity=opacity />
+            {line(point(band, median), point(band + width, median))}
+            {outliers}
+        </g>
+    }
+}
+
+#[component]
+pub fn BoxPlotTaster(box_plot: UseBoxPlot, bounds: Memo<Bounds>) -> impl IntoView {
+    let box_and_whisker = move || {
+        let bounds = bounds.get();
+        let quarter = bounds.height() / 4.0;
+        view! {
+            <BoxAndWhisker
+                horizontal=false
+                band=bounds.left_x() + bounds.width() / 4.0
+                width=bounds.width() / 2.0
+                stats=(
+                    bounds.bottom_y(),
+                    bounds.bottom_y() - quarter,
+                    bounds.centre_y(),
+                    bounds.top_y() + quarter,
+                    bounds.top_y(),
+                )
+                outliers=vec![]
+                opacity=box_plot.opacity.get() />
+        }
+    };
+    view! {
+        <g
+            class="_chartistry_box_plot_taster"
+            stroke=move || box_plot.colour.get().to_string()
+            stroke-width=box_plot.width
+            fill=move || box_plot.colour.get().to_string()>
+            {box_and_whisker}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_stats_from_samples() {
+        let stats = BoxStats::from_samples(&[9.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(stats, BoxStats::new(1.0, 3.0, 5.0, 7.0, 9.0));
+        // Interpolated quartiles
+        let stats = BoxStats::from_samples(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((stats.q1, stats.median, stats.q3), (1.75, 2.5, 3.25));
+        // Outliers beyond 1.5 IQR
+        let stats = BoxStats::from_samples(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 100.0]);
+        assert_eq!(stats.max, 8.0);
+        assert_eq!(stats.outliers, vec![100.0]);
+        // No samples
+        let stats = BoxStats::from_samples(&[f64::NAN]);
+        assert!(stats.median.is_nan());
+        assert!(stats.outliers.is_empty());
+    }
+    #[test]
+    fn test_get_box_stats_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let calls = Arc::new(AtomicUsize::new(0));
+        let get_y = GetBoxStats {
+            stats: Arc::new({
+                let calls = calls.clone();
+                move |samples: &Vec<f64>| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    BoxStats::from_samples(samples)
+                }
+            }),
+        };
+        let samples = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 100.0];
+        let (value, stacked, extra) = get_y.y_values(&samples);
+        assert_eq!((value, stacked), (5.0, 5.0));
+        assert_eq!(extra, vec![1.0, 3.0, 7.0, 8.0, 100.0]);
+        // Computed from the samples once
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,436 @@
+use super::{
+    bar::{group_band, group_count},
+    ApplyUseSeries, GetYValue, IntoUseY, SeriesAcc, UseY, YAxis, BAR_GAP, BAR_GAP_INNER,
+};
+use crate::{bounds::Bounds, state::State, Colour, Tick};
+use leptos::{either::Either, prelude::*};
+use std::sync::Arc;
+
+/// Default colour of a candlestick that closes at or above its open.
+pub const CANDLESTICK_UP_COLOUR: Colour = Colour::from_rgb(0x22, 0xC5, 0x5E);
+/// Default colour of a candlestick that closes below its open.
+pub const CANDLESTICK_DOWN_COLOUR: Colour = Colour::from_rgb(0xEF, 0x44, 0x44);
+
+type GetValue<T, Y> = Arc<dyn Fn(&T) -> Y + Send + Sync>;
+
+/// Draws open, high, low, and close (OHLC) values of financial data.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # use chrono::prelude::*;
+/// # struct Price { day: DateTime<Utc>, open: f64, high: f64, low: f64, close: f64 }
+/// let series = Series::new(|p: &Price| p.day).candlestick(Candlestick::new(
+///     |p: &Price| p.open,
+///     |p: &Price| p.high,
+///     |p: &Price| p.low,
+///     |p: &Price| p.close,
+/// ));
+/// ```
+/// Candlesticks share the width of each X value with [Bar](crate::Bar)s and are drawn side by side with them. Tooltips list all four values.
+#[non_exhaustive]
+pub struct Candlestick<T, Y> {
+    get_open: GetValue<T, Y>,
+    get_high: GetValue<T, Y>,
+    get_low: GetValue<T, Y>,
+    get_close: GetValue<T, Y>,
+    /// Name of the candlestick. Used in the legend.
+    pub name: RwSignal<String>,
+    /// How each data point is drawn. Default is [CandlestickStyle::Candle].
+    pub style: RwSignal<CandlestickStyle>,
+    /// Colour when the close is at or above the open. Default is [CANDLESTICK_UP_COLOUR].
+    pub up_colour: RwSignal<Colour>,
+    /// Colour when the close is below the open. Default is [CANDLESTICK_DOWN_COLOUR].
+    pub down_colour: RwSignal<Colour>,
+    /// Set the gap between groups of candlesticks. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP]. See [Bar::gap](crate::Bar::gap) for details.
+    pub gap: RwSignal<f64>,
+    /// Set the gap inside a group of candlesticks. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER]. See [Bar::group_gap](crate::Bar::group_gap) for details.
+    pub group_gap: RwSignal<f64>,
+    /// Width of the wicks and OHLC ticks.
+    pub width: RwSignal<f64>,
+    /// The Y axis the candlestick is drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+/// How a [Candlestick] is drawn.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum CandlestickStyle {
+    /// A filled body between the open and close with wicks to the high and low.
+    #[default]
+    Candle,
+    /// A vertical line from the low to the high with ticks for the open (left) and close (right).
+    Ohlc,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseCandlestick {
+    pub(super) group_id: usize,
+    style: RwSignal<CandlestickStyle>,
+    up_colour: RwSignal<Colour>,
+    down_colour: RwSignal<Colour>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+}
+
+impl<T, Y> Candlestick<T, Y> {
+    /// Create a new candlestick. Each function extracts one of the open, high, low, and close values from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(
+        get_open: impl Fn(&T) -> Y + Send + Sync + 'static,
+        get_high: impl Fn(&T) -> Y + Send + Sync + 'static,
+        get_low: impl Fn(&T) -> Y + Send + Sync + 'static,
+        get_close: impl Fn(&T) -> Y + Send + Sync + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_open: Arc::new(get_open),
+            get_high: Arc::new(get_high),
+            get_low: Arc::new(get_low),
+            get_close: Arc::new(get_close),
+            name: RwSignal::default(),
+            style: RwSignal::default(),
+            up_colour: RwSignal::new(CANDLESTICK_UP_COLOUR),
+            down_colour: RwSignal::new(CANDLESTICK_DOWN_COLOUR),
+            gap: RwSignal::new(BAR_GAP),
+            group_gap: RwSignal::new(BAR_GAP_INNER),
+            width: RwSignal::new(1.0),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the candlestick. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set how each data point is drawn.
+    pub fn with_style(self, style: impl Into<CandlestickStyle>) -> Self {
+        self.style.set(style.into());
+        self
+    }
+
+    /// Set the colours of candlesticks that close up (at or above the open) and down.
+    pub fn with_colours(self, up: impl Into<Colour>, down: impl Into<Colour>) -> Self {
+        self.up_colour.set(up.into());
+        self.down_colour.set(down.into());
+        self
+    }
+
+    /// Set the gap between groups of candlesticks. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP].
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Set the gap inside a group of candlesticks. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER].
+    pub fn with_group_gap(self, group_gap: f64) -> Self {
+        self.group_gap.set(group_gap);
+        self
+    }
+
+    /// Set the width of the wicks and OHLC ticks.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set the Y axis the candlestick is drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+}
+
+impl<T, Y> Clone for Candlestick<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_open: self.get_open.clone(),
+            get_high: self.get_high.clone(),
+            get_low: self.get_low.clone(),
+            get_close: self.get_close.clone(),
+            name: self.name,
+            style: self.style,
+            up_colour: self.up_colour,
+            down_colour: self.down_colour,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+/// Gets the close of a candlestick. It's drawn from the open with the high and low as extra values.
+struct GetOhlc<T, Y> {
+    open: GetValue<T, Y>,
+    high: GetValue<T, Y>,
+    low: GetValue<T, Y>,
+    close: GetValue<T, Y>,
+}
+
+impl<T, Y> GetYValue<T, Y> for GetOhlc<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<Y> {
+        Some((self.open)(t))
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        vec![(self.high)(t), (self.low)(t)]
+    }
+}
+
+/// A candlestick assigned to a group of side by side candlesticks and bars.
+struct GroupedCandlestick<T, Y> {
+    group_id: usize,
+    candlestick: Candlestick<T, Y>,
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Candlestick<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        // Uses its own colours instead of the series'
+        let group_id = series.next_group_id();
+        let candlestick = (*self).clone();
+        _ = series.push_use_y(GroupedCandlestick {
+            group_id,
+            candlestick,
+        });
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseY<T, Y> for GroupedCandlestick<T, Y> {
+    fn into_use_y(self, id: usize) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let candlestick = self.candlestick;
+        let use_y = UseY::new(
+            id,
+            candlestick.name,
+            candlestick.y_axis,
+            UseCandlestick {
+                group_id: self.group_id,
+                style: candlestick.style,
+                up_colour: candlestick.up_colour,
+                down_colour: candlestick.down_colour,
+                gap: candlestick.gap,
+                group_gap: candlestick.group_gap,
+                width: candlestick.width,
+            },
+        );
+        let get_y = Arc::new(GetOhlc {
+            open: candlestick.get_open.clone(),
+            high: candlestick.get_high.clone(),
+            low: candlestick.get_low.clone(),
+            close: candlestick.get_close.clone(),
+        });
+        (use_y, get_y)
+    }
+}
+
+/// Formats a candlestick's tooltip as "O open H high L low C close". The open is the base, the high and low are the extra values, and the close is the value.
+pub(crate) fn format_ohlc<Y: Clone>(
+    open: Option<Y>,
+    extra: Option<&[Y]>,
+    close: Option<Y>,
+    format: impl Fn(Option<Y>) -> String,
+) -> String {
+    let get = |i: usize| extra.and_then(|extra| extra.get(i).cloned());
+    format!(
+        "O {} H {} L {} C {}",
+        format(open),
+        format(get(0)),
+        format(get(1)),
+        format(close)
+    )
+}
+
+#[component]
+pub fn RenderCandlestick<X: Tick, Y: Tick>(
+    use_y: UseY,
+    candlestick: UseCandlestick,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+    bases: Signal<Vec<f64>>,
+) -> impl IntoView {
+    let extras = state.pre.data.series_extras(use_y.id);
+    let groups = Memo::new(move |_| group_count(&state.pre.data.series.get()));
+    let proj = state.projection;
+    let x_width = state.layout.x_width;
+    let sticks = move || {
+        let proj = proj.get();
+        let horizontal = proj.is_horizontal();
+        let style = candlestick.style.get();
+        let (up, down) = (candlestick.up_colour.get(), candlestick.down_colour.get());
+        let (offset, width) = group_band(
+            x_width.get(),
+            candlestick.gap.get(),
+            candlestick.group_gap.get(),
+            groups.get(),
+            candlestick.group_id,
+        );
+        let half = width / 2.0;
+        let bases = bases.get();
+        let extras = extras.get();
+        positions.with(|positions| {
+            positions
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &(x, y))| {
+                    // Split SVG coords into the X band and Y value
+                    let (band, close) = if horizontal { (y, x) } else { (x, y) };
+                    let open = bases.get(index).copied()?;
+                    let (high, low) = match extras.get(index)?.as_slice() {
+                        &[high, low] => (proj.y_to_svg(high), proj.y_to_svg(low)),
+                        _ => return None,
+                    };
+                    if [band, open, close, high, low].iter().any(|v| v.is_nan()) {
+                        return None;
+                    }
+                    // SVG's Y axis is inverted
+                    let rising = if horizontal {
+                        close >= open
+                    } else {
+                        close <= open
+                    };
+                    let colour = if rising { up } else { down };
+                    Some(view! {
+                        <Stick
+                            style=style
+                            horizontal=horizontal
+                            band=band + offset + half
+                            half=half
+                            ohlc=(open, high, low, close)
+                            colour=colour />
+                    })
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g class="_chartistry_candlestick" stroke-width=candlestick.width>
+            {sticks}
+        </g>
+    }
+}
+
+/// Draws one data point. Values are in SVG coords along the Y axis. The band is the centre of the X value and half is half of its width.
+#[component]
+fn Stick(
+    style: CandlestickStyle,
+    horizontal: bool,
+    band: f64,
+    half: f64,
+    ohlc: (f64, f64, f64, f64),
+    colour: Colour,
+) -> impl IntoView {
+    let (open, high, low, close) = ohlc;
+    // Swap coords on horizontal charts
+    let point = move |band: f64, value: f64| {
+        if horizontal {
+            (value, band)
+        } else {
+            (band, value)
+        }
+    };
+    let line = move |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+        view! { <line x1=x1 y1=y1 x2=x2 y2=y2 /> }
+    };
+    let colour = colour.to_string();
+    match style {
+        CandlestickStyle::Candle => {
+            let (start, end) = (open.min(close), open.max(close));
+            let (x, y) = point(band - half, start);
+            let (width, height) = point(half * 2.0, end - start);
+            Either::Left(view! {
+                <g stroke=colour.clone() fill=colour>
+                    {line(point(band, high), point(band, low))}
+                    <rect x=x y=y width=width height=height />
+                </g>
+            })
+        }
+        CandlestickStyle::Ohlc => Either::Right(view! {
+            <g stroke=colour>
+                {line(point(band, high), point(band, low))}
+                {line(point(band - half, open), point(band, open))}
+                {line(point(band, close), point(band + half, close))}
+            </g>
+        }),
+    }
+}
+
+#[component]
+pub fn CandlestickTaster(candlestick: UseCandlestick, bounds: Memo<Bounds>) -> impl IntoView {
+    let stick = move || {
+        let bounds = bounds.get();
+        let quarter = bounds.height() / 4.0;
+        view! {
+            <Stick
+                style=candlestick.style.get()
+                horizontal=false
+                band=bounds.centre_x()
+                half=bounds.width() / 4.0
+                ohlc=(
+                    bounds.bottom_y() - quarter,
+                    bounds.top_y(),
+                    bounds.bottom_y(),
+                    bounds.top_y() + quarter,
+                )
+                colour=candlestick.up_colour.get() />
+        }
+    };
+    view! {
+        <g class="_chartistry_candlestick_taster" stroke-width=candlestick.width>
+            {stick}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_ohlc() {
+        let get_y = GetOhlc {
+            open: Arc::new(|d: &[f64; 4]| d[0]),
+            high: Arc::new(|d: &[f64; 4]| d[1]),
+            low: Arc::new(|d: &[f64; 4]| d[2]),
+            close: Arc::new(|d: &[f64; 4]| d[3]),
+        };
+        let d = [2.0, 5.0, 1.0, 3.0];
+        // Drawn from the open to the close
+        assert_eq!(get_y.y_values(&d), (3.0, 3.0, vec![5.0, 1.0]));
+        assert_eq!(get_y.stacked_base(&d), Some(2.0));
+        // No per-point values
+        assert_eq!(get_y.point_values(&d), None);
+    }
+
+    #[test]
+    fn test_format_ohlc() {
+        let format = |y: Option<f64>| y.map_or_else(|| "-".to_string(), |y| y.to_string());
+        let extra = [5.0, 1.0];
+        assert_eq!(
+            format_ohlc(Some(2.0), Some(&extra), Some(3.0), format),
+            "O 2 H 5 L 1 C 3"
+        );
+        // Missing values
+        assert_eq!(
+            format_ohlc(None, Some(&extra[..1]), Some(3.0), format),
+            "O - H 5 L - C 3"
+        );
+        assert_eq!(
+            format_ohlc(Some(2.0), None, None, format),
+            "O 2 H - L - C -"
+        );
+    }
+}
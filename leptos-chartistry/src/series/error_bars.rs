@@ -0,0 +1,158 @@
+use super::GetYValue;
+use crate::Colour;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default colour of error bars on bars. Lines use their own colour.
+pub const ERROR_BAR_COLOUR: Colour = Colour::from_rgb(0x33, 0x33, 0x33);
+
+pub(super) type GetError<T, Y> = Arc<dyn Fn(&T) -> Y + Send + Sync>;
+
+/// Lower and upper bounds of a value.
+pub(super) type GetErrors<T, Y> = (GetError<T, Y>, GetError<T, Y>);
+
+/// Adds lower and upper bounds to a value as extra values. The Y axis is extended to include them.
+pub(super) struct WithErrors<T, Y> {
+    pub inner: Arc<dyn GetYValue<T, Y>>,
+    pub errors: GetErrors<T, Y>,
+}
+
+impl<T: 'static, Y: 'static> WithErrors<T, Y> {
+    /// Wraps `inner` if there are errors.
+    pub fn wrap(
+        inner: Arc<dyn GetYValue<T, Y>>,
+        errors: Option<GetErrors<T, Y>>,
+    ) -> Arc<dyn GetYValue<T, Y>> {
+        match errors {
+            Some(errors) => Arc::new(Self { inner, errors }),
+            None => inner,
+        }
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for WithErrors<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        self.inner.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        self.inner.stacked_value(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<Y> {
+        self.inner.stacked_base(t)
+    }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        self.inner.point_values(t)
+    }
+
+    fn is_total(&self, t: &T) -> bool {
+        self.inner.is_total(t)
+    }
+
+    fn x_span(&self, t: &T) -> Option<(f64, f64)> {
+        self.inner.x_span(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        let (lower, upper) = &self.errors;
+        vec![lower(t), upper(t)]
+    }
+}
+
+/// Converts extra values (Y positions) of a series with errors to (lower, upper) SVG coords along the Y axis. Missing errors are NaN.
+pub(super) fn error_coords(extras: &[Vec<f64>], y_to_svg: impl Fn(f64) -> f64) -> Vec<(f64, f64)> {
+    extras
+        .iter()
+        .map(|extra| match extra.as_slice() {
+            &[lower, upper] => (y_to_svg(lower), y_to_svg(upper)),
+            _ => (f64::NAN, f64::NAN),
+        })
+        .collect()
+}
+
+/// Draws a whisker from the lower to the upper error with a cap at each end. Each error is paired with a position along the X axis (SVG coords).
+#[component]
+pub(super) fn ErrorBars(
+    bands: Vec<f64>,
+    errors: Vec<(f64, f64)>,
+    horizontal: bool,
+    cap_width: f64,
+) -> impl IntoView {
+    let half = cap_width / 2.0;
+    let path = bands
+        .into_iter()
+        .zip(errors)
+        .filter(|&(band, (lower, upper))| !(band.is_nan() || lower.is_nan() || upper.is_nan()))
+        .map(|(band, (lower, upper))| {
+            if horizontal {
+                format!(
+                    "M {lower} {band} H {upper} M {lower} {} V {} M {upper} {} V {} ",
+                    band - half,
+                    band + half,
+                    band - half,
+                    band + half
+                )
+            } else {
+                format!(
+                    "M {band} {lower} V {upper} M {} {lower} H {} M {} {upper} H {} ",
+                    band - half,
+                    band + half,
+                    band - half,
+                    band + half
+                )
+            }
+        })
+        .collect::<String>();
+    view! {
+        <path class="_chartistry_error_bars" d=path fill="none" />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{series::use_data::UseData, Line, Series};
+
+    #[test]
+    fn test_error_coords() {
+        let extras = [
+            vec![1.0, 3.0],
+            vec![],
+            vec![f64::NAN, 2.0],
+            vec![1.0, 2.0, 3.0],
+        ];
+        let coords = error_coords(&extras, |y| 10.0 - y);
+        assert_eq!(coords[0], (9.0, 7.0));
+        // Missing errors
+        assert!(coords[1].0.is_nan() && coords[1].1.is_nan());
+        assert!(coords[3].0.is_nan() && coords[3].1.is_nan());
+        // NaN errors stay NaN
+        assert!(coords[2].0.is_nan());
+        assert_eq!(coords[2].1, 8.0);
+    }
+
+    #[test]
+    fn test_with_errors() {
+        let get_y = WithErrors::wrap(
+            Arc::new(|d: &(f64, f64)| d.1),
+            Some((
+                Arc::new(|d: &(f64, f64)| d.1 - 1.0),
+                Arc::new(|d: &(f64, f64)| d.1 + 2.0),
+            )),
+        );
+        assert_eq!(get_y.y_values(&(0.0, 5.0)), (5.0, 5.0, vec![4.0, 7.0]));
+
+        // Y range includes the error extents
+        let series = Series::new(|d: &(f64, f64)| d.0)
+            .line(Line::new(|d: &(f64, f64)| d.1).with_error_bars(|d| d.1 - 1.0, |d| d.1 + 2.0));
+        let data = vec![(0.0, 5.0), (1.0, 3.0), (2.0, f64::NAN)];
+        let data = UseData::new(
+            series,
+            Signal::derive(move || data.clone()),
+            Signal::default(),
+        );
+        assert_eq!(data.range_y.get().positions(), Some((2.0, 7.0)));
+    }
+}
@@ -0,0 +1,354 @@
+use super::{scatter::value_ranges, ApplyUseSeries, GetYValue, IntoUseY, SeriesAcc, UseY, YAxis};
+use crate::{
+    bounds::Bounds,
+    colours::{ColourScheme, SequentialGradient, LIPARI},
+    state::State,
+    ticks::format_value,
+    Tick,
+};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default gradient of a heatmap.
+pub const HEATMAP_GRADIENT: SequentialGradient = LIPARI;
+/// Default gap ratio between heatmap cells.
+pub const HEATMAP_GAP: f64 = 0.05;
+/// Number of stops used to draw a heatmap's colour bar.
+const COLOUR_BAR_STOPS: usize = 16;
+
+type GetValue<T> = Arc<dyn Fn(&T) -> f64 + Send + Sync>;
+type FormatValue = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Draws a grid of cells coloured by value. Each `T` is a cell: the series' X value and the heatmap's Y value give its column and row.
+///
+/// # Example
+/// An hour of day by day of week load heatmap:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Load { hour: f64, day: Category, load: f64 }
+/// let series = Series::new(|l: &Load| l.hour)
+///     .heatmap(Heatmap::new(|l: &Load| l.day.clone(), |l: &Load| l.load).with_name("load"));
+/// ```
+/// Cells take up the width of an X value and the height of a Y value. Columns and rows are found from [Tick::band_count] (e.g., [Category]) or else the number of distinct values. Values are coloured through a [ColourScheme] e.g., a [SequentialGradient] or a [DivergingGradient](crate::DivergingGradient) that centres on zero.
+///
+/// Legends show a colour bar with the range of values. Tooltips report the value of the cell under the mouse.
+///
+/// [Category]: crate::Category
+#[non_exhaustive]
+pub struct Heatmap<T, Y> {
+    get_y: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    get_value: GetValue<T>,
+    /// Name of the heatmap. Used in the legend.
+    pub name: RwSignal<String>,
+    /// Colour scheme that values are mapped through. Default is [HEATMAP_GRADIENT].
+    pub gradient: RwSignal<ColourScheme>,
+    /// Gap between cells. Clamped to 0.0 and 1.0. Default is [HEATMAP_GAP].
+    pub gap: RwSignal<f64>,
+    /// Formats cell values in tooltips and the legend.
+    pub format: RwSignal<FormatValue>,
+    /// The Y axis the rows are drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseHeatmap {
+    gradient: RwSignal<ColourScheme>,
+    gap: RwSignal<f64>,
+    format: RwSignal<FormatValue>,
+}
+
+impl<T, Y> Heatmap<T, Y> {
+    /// Create a new heatmap. Use `get_y` to extract the row and `get_value` to extract the cell's value from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(
+        get_y: impl Fn(&T) -> Y + Send + Sync + 'static,
+        get_value: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Arc::new(get_y),
+            get_value: Arc::new(get_value),
+            name: RwSignal::default(),
+            gradient: RwSignal::new(HEATMAP_GRADIENT.into()),
+            gap: RwSignal::new(HEATMAP_GAP),
+            format: RwSignal::new(Arc::new(format_value)),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the heatmap. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour scheme that values are mapped through e.g., a [SequentialGradient] or [DivergingGradient](crate::DivergingGradient).
+    pub fn with_gradient(self, gradient: impl Into<ColourScheme>) -> Self {
+        self.gradient.set(gradient.into());
+        self
+    }
+
+    /// Set the gap between cells. Clamped to 0.0 and 1.0. Default is [HEATMAP_GAP].
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Set how cell values are formatted in tooltips and the legend. Defaults to at most two decimal places.
+    pub fn with_format(self, format: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        self.format.set(Arc::new(format));
+        self
+    }
+
+    /// Set the Y axis the rows are drawn against. Use [YAxis::Secondary] for rows with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+}
+
+impl<T, Y> Clone for Heatmap<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            get_value: self.get_value.clone(),
+            name: self.name,
+            gradient: self.gradient,
+            gap: self.gap,
+            format: self.format,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+impl UseHeatmap {
+    /// Formats a cell value.
+    pub(crate) fn format_value(&self, value: f64) -> String {
+        self.format.with(|format| format(value))
+    }
+}
+
+/// Gets the row of a cell. The cell's value is the per-point colour value.
+struct GetCell<T, Y> {
+    y: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    value: GetValue<T>,
+}
+
+impl<T, Y> GetYValue<T, Y> for GetCell<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.y)(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        (self.y)(t)
+    }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        Some((f64::NAN, (self.value)(t)))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Heatmap<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        // Uses its own colours instead of the series'
+        _ = series.push_use_y((*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseY<T, Y> for Heatmap<T, Y> {
+    fn into_use_y(self, id: usize) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let heatmap = UseY::new(
+            id,
+            self.name,
+            self.y_axis,
+            UseHeatmap {
+                gradient: self.gradient,
+                gap: self.gap,
+                format: self.format,
+            },
+        );
+        let get_y = Arc::new(GetCell {
+            y: self.get_y.clone(),
+            value: self.get_value.clone(),
+        });
+        (heatmap, get_y)
+    }
+}
+
+/// The (width, height) of a cell in SVG coords. Swapped on horizontal charts.
+fn cell_size(x_width: f64, y_width: f64, gap: f64, horizontal: bool) -> (f64, f64) {
+    let gap = 1.0 - gap.clamp(0.0, 1.0);
+    let (width, height) = (x_width * gap, y_width * gap);
+    if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    }
+}
+
+#[component]
+pub fn RenderHeatmap<X: Tick, Y: Tick>(
+    use_y: UseY,
+    heatmap: UseHeatmap,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+) -> impl IntoView {
+    let values = state.pre.data.series_point_values(use_y.id);
+    let range = Memo::new(move |_| values.with(|values| value_ranges(values).1));
+    let proj = state.projection;
+    let (x_width, y_width) = (state.layout.x_width, state.layout.y_width);
+    let cells = move || {
+        let (width, height) = cell_size(
+            x_width.get(),
+            y_width.get(),
+            heatmap.gap.get(),
+            proj.get().is_horizontal(),
+        );
+        let range = range.get();
+        heatmap.gradient.with(|gradient| {
+            values.with(|values| {
+                positions.with(|positions| {
+                    (positions.iter().zip(values))
+                        .filter(|&(&(x, y), &(_, value))| {
+                            !(x.is_nan() || y.is_nan() || value.is_nan())
+                        })
+                        .map(|(&(x, y), &(_, value))| {
+                            view! {
+                                <rect
+                                    x=x - width / 2.0
+                                    y=y - height / 2.0
+                                    width=width
+                                    height=height
+                                    fill=gradient.by_value(value, range).to_string() />
+                            }
+                        })
+                        .collect_view()
+                })
+            })
+        })
+    };
+    view! {
+        <g class="_chartistry_heatmap">
+            {cells}
+        </g>
+    }
+}
+
+/// Renders a colour bar of the heatmap's values from left (min) to right (max).
+#[component]
+pub fn HeatmapTaster<X: Tick, Y: Tick>(
+    use_y: UseY,
+    heatmap: UseHeatmap,
+    state: State<X, Y>,
+    bounds: Memo<Bounds>,
+) -> impl IntoView {
+    let values = state.pre.data.series_point_values(use_y.id);
+    let range = Memo::new(move |_| values.with(|values| value_ranges(values).1));
+    let gradient_id = format!("heatmap_{}_colour_bar", use_y.id);
+    let stops = move || {
+        let range = range.get();
+        let (min, max) = if range.0.is_nan() { (0.0, 1.0) } else { range };
+        heatmap.gradient.with(|gradient| {
+            (0..=COLOUR_BAR_STOPS)
+                .map(|i| {
+                    let ratio = i as f64 / COLOUR_BAR_STOPS as f64;
+                    let colour = gradient.by_value(min + (max - min) * ratio, (min, max));
+                    view! {
+                        <stop offset=format!("{:.2}%", ratio * 100.0) stop-color=colour.to_string() />
+                    }
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g class="_chartistry_heatmap_taster">
+            <defs>
+                <linearGradient id=gradient_id.clone() x1="0%" y1="0%" x2="100%" y2="0%">
+                    {stops}
+                </linearGradient>
+            </defs>
+            <rect
+                x=move || bounds.get().left_x()
+                y=move || bounds.get().top_y()
+                width=move || bounds.get().width()
+                height=move || bounds.get().height()
+                fill=format!("url(#{gradient_id})") />
+        </g>
+    }
+}
+
+/// Renders the (min, max) range of a heatmap's values e.g., next to its colour bar.
+#[component]
+pub fn HeatmapRange<X: Tick, Y: Tick>(
+    use_y: UseY,
+    heatmap: UseHeatmap,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let values = state.pre.data.series_point_values(use_y.id);
+    move || {
+        let (min, max) = values.with(|values| value_ranges(values).1);
+        (!min.is_nan()).then(|| {
+            format!(
+                " {} – {}",
+                heatmap.format_value(min),
+                heatmap.format_value(max)
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        colours::Colour,
+        series::{use_data::UseData, SERIES_COLOUR_SCHEME},
+        Line, Series,
+    };
+
+    #[test]
+    fn test_cell_size() {
+        assert_eq!(cell_size(10.0, 20.0, 0.1, false), (9.0, 18.0));
+        // Rows run along the SVG X axis on horizontal charts
+        assert_eq!(cell_size(10.0, 20.0, 0.1, true), (18.0, 9.0));
+        // Gap is clamped
+        assert_eq!(cell_size(10.0, 20.0, -1.0, false), (10.0, 20.0));
+        assert_eq!(cell_size(10.0, 20.0, 2.0, false), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_colour_scale() {
+        let gradient = ColourScheme::from(HEATMAP_GRADIENT);
+        let range = value_ranges(&[(f64::NAN, 4.0), (f64::NAN, f64::NAN), (f64::NAN, 2.0)]).1;
+        assert_eq!(range, (2.0, 4.0));
+        assert_eq!(gradient.by_value(2.0, range), gradient.by_ratio(0.0));
+        assert_eq!(gradient.by_value(3.0, range), gradient.by_ratio(0.5));
+        assert_eq!(gradient.by_value(4.0, range), gradient.by_ratio(1.0));
+    }
+
+    #[test]
+    fn test_heatmap_series() {
+        let series = Series::new(|d: &(f64, f64)| d.0)
+            .line(Line::new(|d: &(f64, f64)| d.1))
+            .heatmap(
+                Heatmap::new(|d: &(f64, f64)| d.1, |d: &(f64, f64)| d.0)
+                    .with_y_axis(YAxis::Secondary),
+            )
+            .line(Line::new(|d: &(f64, f64)| d.1));
+        let data = UseData::new(series, Signal::derive(Vec::new), Signal::default());
+        let series = data.series.get();
+        assert_eq!(series[1].y_axis.get(), YAxis::Secondary);
+        // Heatmaps don't take a colour from the series
+        let colours = (series.iter())
+            .filter_map(|use_y| use_y.colour())
+            .map(|colour| colour.get())
+            .collect::<Vec<Colour>>();
+        assert_eq!(
+            colours,
+            vec![SERIES_COLOUR_SCHEME[0], SERIES_COLOUR_SCHEME[1]]
+        );
+    }
+}
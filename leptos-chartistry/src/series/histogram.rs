@@ -0,0 +1,211 @@
+use super::{Bar, BoxStats};
+
+/// Maximum number of histogram bins per sample. Binnings that need more bins (e.g., a tiny [Binning::Width] or an extreme outlier with [Binning::FreedmanDiaconis]) fall back to [Binning::Sturges].
+pub const HISTOGRAM_MAX_BINS_PER_SAMPLE: usize = 10;
+
+/// How samples are grouped into the bins of a histogram. See [Binning::bins].
+///
+/// Bin counts are capped at [HISTOGRAM_MAX_BINS_PER_SAMPLE] times the number of samples. Binnings over the cap fall back to [Binning::Sturges].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Binning {
+    /// A fixed number of equal width bins spanning the samples.
+    Count(usize),
+    /// Bins of a fixed width. Edges are aligned to multiples of the width e.g., a width of 10 gives bins of 0-10, 10-20, etc. Widths that aren't positive fall back to [Binning::Sturges].
+    Width(f64),
+    /// Sturges' rule: `log2(n) + 1` bins. Suits small, roughly normal samples.
+    #[default]
+    Sturges,
+    /// Freedman–Diaconis rule: bins are `2 * IQR / cbrt(n)` wide. Robust to outliers. Falls back to [Binning::Sturges] when the interquartile range is zero.
+    FreedmanDiaconis,
+}
+
+/// A bin of a histogram. Counts the samples from `start` (inclusive) to `end` (exclusive). The last bin includes its end.
+///
+/// # Example
+/// Bins are used as chart data and drawn with [Bar::histogram]:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let samples = vec![1.0, 2.5, 2.7, 3.1, 4.8, 5.0];
+/// let data = Binning::Sturges.bins(&samples);
+/// let series = Series::new(HistogramBin::centre).bar(Bar::histogram().with_name("samples"));
+/// // Label bin boundaries
+/// let x_ticks = TickLabels::from(AlignedFloats::default().with_edges(HistogramBin::edges(&data)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct HistogramBin {
+    /// Start edge of the bin.
+    pub start: f64,
+    /// End edge of the bin.
+    pub end: f64,
+    /// Number of samples in the bin.
+    pub count: usize,
+}
+
+impl HistogramBin {
+    /// Centre of the bin. Use as the X value of the series.
+    pub fn centre(&self) -> f64 {
+        self.start + (self.end - self.start) / 2.0
+    }
+
+    /// Edges of a list of bins. Pass to [AlignedFloats::with_edges](crate::AlignedFloats::with_edges) so tick labels land on bin boundaries.
+    pub fn edges(bins: &[HistogramBin]) -> Vec<f64> {
+        let last = bins.last().map(|bin| bin.end);
+        (bins.iter().map(|bin| bin.start)).chain(last).collect()
+    }
+}
+
+impl Binning {
+    /// Groups samples into bins. Non-finite samples are ignored. Returns no bins if there are no samples.
+    pub fn bins(self, samples: &[f64]) -> Vec<HistogramBin> {
+        let samples = (samples.iter().copied())
+            .filter(|s| s.is_finite())
+            .collect::<Vec<_>>();
+        if samples.is_empty() {
+            return Vec::new();
+        }
+        let (start, width, count) = self.layout(&samples);
+        let mut bins = (0..count)
+            .map(|i| HistogramBin {
+                // Avoid f64 accumulation errors
+                start: start + i as f64 * width,
+                end: start + (i + 1) as f64 * width,
+                count: 0,
+            })
+            .collect::<Vec<_>>();
+        for s in samples {
+            let index = ((s - start) / width).floor() as usize;
+            bins[index.min(count - 1)].count += 1;
+        }
+        bins
+    }
+
+    /// Finds the first edge, bin width, and bin count of non-empty, finite samples. An invalid width falls back to [Binning::Sturges].
+    fn layout(self, samples: &[f64]) -> (f64, f64, usize) {
+        let (min, max) = samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &s| {
+                (min.min(s), max.max(s))
+            });
+        let by_count = |count: usize| {
+            if min == max {
+                // All samples are the same: centre a single bin on them
+                (min - 0.5, 1.0, 1)
+            } else {
+                let count = count.max(1);
+                (min, (max - min) / count as f64, count)
+            }
+        };
+        let sturges = (samples.len() as f64).log2().ceil() as usize + 1;
+        // Too many bins fall back to Sturges. Compared as floats to avoid overflow
+        let max_bins = samples.len().saturating_mul(HISTOGRAM_MAX_BINS_PER_SAMPLE) as f64;
+        let capped = |count: f64| {
+            if count <= max_bins {
+                by_count(count as usize)
+            } else {
+                by_count(sturges)
+            }
+        };
+        match self {
+            Self::Count(count) => capped(count as f64),
+            Self::Width(width) if width > 0.0 && width.is_finite() => {
+                let start = (min / width).floor() * width;
+                let count = ((max - start) / width).floor() + 1.0;
+                if count <= max_bins {
+                    (start, width, count as usize)
+                } else {
+                    by_count(sturges)
+                }
+            }
+            Self::Width(_) | Self::Sturges => by_count(sturges),
+            Self::FreedmanDiaconis => {
+                let stats = BoxStats::from_samples(samples);
+                let iqr = stats.q3 - stats.q1;
+                if iqr > 0.0 {
+                    let width = 2.0 * iqr / (samples.len() as f64).cbrt();
+                    capped(((max - min) / width).ceil())
+                } else {
+                    by_count(sturges)
+                }
+            }
+        }
+    }
+}
+
+impl Bar<HistogramBin, f64> {
+    /// Create a new bar that draws [HistogramBin]s. Bars are adjacent (no gap) and span the edges of their bin. Use [HistogramBin::centre] as the X value of the series.
+    ///
+    /// See [HistogramBin] for an example.
+    pub fn histogram() -> Self {
+        Bar::new(|bin: &HistogramBin| bin.count as f64)
+            .with_gap(0.0)
+            .with_group_gap(0.0)
+            .with_x_span(|bin: &HistogramBin| (bin.start, bin.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(bins: &[HistogramBin]) -> Vec<usize> {
+        bins.iter().map(|bin| bin.count).collect()
+    }
+
+    #[test]
+    fn test_binning() {
+        let samples = [
+            0.0,
+            1.0,
+            2.0,
+            3.0,
+            4.0,
+            5.0,
+            6.0,
+            7.0,
+            8.0,
+            9.0,
+            10.0,
+            f64::NAN,
+        ];
+        // Fixed count spans the samples. Max is in the last bin
+        let bins = Binning::Count(5).bins(&samples);
+        assert_eq!(counts(&bins), vec![2, 2, 2, 2, 3]);
+        assert_eq!(
+            HistogramBin::edges(&bins),
+            vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]
+        );
+        assert_eq!(bins[0].centre(), 1.0);
+        // Fixed width aligns edges
+        let bins = Binning::Width(4.0).bins(&samples[1..]);
+        assert_eq!(HistogramBin::edges(&bins), vec![0.0, 4.0, 8.0, 12.0]);
+        assert_eq!(counts(&bins), vec![3, 4, 3]);
+        // Sturges: ceil(log2(11)) + 1
+        assert_eq!(Binning::Sturges.bins(&samples).len(), 5);
+        // Freedman–Diaconis: IQR of 5 gives a width of 10 / cbrt(11)
+        assert_eq!(Binning::FreedmanDiaconis.bins(&samples).len(), 3);
+        // Identical samples
+        let bins = Binning::Sturges.bins(&[2.0, 2.0]);
+        assert_eq!(HistogramBin::edges(&bins), vec![1.5, 2.5]);
+        assert_eq!(counts(&bins), vec![2]);
+        // Nothing to bin
+        assert_eq!(Binning::Sturges.bins(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_binning_cap() {
+        // An extreme outlier with a tiny IQR would need billions of bins
+        let mut samples = (0..40).map(|i| i as f64 * 1e-9).collect::<Vec<_>>();
+        samples.push(1e12);
+        let sturges = Binning::Sturges.bins(&samples);
+        assert_eq!(sturges.len(), 7);
+        assert_eq!(Binning::FreedmanDiaconis.bins(&samples), sturges);
+        // Tiny widths and huge counts fall back too
+        assert_eq!(Binning::Width(1e-9).bins(&samples), sturges);
+        assert_eq!(Binning::Count(usize::MAX).bins(&samples), sturges);
+        // At the cap is fine
+        let max = samples.len() * HISTOGRAM_MAX_BINS_PER_SAMPLE;
+        assert_eq!(Binning::Count(max).bins(&samples).len(), max);
+    }
+}
@@ -71,6 +71,32 @@ impl Interpolation {
             Self::Monotone => monotone(points),
         }
     }
+
+    /// Closed path of the area between two lines. Each point on the line is paired with a base point. Gaps (NaN) in either split the area.
+    pub(crate) fn area_path(self, line: &[(f64, f64)], bases: &[(f64, f64)]) -> String {
+        let is_nan = |(x, y): &(f64, f64)| x.is_nan() || y.is_nan();
+        let mut path = String::new();
+        let mut start = 0;
+        for end in 0..=line.len() {
+            let gap = match (line.get(end), bases.get(end)) {
+                (Some(point), Some(base)) => is_nan(point) || is_nan(base),
+                _ => true,
+            };
+            if gap {
+                if end - start > 1 {
+                    // Along the line then back along the base
+                    let mut bases = bases[start..end].to_vec();
+                    bases.reverse();
+                    let back = self.path(&bases).replacen('M', "L", 1);
+                    path.push_str(&self.path(&line[start..end]));
+                    path.push_str(&back);
+                    path.push_str("Z ");
+                }
+                start = end + 1;
+            }
+        }
+        path
+    }
 }
 
 fn linear(points: &[(f64, f64)]) -> String {
@@ -181,3 +207,37 @@ fn tangent(x_prev: f64, x: f64, x_next: f64, y_prev: f64, y: f64, y_next: f64) -
     // Tangent
     (slope_prev.signum() + slope.signum()) * slope_prev.abs().min(0.5 * para.abs())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_path() {
+        let line = [
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (f64::NAN, f64::NAN),
+            (3.0, 4.0),
+            (4.0, 5.0),
+        ];
+        let bases = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (3.0, 0.0),
+            (4.0, f64::NAN),
+        ];
+        assert_eq!(
+            Interpolation::Linear.area_path(&line, &bases),
+            "M 0 1 L 1 2 L 1 0 L 0 0 Z "
+        );
+        // Base doesn't split
+        let bases = [(0.0, 0.0); 5];
+        let area = Interpolation::Linear.area_path(&line, &bases);
+        assert_eq!(area.matches('M').count(), 2);
+        assert_eq!(area.matches('Z').count(), 2);
+        // Not enough points
+        assert_eq!(Interpolation::Linear.area_path(&line[..1], &bases), "");
+    }
+}
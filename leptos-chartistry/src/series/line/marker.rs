@@ -1,5 +1,6 @@
 use super::UseLine;
 use crate::colours::Colour;
+use crate::series::error_bars::ErrorBars;
 use leptos::{either::EitherOf7, prelude::*};
 
 // Scales our marker (drawn -1 to 1) to a 1.0 line width
@@ -95,9 +96,34 @@ impl Marker {
 }
 
 #[component]
-pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
+pub(super) fn LineMarkers(
+    line: UseLine,
+    positions: Signal<Vec<(f64, f64)>>,
+    errors: Signal<Vec<(f64, f64)>>,
+    horizontal: bool,
+) -> impl IntoView {
     let marker = line.marker.clone();
 
+    // Error bars at each point. Caps are the width of a marker
+    let error_bars = {
+        let marker = marker.clone();
+        move || {
+            let cap_width = line.width.get() * WIDTH_TO_MARKER * marker.scale.get();
+            let bands = positions.with(|positions| {
+                (positions.iter())
+                    .map(|&(x, y)| if horizontal { y } else { x })
+                    .collect::<Vec<_>>()
+            });
+            view! {
+                <ErrorBars
+                    bands=bands
+                    errors=errors.get()
+                    horizontal=horizontal
+                    cap_width=cap_width />
+            }
+        }
+    };
+
     // Disable border if no marker
     let border_width = Signal::derive(move || {
         if marker.shape.get() == MarkerShape::None {
@@ -137,6 +163,9 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
     };
 
     view! {
+        <g stroke=move || line.colour.get().to_string() stroke-width=line.width>
+            {error_bars}
+        </g>
         <g
             fill=move || marker.colour.get().unwrap_or_else(|| line.colour.get()).to_string()
             stroke=move || marker.border.get().unwrap_or_else(|| line.colour.get()).to_string()
@@ -149,7 +178,7 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
 
 /// Renders the marker shape in a square. They should all be similar in size and not just extend to the edge e.g., square is a rotated diamond.
 #[component]
-fn MarkerShape(
+pub(crate) fn MarkerShape(
     shape: MarkerShape,
     x: f64,
     y: f64,
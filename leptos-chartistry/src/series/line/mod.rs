@@ -1,12 +1,16 @@
 mod interpolation;
-mod marker;
+pub(super) mod marker;
 pub use interpolation::{Interpolation, Step};
 pub use marker::{Marker, MarkerShape};
 
-use super::{ApplyUseSeries, IntoUseLine, SeriesAcc, UseData, UseY};
+use super::{
+    error_bars::{GetErrors, WithErrors},
+    ApplyUseSeries, IntoUseLine, SeriesAcc, UseData, UseY, YAxis,
+};
 use crate::{
     colours::{Colour, DivergingGradient, LinearGradientSvg, SequentialGradient, BERLIN, LIPARI},
-    series::GetYValue,
+    series::{GetYValue, Orientation},
+    state::State,
     ColourScheme, Tick,
 };
 use leptos::prelude::*;
@@ -18,6 +22,9 @@ pub const LINEAR_GRADIENT: SequentialGradient = LIPARI;
 /// Suggested colour scheme for a diverging gradient on a line. Uses a blue for negative values, a dark central value and red for positive values. Assumes a light background.
 pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 
+/// Default opacity of the area under a line. See [Line::with_fill].
+pub const AREA_FILL_OPACITY: f64 = 0.4;
+
 /// Draws a line on the chart.
 ///
 /// # Simple example
@@ -41,9 +48,22 @@ pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 ///     .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
 /// ```
 /// See this in action with the [legend example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#legend).
+///
+/// # Area charts
+/// Lines can fill the area beneath them. Lines in a [Stack](crate::Stack) fill down to the line beneath them, giving a stacked area chart:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Memory { at: f64, cache: f64, heap: f64 }
+/// let stack = Stack::new()
+///     .line(Line::new(|m: &Memory| m.cache).with_name("cache").with_fill(AreaFill::Zero))
+///     .line(Line::new(|m: &Memory| m.heap).with_name("heap").with_fill(AreaFill::Zero));
+/// ```
+/// Use [Line::with_fill_to] to fill between a line and another set of values.
 #[non_exhaustive]
 pub struct Line<T, Y> {
     get_y: Arc<dyn GetYValue<T, Y>>,
+    fill_to: Option<Arc<dyn GetYValue<T, Y>>>,
+    errors: Option<GetErrors<T, Y>>,
     /// Name of the line. Used in the legend.
     pub name: RwSignal<String>,
     /// Colour of the line. If not set, the next colour in the series will be used.
@@ -56,15 +76,36 @@ pub struct Line<T, Y> {
     pub interpolation: RwSignal<Interpolation>,
     /// Marker at each point on the line.
     pub marker: Marker,
+    /// The Y axis the line is drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+    /// Fills the area beneath the line. Default is [AreaFill::None].
+    pub fill: RwSignal<AreaFill>,
+    /// Opacity of the filled area. Default is [AREA_FILL_OPACITY].
+    pub fill_opacity: RwSignal<f64>,
+}
+
+/// Where the area beneath a line is filled to.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum AreaFill {
+    /// No fill. Only the line is drawn.
+    #[default]
+    None,
+    /// Fills down to the zero line. Lines in a [Stack](crate::Stack) fill down to the line beneath them and [Line::with_fill_to] fills to its values instead.
+    Zero,
+    /// Fills down to the bottom edge of the chart (the left edge of a horizontal chart).
+    Edge,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseLine {
-    colour: Signal<Colour>,
+    pub(super) colour: Signal<Colour>,
     gradient: RwSignal<Option<ColourScheme>>,
     width: RwSignal<f64>,
     interpolation: RwSignal<Interpolation>,
     marker: Marker,
+    fill: RwSignal<AreaFill>,
+    fill_opacity: RwSignal<f64>,
 }
 
 impl<T, Y> Line<T, Y> {
@@ -77,12 +118,17 @@ impl<T, Y> Line<T, Y> {
     {
         Self {
             get_y: Arc::new(get_y),
+            fill_to: None,
+            errors: None,
             name: RwSignal::default(),
             colour: RwSignal::default(),
             gradient: RwSignal::default(),
             width: RwSignal::new(1.0),
             interpolation: RwSignal::default(),
             marker: Marker::default(),
+            y_axis: RwSignal::default(),
+            fill: RwSignal::default(),
+            fill_opacity: RwSignal::new(AREA_FILL_OPACITY),
         }
     }
 
@@ -123,18 +169,68 @@ impl<T, Y> Line<T, Y> {
         self.marker = marker.into();
         self
     }
+
+    /// Set the Y axis the line is drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+
+    /// Fills the area beneath the line. Uses the line's colour (or gradient) with [Line::fill_opacity].
+    pub fn with_fill(self, fill: impl Into<AreaFill>) -> Self {
+        self.fill.set(fill.into());
+        self
+    }
+
+    /// Fills the area between the line and another set of values e.g., a lower bound or another line. The Y axis is extended to include them. Sets [AreaFill::Zero] if there is no fill.
+    ///
+    /// Has no effect on lines in a [Stack](crate::Stack) which always fill to the line beneath.
+    pub fn with_fill_to(mut self, get_y: impl Fn(&T) -> Y + Send + Sync + 'static) -> Self
+    where
+        T: 'static,
+        Y: Tick,
+    {
+        self.fill_to = Some(Arc::new(get_y));
+        if self.fill.get_untracked() == AreaFill::None {
+            self.fill.set(AreaFill::Zero);
+        }
+        self
+    }
+
+    /// Set the opacity of the filled area. Default is [AREA_FILL_OPACITY].
+    pub fn with_fill_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.fill_opacity.set(opacity.into());
+        self
+    }
+
+    /// Draws error bars from `lower` to `upper` at each point on the line e.g., `y - error` and `y + error`. The Y axis is extended to include them.
+    ///
+    /// Errors are drawn where given: lines in a [Stack](crate::Stack) don't offset them.
+    pub fn with_error_bars(
+        mut self,
+        lower: impl Fn(&T) -> Y + Send + Sync + 'static,
+        upper: impl Fn(&T) -> Y + Send + Sync + 'static,
+    ) -> Self {
+        self.errors = Some((Arc::new(lower), Arc::new(upper)));
+        self
+    }
 }
 
 impl<T, Y> Clone for Line<T, Y> {
     fn clone(&self) -> Self {
         Self {
             get_y: self.get_y.clone(),
+            fill_to: self.fill_to.clone(),
+            errors: self.errors.clone(),
             name: self.name,
             colour: self.colour,
             gradient: self.gradient,
             width: self.width,
             interpolation: self.interpolation,
             marker: self.marker.clone(),
+            y_axis: self.y_axis,
+            fill: self.fill,
+            fill_opacity: self.fill_opacity,
         }
     }
 }
@@ -155,29 +251,123 @@ impl<T, Y: Tick, U: Fn(&T) -> Y + Send + Sync> GetYValue<T, Y> for U {
     }
 }
 
-impl<T, Y> ApplyUseSeries<T, Y> for Line<T, Y> {
+/// A line filled to another set of values.
+struct FillTo<T, Y> {
+    line: Arc<dyn GetYValue<T, Y>>,
+    to: Arc<dyn GetYValue<T, Y>>,
+}
+
+impl<T, Y> GetYValue<T, Y> for FillTo<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        self.line.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        self.line.stacked_value(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<Y> {
+        Some(self.to.value(t))
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        self.line.extra_values(t)
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Line<T, Y> {
     fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
         let colour = series.next_colour();
         _ = series.push_line(colour, (*self).clone());
     }
 }
 
-impl<T, Y> IntoUseLine<T, Y> for Line<T, Y> {
+impl<T: 'static, Y: 'static> IntoUseLine<T, Y> for Line<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
         let override_colour = self.colour;
         let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
-        let line = UseY::new_line(
+        let line = UseY::new(
             id,
             self.name,
+            self.y_axis,
             UseLine {
                 colour,
                 gradient: self.gradient,
                 width: self.width,
                 interpolation: self.interpolation,
                 marker: self.marker.clone(),
+                fill: self.fill,
+                fill_opacity: self.fill_opacity,
             },
         );
-        (line, self.get_y.clone())
+        let get_y = WithErrors::wrap(self.get_y.clone(), self.errors.clone());
+        let get_y = match self.fill_to.clone() {
+            Some(to) => Arc::new(FillTo { line: get_y, to }),
+            None => get_y,
+        };
+        (line, get_y)
+    }
+}
+
+impl UseLine {
+    /// Finds the SVG coords of the base of each position: where the area beneath the line is filled down to. Bases (e.g., the line beneath in a stack) are given along the Y axis and take precedence over the fill.
+    pub(super) fn area_bases<X: Tick, Y: Tick>(
+        &self,
+        state: &State<X, Y>,
+        positions: Signal<Vec<(f64, f64)>>,
+        bases: Signal<Vec<f64>>,
+    ) -> Signal<Vec<(f64, f64)>> {
+        let fill = self.fill;
+        let proj = state.projection;
+        let inner = state.layout.inner;
+        Signal::derive(move || {
+            let fill = fill.get();
+            if fill == AreaFill::None {
+                return Vec::new();
+            }
+            let proj = proj.get();
+            let horizontal = proj.is_horizontal();
+            let inner = inner.get();
+            let edge = if horizontal {
+                inner.left_x()
+            } else {
+                inner.bottom_y()
+            };
+            // Keep areas inside the chart. Log scales can't show zero so use the edge instead
+            let clamp = |svg: f64| {
+                if svg.is_nan() {
+                    edge
+                } else if horizontal {
+                    svg.clamp(inner.left_x(), inner.right_x())
+                } else {
+                    svg.clamp(inner.top_y(), inner.bottom_y())
+                }
+            };
+            let default = match fill {
+                AreaFill::Edge => edge,
+                _ => clamp(proj.y_to_svg(0.0)),
+            };
+            bases.with(|bases| {
+                positions.with(|positions| {
+                    positions
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &(x, y))| {
+                            let base = bases
+                                .get(index)
+                                .copied()
+                                .filter(|base| base.is_finite())
+                                .map_or(default, clamp);
+                            if horizontal {
+                                (base, y)
+                            } else {
+                                (x, base)
+                            }
+                        })
+                        .collect()
+                })
+            })
+        })
     }
 }
 
@@ -188,8 +378,16 @@ pub fn RenderLine<X: Tick, Y: Tick>(
     data: UseData<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
     markers: Signal<Vec<(f64, f64)>>,
+    #[prop(into)] bases: Signal<Vec<(f64, f64)>>,
+    #[prop(into)] errors: Signal<Vec<(f64, f64)>>,
 ) -> impl IntoView {
+    let horizontal = data.orientation == Orientation::Horizontal;
     let path = move || positions.with(|positions| line.interpolation.get().path(positions));
+    // Area from each position down to its base
+    let area = move || {
+        let interpolation = line.interpolation.get();
+        bases.with(|bases| positions.with(|positions| interpolation.area_path(positions, bases)))
+    };
 
     // Line colour
     let gradient_id = format!("line_{}_gradient", use_y.id);
@@ -210,9 +408,11 @@ pub fn RenderLine<X: Tick, Y: Tick>(
             .get()
             .unwrap_or_else(|| LINEAR_GRADIENT.into())
     });
-    let range_y = Signal::derive(move || data.range_y.read().positions());
+    let y_axis = use_y.y_axis;
+    let range_y = Signal::derive(move || data.range_y(y_axis.get()).read().positions());
 
     let width = line.width;
+    let (fill, fill_opacity) = (line.fill, line.fill_opacity);
     view! {
         <g
             class="_chartistry_line"
@@ -228,8 +428,69 @@ pub fn RenderLine<X: Tick, Y: Tick>(
                         range_y=range_y />
                 </Show>
             </defs>
+            <Show when=move || fill.get() != AreaFill::None>
+                <path
+                    class="_chartistry_line_area"
+                    d=area
+                    fill=stroke
+                    fill-opacity=fill_opacity
+                    stroke="none" />
+            </Show>
             <path d=path fill="none" />
-            <marker::LineMarkers line=line positions=markers />
+            <marker::LineMarkers
+                line=line
+                positions=markers
+                errors=errors
+                horizontal=horizontal />
         </g>
     }
 }
+
+/// Renders a bare line path for a [Sparkline](crate::Sparkline). Only the line's colour, width, and interpolation are used.
+#[component]
+pub fn SparklinePath(line: UseLine, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
+    let path = move || positions.with(|positions| line.interpolation.get().path(positions));
+    view! {
+        <path
+            class="_chartistry_sparkline_line"
+            d=path
+            fill="none"
+            stroke=move || line.colour.get().to_string()
+            stroke-linecap="round"
+            stroke-linejoin="bevel"
+            stroke-width=line.width />
+    }
+}
+
+/// Renders a line as a closed shape for a [PolarChart](crate::PolarChart) e.g., a radar chart. Points are joined by straight lines and missing points are skipped. The shape is filled if the line has an [AreaFill].
+#[component]
+pub fn RadarPath(line: UseLine, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
+    let path = move || {
+        positions.with(|positions| {
+            let points = (positions.iter().copied())
+                .filter(|(x, y)| !(x.is_nan() || y.is_nan()))
+                .collect::<Vec<_>>();
+            if points.is_empty() {
+                return String::new();
+            }
+            format!("{}Z", Interpolation::Linear.path(&points))
+        })
+    };
+    let fill_opacity = line.fill_opacity;
+    let fill_opacity = move || match line.fill.get() {
+        AreaFill::None => 0.0,
+        _ => fill_opacity.get(),
+    };
+    let colour = move || line.colour.get().to_string();
+    view! {
+        <path
+            class="_chartistry_radar_line"
+            d=path
+            fill=colour
+            fill-opacity=fill_opacity
+            stroke=colour
+            stroke-linecap="round"
+            stroke-linejoin="bevel"
+            stroke-width=line.width />
+    }
+}
@@ -1,20 +1,44 @@
+mod band;
 mod bar;
+mod box_plot;
+mod candlestick;
+mod error_bars;
+mod heatmap;
+mod histogram;
 mod line;
+mod scatter;
 mod stack;
 mod use_data;
 mod use_y;
+mod waterfall;
 
-pub use bar::{Bar, BarPlacement, BAR_GAP, BAR_GAP_INNER};
+pub use band::{Band, BAND_OPACITY};
+pub(crate) use bar::group_count;
+pub use bar::{Bar, BarPlacement, BAR_GAP, BAR_GAP_INNER, BAR_NEGATIVE_COLOUR_SCHEME};
+pub use box_plot::{BoxPlot, BoxStats, BOX_PLOT_OPACITY};
+pub(crate) use candlestick::format_ohlc;
+pub use candlestick::{
+    Candlestick, CandlestickStyle, CANDLESTICK_DOWN_COLOUR, CANDLESTICK_UP_COLOUR,
+};
+pub use error_bars::ERROR_BAR_COLOUR;
+pub use heatmap::{Heatmap, HEATMAP_GAP, HEATMAP_GRADIENT};
+pub use histogram::{Binning, HistogramBin, HISTOGRAM_MAX_BINS_PER_SAMPLE};
 pub use line::{
-    Interpolation, Line, Marker, MarkerShape, Step, DIVERGING_GRADIENT, LINEAR_GRADIENT,
+    AreaFill, Interpolation, Line, Marker, MarkerShape, Step, AREA_FILL_OPACITY,
+    DIVERGING_GRADIENT, LINEAR_GRADIENT,
 };
+pub(crate) use line::{RadarPath, SparklinePath};
+pub use scatter::{Scatter, SCATTER_OPACITY, SCATTER_SIZE};
 pub use stack::{Stack, STACK_COLOUR_SCHEME};
-pub use use_data::{RenderData, UseData};
+pub use use_data::{Range, RenderData, UseData};
 pub use use_y::{Snippet, UseY};
+pub use waterfall::{
+    WaterfallChange, WaterfallStep, WATERFALL_INCREASE_COLOUR, WATERFALL_TOTAL_COLOUR,
+};
 
 use crate::{
     colours::{Colour, ColourScheme},
-    Tick,
+    Scale, Tick,
 };
 use leptos::prelude::*;
 use std::sync::Arc;
@@ -35,10 +59,41 @@ pub const SERIES_COLOUR_SCHEME: [Colour; 10] = [
 
 type GetX<T, X> = Arc<dyn Fn(&T) -> X + Send + Sync>;
 type GetY<T, Y> = Arc<dyn GetYValue<T, Y>>;
+type GetRangeY<Y> = (RwSignal<YAxis>, Arc<dyn Fn() -> Option<Y> + Send + Sync>);
 
 trait GetYValue<T, Y>: Send + Sync {
     fn value(&self, t: &T) -> Y;
     fn stacked_value(&self, t: &T) -> Y;
+
+    /// Where the stacked value is drawn from e.g., the top of the previous bar in a stack. None uses the series default.
+    fn stacked_base(&self, _: &T) -> Option<Y> {
+        None
+    }
+
+    /// Per-point values e.g., (size, colour) for a bubble chart. Values may be `f64::NAN` when unset. None if the series has no per-point values.
+    fn point_values(&self, _: &T) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// The (start, end) X positions a data point spans e.g., a histogram bin. None uses the series default.
+    fn x_span(&self, _: &T) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Whether the data point is a total e.g., a waterfall total. Totals are drawn in the bar's total colour.
+    fn is_total(&self, _: &T) -> bool {
+        false
+    }
+
+    /// Extra values of a data point e.g., the high and low of a candlestick. The Y axis is extended to include them.
+    fn extra_values(&self, _: &T) -> Vec<Y> {
+        Vec::new()
+    }
+
+    /// The (value, stacked value, extra values) of a data point. Override when they're all derived from one expensive summary e.g., a box plot computed from samples.
+    fn y_values(&self, t: &T) -> (Y, Y, Vec<Y>) {
+        (self.value(t), self.stacked_value(t), self.extra_values(t))
+    }
 }
 
 /// Describes how to render a series of data. A series is a collection of lines, bars, etc. that share the same X and Y axes.
@@ -92,7 +147,7 @@ trait GetYValue<T, Y>: Send + Sync {
 ///
 /// ### Stacking lines
 ///
-/// Another approach is to use a [Stack] to stack lines on top of each other. For example if we wanted to chart the total traffic we could use:
+/// Another approach is to use a [Stack] to stack lines (or bars) on top of each other. For example if we wanted to chart the total traffic we could use:
 ///
 /// ```rust
 /// # use chrono::prelude::*;
@@ -106,6 +161,38 @@ trait GetYValue<T, Y>: Send + Sync {
 ///
 /// This would render the lines on top of each other to show the total traffic. Check this out on the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
 ///
+/// ## Horizontal charts
+///
+/// By default X is drawn left to right and Y bottom to top. A [horizontal](Orientation::Horizontal) series swaps this around: X is drawn top to bottom and Y left to right. This is useful for ranked "top N" lists drawn with bars:
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Country { rank: f64, population: f64 }
+/// let series = Series::new(|c: &Country| c.rank)
+///     .with_orientation(Orientation::Horizontal)
+///     .bar(|c: &Country| c.population);
+/// ```
+///
+/// The chart's edges follow the axis they describe: layouts passed to [Chart](crate::Chart) as `bottom` and `top` (the X axis) are drawn on the left and right edges while `left` and `right` (the Y axis) are drawn on the bottom and top edges.
+///
+/// ## Secondary Y axis
+///
+/// Series with different units (e.g., requests per second and an error rate) can be drawn on a [secondary Y axis](YAxis::Secondary) with its own range and scale. Assign lines or bars to it and pass [TickLabels](crate::TickLabels) for the same axis to the right edge:
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Traffic { at: f64, requests: f64, error_rate: f64 }
+/// let series = Series::new(|t: &Traffic| t.at)
+///     .bar(Bar::new(|t: &Traffic| t.requests).with_name("Requests/s"))
+///     .line(
+///         Line::new(|t: &Traffic| t.error_rate)
+///             .with_name("Error %")
+///             .with_y_axis(YAxis::Secondary),
+///     )
+///     .with_secondary_y_range(0.0, 100.0);
+/// let right = TickLabels::aligned_floats().with_y_axis(YAxis::Secondary);
+/// ```
+///
 /// ## Other options
 ///
 /// Finally, like most other components, you can control aspects such as the colour scheme and data ranges of X and Y.
@@ -114,6 +201,7 @@ trait GetYValue<T, Y>: Send + Sync {
 pub struct Series<T: Send + Sync + 'static, X: Tick, Y: Tick> {
     get_x: GetX<T, X>,
     series: Vec<Arc<dyn ApplyUseSeries<T, Y> + Send + Sync>>,
+    orientation: Orientation,
     /// Optional minimum X value. Extends the lower bound of the X axis if set.
     pub min_x: RwSignal<Option<X>>,
     /// Optional maximum X value. Extends the upper bound of the X axis if set.
@@ -124,6 +212,40 @@ pub struct Series<T: Send + Sync + 'static, X: Tick, Y: Tick> {
     pub max_y: RwSignal<Option<Y>>,
     /// Colour scheme for the series. If there are more lines than colours, the colours will repeat.
     pub colours: RwSignal<ColourScheme>,
+    /// Colour scheme for bars below the zero line. Paired by index with [colours](Self::colours). If there are more bars than colours, the colours will repeat.
+    pub negative_colours: RwSignal<ColourScheme>,
+    /// Scale of the X axis. Default is [Scale::Linear].
+    pub x_scale: RwSignal<Scale>,
+    /// Scale of the Y axis. Default is [Scale::Linear].
+    pub y_scale: RwSignal<Scale>,
+    /// Optional minimum value of the [secondary Y axis](YAxis::Secondary). Extends its lower bound if set.
+    pub min_secondary_y: RwSignal<Option<Y>>,
+    /// Optional maximum value of the [secondary Y axis](YAxis::Secondary). Extends its upper bound if set.
+    pub max_secondary_y: RwSignal<Option<Y>>,
+    /// Scale of the [secondary Y axis](YAxis::Secondary). Default is [Scale::Linear].
+    pub secondary_y_scale: RwSignal<Scale>,
+}
+
+/// The Y axis that a line or bar is drawn against. Each axis has its own range, scale, and projection. See [Series] for an example.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum YAxis {
+    /// The default Y axis. Usually labelled on the left edge.
+    #[default]
+    Primary,
+    /// A secondary Y axis for values with different units. Usually labelled on the right edge.
+    Secondary,
+}
+
+/// The direction the X and Y axes are drawn in. See [Series::with_orientation].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Orientation {
+    /// X is drawn left to right and Y bottom to top. Bars are vertical.
+    #[default]
+    Vertical,
+    /// X is drawn top to bottom and Y left to right. Bars are horizontal.
+    Horizontal,
 }
 
 trait ApplyUseSeries<T, Y> {
@@ -134,16 +256,29 @@ trait IntoUseLine<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
 }
 
+/// Series that bring their own colours e.g., a heatmap's gradient. They don't take a colour from the series.
+trait IntoUseY<T, Y> {
+    fn into_use_y(self, id: usize) -> (UseY, GetY<T, Y>);
+}
+
 trait IntoUseBar<T, Y> {
-    fn into_use_bar(self, id: usize, group_id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+        negative_colour: Memo<Colour>,
+    ) -> (UseY, GetY<T, Y>);
 }
 
 struct SeriesAcc<T, Y> {
     colour_id: usize,
     colours: RwSignal<ColourScheme>,
+    negative_colours: RwSignal<ColourScheme>,
     next_id: usize,
     next_group_id: usize,
     lines: Vec<(UseY, GetY<T, Y>)>,
+    range_y: Vec<GetRangeY<Y>>,
 }
 
 impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
@@ -160,16 +295,62 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
             min_y: RwSignal::default(),
             max_y: RwSignal::default(),
             colours: RwSignal::new(SERIES_COLOUR_SCHEME.into()),
+            negative_colours: RwSignal::new(BAR_NEGATIVE_COLOUR_SCHEME.into()),
+            x_scale: RwSignal::default(),
+            y_scale: RwSignal::default(),
+            min_secondary_y: RwSignal::default(),
+            max_secondary_y: RwSignal::default(),
+            secondary_y_scale: RwSignal::default(),
             series: Vec::new(),
+            orientation: Orientation::default(),
         }
     }
 
+    /// Set the orientation of the chart. Horizontal charts draw X top to bottom and Y left to right. Unlike other options this cannot be changed after the chart is created. Default is [Orientation::Vertical].
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Set the colour scheme for the series. If there are more lines than colours, the colours will repeat.
     pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
         self.colours.set(colours.into());
         self
     }
 
+    /// Set the colour scheme for bars below the zero line. Paired by index with the series colour scheme. If there are more bars than colours, the colours will repeat.
+    pub fn with_negative_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.negative_colours.set(colours.into());
+        self
+    }
+
+    /// Set the scale of the X axis. Values that can't be shown on the scale (e.g., zero on a log scale) are treated as missing data.
+    pub fn with_x_scale(self, scale: impl Into<Scale>) -> Self {
+        self.x_scale.set(scale.into());
+        self
+    }
+
+    /// Set the scale of the Y axis. Values that can't be shown on the scale (e.g., zero on a log scale) are treated as missing data. Pair with [TickLabels::log_floats](crate::TickLabels::log_floats) for log ticks:
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Request { at: f64, latency: f64 }
+    /// let series = Series::new(|r: &Request| r.at)
+    ///     .with_y_scale(Scale::Log10)
+    ///     .line(|r: &Request| r.latency);
+    /// let y_ticks = TickLabels::log_floats();
+    /// ```
+    pub fn with_y_scale(self, scale: impl Into<Scale>) -> Self {
+        self.y_scale.set(scale.into());
+        self
+    }
+
+    /// Set the scale of the [secondary Y axis](YAxis::Secondary).
+    pub fn with_secondary_y_scale(self, scale: impl Into<Scale>) -> Self {
+        self.secondary_y_scale.set(scale.into());
+        self
+    }
+
     /// Set the minimum X value. Extends the lower bound of the X axis if set.
     pub fn with_min_x(self, max_x: impl Into<Option<X>>) -> Self {
         self.min_x.set(max_x.into());
@@ -204,6 +385,17 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self.with_min_y(min_y).with_max_y(max_y)
     }
 
+    /// Set the range of the [secondary Y axis](YAxis::Secondary). Extends its lower and upper bounds if set.
+    pub fn with_secondary_y_range(
+        self,
+        min_y: impl Into<Option<Y>>,
+        max_y: impl Into<Option<Y>>,
+    ) -> Self {
+        self.min_secondary_y.set(min_y.into());
+        self.max_secondary_y.set(max_y.into());
+        self
+    }
+
     /// Adds a line to the series. See [Line] for more details.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
         self.series.push(Arc::new(line.into()));
@@ -218,6 +410,12 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self
     }
 
+    /// Adds a band to the series. See [Band] for more details.
+    pub fn band(mut self, band: impl Into<Band<T, Y>>) -> Self {
+        self.series.push(Arc::new(band.into()));
+        self
+    }
+
     /// Adds a bar to the series. See [Bar] for more details.
     pub fn bar(mut self, bar: impl Into<Bar<T, Y>>) -> Self {
         self.series.push(Arc::new(bar.into()));
@@ -232,6 +430,30 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self
     }
 
+    /// Adds a box plot to the series. See [BoxPlot] for more details.
+    pub fn box_plot(mut self, box_plot: impl Into<BoxPlot<T, Y>>) -> Self {
+        self.series.push(Arc::new(box_plot.into()));
+        self
+    }
+
+    /// Adds a candlestick to the series. See [Candlestick] for more details.
+    pub fn candlestick(mut self, candlestick: impl Into<Candlestick<T, Y>>) -> Self {
+        self.series.push(Arc::new(candlestick.into()));
+        self
+    }
+
+    /// Adds a heatmap to the series. See [Heatmap] for more details.
+    pub fn heatmap(mut self, heatmap: impl Into<Heatmap<T, Y>>) -> Self {
+        self.series.push(Arc::new(heatmap.into()));
+        self
+    }
+
+    /// Adds a scatter to the series. See [Scatter] for more details.
+    pub fn scatter(mut self, scatter: impl Into<Scatter<T, Y>>) -> Self {
+        self.series.push(Arc::new(scatter.into()));
+        self
+    }
+
     /// Gets the current size of the series (number of lines and stacks).
     pub fn len(&self) -> usize {
         self.series.len()
@@ -242,12 +464,12 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self.series.is_empty()
     }
 
-    fn to_use_lines(&self) -> Vec<(UseY, GetY<T, Y>)> {
-        let mut series = SeriesAcc::new(self.colours);
+    fn to_use_series(&self) -> SeriesAcc<T, Y> {
+        let mut series = SeriesAcc::new(self.colours, self.negative_colours);
         for seq in self.series.clone() {
             seq.apply_use_series(&mut series);
         }
-        series.lines
+        series
     }
 }
 
@@ -260,13 +482,15 @@ impl<T: Send + Sync, X: Tick> Series<T, X, f64> {
 }
 
 impl<T, Y> SeriesAcc<T, Y> {
-    fn new(colours: RwSignal<ColourScheme>) -> Self {
+    fn new(colours: RwSignal<ColourScheme>, negative_colours: RwSignal<ColourScheme>) -> Self {
         Self {
             colour_id: 0,
             colours,
+            negative_colours,
             next_id: 0,
             next_group_id: 0,
             lines: Vec::new(),
+            range_y: Vec::new(),
         }
     }
 
@@ -277,6 +501,23 @@ impl<T, Y> SeriesAcc<T, Y> {
         Memo::new(move |_| colours.get().by_index(id))
     }
 
+    fn next_bar_colours(&mut self) -> (Memo<Colour>, Memo<Colour>) {
+        let id = self.colour_id;
+        let colour = self.next_colour();
+        let negative_colours = self.negative_colours;
+        let negative_colour = Memo::new(move |_| negative_colours.get().by_index(id));
+        (colour, negative_colour)
+    }
+
+    /// Extends the Y axis to include the value (if set).
+    fn extend_range_y(
+        &mut self,
+        axis: RwSignal<YAxis>,
+        y: impl Fn() -> Option<Y> + Send + Sync + 'static,
+    ) {
+        self.range_y.push((axis, Arc::new(y)));
+    }
+
     fn push_line(&mut self, colour: Memo<Colour>, line: impl IntoUseLine<T, Y>) -> GetY<T, Y> {
         // Create line
         let id = self.next_id;
@@ -287,13 +528,41 @@ impl<T, Y> SeriesAcc<T, Y> {
         get_y
     }
 
-    fn push_bar(&mut self, colour: Memo<Colour>, bar: impl IntoUseBar<T, Y>) -> GetY<T, Y> {
-        // Create bar
+    fn push_use_y(&mut self, use_y: impl IntoUseY<T, Y>) -> GetY<T, Y> {
         let id = self.next_id;
-        let group_id = self.next_group_id;
         self.next_id += 1;
+        let (use_y, get_y) = use_y.into_use_y(id);
+        self.lines.push((use_y, get_y.clone()));
+        get_y
+    }
+
+    fn next_group_id(&mut self) -> usize {
+        let group_id = self.next_group_id;
         self.next_group_id += 1;
-        let (bar, get_y) = bar.into_use_bar(id, group_id, colour);
+        group_id
+    }
+
+    fn push_bar(
+        &mut self,
+        colour: Memo<Colour>,
+        negative_colour: Memo<Colour>,
+        bar: impl IntoUseBar<T, Y>,
+    ) -> GetY<T, Y> {
+        let group_id = self.next_group_id();
+        self.push_bar_in_group(group_id, colour, negative_colour, bar)
+    }
+
+    fn push_bar_in_group(
+        &mut self,
+        group_id: usize,
+        colour: Memo<Colour>,
+        negative_colour: Memo<Colour>,
+        bar: impl IntoUseBar<T, Y>,
+    ) -> GetY<T, Y> {
+        // Create bar
+        let id = self.next_id;
+        self.next_id += 1;
+        let (bar, get_y) = bar.into_use_bar(id, group_id, colour, negative_colour);
         // Insert bar
         self.lines.push((bar, get_y.clone()));
         get_y
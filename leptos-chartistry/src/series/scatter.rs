@@ -0,0 +1,357 @@
+use super::{
+    line::marker::MarkerShape, ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY, YAxis,
+};
+use crate::{
+    bounds::Bounds,
+    colours::{Colour, ColourScheme},
+    state::State,
+    Tick, LINEAR_GRADIENT,
+};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+type GetValue<T> = Arc<dyn Fn(&T) -> f64 + Send + Sync>;
+
+/// Default diameter (in pixels) of a scatter point. Bubbles are scaled up to this size.
+pub const SCATTER_SIZE: f64 = 8.0;
+
+/// Default opacity of scatter points. Helps show overlapping points.
+pub const SCATTER_OPACITY: f64 = 0.8;
+
+/// Draws unconnected points (markers) on the chart. Useful for correlating two values.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Request { payload_kb: f64, latency_ms: f64 }
+/// let series = Series::new(|r: &Request| r.payload_kb)
+///     .scatter(Scatter::new(|r: &Request| r.latency_ms).with_name("latency"));
+/// ```
+/// Data must still be sorted by X.
+///
+/// # Bubble charts
+/// Each point can have its own size and colour. Sizes are scaled so the biggest value is [Scatter::size] across and colours are mapped across a [ColourScheme] from the smallest to the biggest value:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Request { payload_kb: f64, latency_ms: f64, count: f64, errors: f64 }
+/// let scatter = Scatter::new(|r: &Request| r.latency_ms)
+///     .with_size(30.0)
+///     .with_size_by(|r: &Request| r.count)
+///     .with_colour_by(|r: &Request| r.errors)
+///     .with_colours(LINEAR_GRADIENT);
+/// ```
+#[non_exhaustive]
+pub struct Scatter<T, Y> {
+    get_y: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    get_size: Option<GetValue<T>>,
+    get_colour: Option<GetValue<T>>,
+    /// Name of the scatter. Used in the legend.
+    pub name: RwSignal<String>,
+    /// Colour of the points. If not set, the next colour in the series will be used. Ignored when points are coloured by value. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Colour scheme that values are mapped across when points are coloured by value. Default is [LINEAR_GRADIENT].
+    pub colours: RwSignal<ColourScheme>,
+    /// Shape of each point. Default is [MarkerShape::Circle].
+    pub shape: RwSignal<MarkerShape>,
+    /// Diameter of each point in pixels. When sized by value, the diameter of the biggest value. Default is [SCATTER_SIZE].
+    pub size: RwSignal<f64>,
+    /// Opacity of each point. Default is [SCATTER_OPACITY].
+    pub opacity: RwSignal<f64>,
+    /// The Y axis the points are drawn against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseScatter {
+    colour: Signal<Colour>,
+    colours: RwSignal<ColourScheme>,
+    shape: RwSignal<MarkerShape>,
+    size: RwSignal<f64>,
+    opacity: RwSignal<f64>,
+}
+
+impl<T, Y> Scatter<T, Y> {
+    /// Create a new scatter. The `get_y` function is used to extract the Y value from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(get_y: impl Fn(&T) -> Y + Send + Sync + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Arc::new(get_y),
+            get_size: None,
+            get_colour: None,
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            colours: RwSignal::new(LINEAR_GRADIENT.into()),
+            shape: RwSignal::new(MarkerShape::Circle),
+            size: RwSignal::new(SCATTER_SIZE),
+            opacity: RwSignal::new(SCATTER_OPACITY),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the scatter. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the points. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Colour each point by a value. Values are mapped across [Scatter::colours] from the smallest to the biggest.
+    pub fn with_colour_by(
+        mut self,
+        get_colour: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        self.get_colour = Some(Arc::new(get_colour));
+        self
+    }
+
+    /// Set the colour scheme used when colouring points by value.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Set the shape of each point.
+    pub fn with_shape(self, shape: impl Into<MarkerShape>) -> Self {
+        self.shape.set(shape.into());
+        self
+    }
+
+    /// Set the diameter of each point in pixels. When sized by value, sets the diameter of the biggest value.
+    pub fn with_size(self, size: impl Into<f64>) -> Self {
+        self.size.set(size.into());
+        self
+    }
+
+    /// Size each point by a value, giving a bubble chart. A point's area is proportional to its value. Values below zero are hidden.
+    pub fn with_size_by(mut self, get_size: impl Fn(&T) -> f64 + Send + Sync + 'static) -> Self {
+        self.get_size = Some(Arc::new(get_size));
+        self
+    }
+
+    /// Set the opacity of each point.
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+
+    /// Set the Y axis the points are drawn against. Use [YAxis::Secondary] for values with different units.
+    pub fn with_y_axis(self, y_axis: YAxis) -> Self {
+        self.y_axis.set(y_axis);
+        self
+    }
+}
+
+impl<T, Y> Clone for Scatter<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            get_size: self.get_size.clone(),
+            get_colour: self.get_colour.clone(),
+            name: self.name,
+            colour: self.colour,
+            colours: self.colours,
+            shape: self.shape,
+            size: self.size,
+            opacity: self.opacity,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+impl<T, Y: Tick, F: Fn(&T) -> Y + Send + Sync + 'static> From<F> for Scatter<T, Y> {
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+/// Gets the Y value of a scatter point with optional per-point values.
+struct GetScatter<T, Y> {
+    y: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+    size: Option<GetValue<T>>,
+    colour: Option<GetValue<T>>,
+}
+
+impl<T, Y> GetYValue<T, Y> for GetScatter<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.y)(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Y {
+        (self.y)(t)
+    }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        if self.size.is_none() && self.colour.is_none() {
+            return None;
+        }
+        let get = |f: &Option<GetValue<T>>| f.as_ref().map_or(f64::NAN, |f| f(t));
+        Some((get(&self.size), get(&self.colour)))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Scatter<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_line(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseLine<T, Y> for Scatter<T, Y> {
+    fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let scatter = UseY::new(
+            id,
+            self.name,
+            self.y_axis,
+            UseScatter {
+                colour,
+                colours: self.colours,
+                shape: self.shape,
+                size: self.size,
+                opacity: self.opacity,
+            },
+        );
+        let get_y = Arc::new(GetScatter {
+            y: self.get_y.clone(),
+            size: self.get_size.clone(),
+            colour: self.get_colour.clone(),
+        });
+        (scatter, get_y)
+    }
+}
+
+/// Finds the biggest value and the (min, max) range of values. Ignores non-finite values.
+pub(super) fn value_ranges(values: &[(f64, f64)]) -> (f64, (f64, f64)) {
+    let max_size = (values.iter())
+        .map(|&(size, _)| size)
+        .filter(|size| size.is_finite())
+        .fold(f64::NAN, f64::max);
+    let colour = (values.iter())
+        .map(|&(_, colour)| colour)
+        .filter(|colour| colour.is_finite())
+        .fold((f64::NAN, f64::NAN), |(min, max), colour| {
+            (min.min(colour), max.max(colour))
+        });
+    (max_size, colour)
+}
+
+/// Diameter of a point sized by value. The area is proportional to the value.
+fn bubble_diameter(size: f64, max_size: f64, diameter: f64) -> f64 {
+    if size.is_nan() || max_size.is_nan() {
+        diameter
+    } else if size <= 0.0 {
+        0.0
+    } else {
+        diameter * (size / max_size).sqrt()
+    }
+}
+
+#[component]
+pub fn RenderScatter<X: Tick, Y: Tick>(
+    use_y: UseY,
+    scatter: UseScatter,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+) -> impl IntoView {
+    let point_values = state.pre.data.series_point_values(use_y.id);
+    let points = move || {
+        let shape = scatter.shape.get();
+        let diameter = scatter.size.get();
+        let colour = scatter.colour.get();
+        let colours = scatter.colours.get();
+        point_values.with(|values| {
+            let (max_size, (min_colour, max_colour)) = value_ranges(values);
+            positions.with(|positions| {
+                positions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (x, y))| !(x.is_nan() || y.is_nan()))
+                    .map(|(index, &(x, y))| {
+                        let (size, value) =
+                            (values.get(index).copied()).unwrap_or((f64::NAN, f64::NAN));
+                        let diameter = bubble_diameter(size, max_size, diameter);
+                        let fill = if value.is_nan() {
+                            colour
+                        } else {
+                            colours.by_ratio((value - min_colour) / (max_colour - min_colour))
+                        };
+                        view! {
+                            <g fill=fill.to_string()>
+                                <MarkerShape
+                                    shape=shape
+                                    x=x
+                                    y=y
+                                    diameter=diameter
+                                    line_width=diameter / 4.0 />
+                            </g>
+                        }
+                    })
+                    .collect_view()
+            })
+        })
+    };
+    view! {
+        <g
+            class="_chartistry_scatter"
+            fill-opacity=scatter.opacity
+            stroke="none">
+            {points}
+        </g>
+    }
+}
+
+#[component]
+pub fn ScatterTaster(scatter: UseScatter, bounds: Memo<Bounds>) -> impl IntoView {
+    let marker = move || {
+        let bounds = bounds.get();
+        let diameter = bounds.height().min(scatter.size.get());
+        view! {
+            <MarkerShape
+                shape=scatter.shape.get()
+                x=bounds.centre_x()
+                y=bounds.centre_y()
+                diameter=diameter
+                line_width=diameter / 4.0 />
+        }
+    };
+    view! {
+        <g
+            class="_chartistry_scatter_taster"
+            fill=move || scatter.colour.get().to_string()
+            fill-opacity=scatter.opacity>
+            {marker}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bubble_diameter() {
+        let values = [(1.0, 5.0), (4.0, f64::NAN), (f64::NAN, -5.0)];
+        let (max_size, colour) = value_ranges(&values);
+        assert_eq!(max_size, 4.0);
+        assert_eq!(colour, (-5.0, 5.0));
+        // Area is proportional to size
+        assert_eq!(bubble_diameter(4.0, max_size, 20.0), 20.0);
+        assert_eq!(bubble_diameter(1.0, max_size, 20.0), 10.0);
+        // Hidden
+        assert_eq!(bubble_diameter(0.0, max_size, 20.0), 0.0);
+        assert_eq!(bubble_diameter(-1.0, max_size, 20.0), 0.0);
+        // Not sized
+        assert_eq!(bubble_diameter(f64::NAN, max_size, 20.0), 20.0);
+        assert_eq!(bubble_diameter(1.0, f64::NAN, 20.0), 20.0);
+    }
+}
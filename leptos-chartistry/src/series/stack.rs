@@ -1,7 +1,7 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY};
+use super::{ApplyUseSeries, GetYValue, IntoUseBar, IntoUseLine, SeriesAcc, UseY};
 use crate::{
     colours::{Colour, ColourScheme, BATLOW},
-    Line,
+    AreaFill, Bar, Line,
 };
 use leptos::prelude::*;
 use std::sync::Arc;
@@ -9,7 +9,7 @@ use std::sync::Arc;
 /// Default colour scheme for stack. Assumes a light background with dark values for high values.
 pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 
-/// Draws a stack of lines on top of each other.
+/// Draws a stack of lines or bars on top of each other.
 ///
 /// # Example
 /// ```rust
@@ -20,14 +20,39 @@ pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 ///     .line(Line::new(|data: &MyData| data.y2).with_name("pixies"));
 /// ```
 /// See this in action with the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
+///
+/// # Stacked bars
+/// Bars in a stack share the same X position and are drawn on top of each other. Positive and negative values are stacked separately either side of the zero line:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// let stack = Stack::new()
+///     .bar(Bar::new(|data: &MyData| data.y1).with_name("gnomes"))
+///     .bar(Bar::new(|data: &MyData| data.y2).with_name("goblins"));
+/// ```
+/// Lines and bars are stacked independently of each other. Lines with a fill (see [Line::with_fill]) fill down to the line beneath them, giving a stacked area chart.
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct Stack<T, Y> {
-    lines: Vec<Line<T, Y>>,
+    items: Vec<Stacked<T, Y>>,
     /// Colour scheme for the stack. Interpolates colours across the whole scheme.
     pub colours: RwSignal<ColourScheme>,
 }
 
+enum Stacked<T, Y> {
+    Line(Line<T, Y>),
+    Bar(Bar<T, Y>),
+}
+
+impl<T, Y> Clone for Stacked<T, Y> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Line(line) => Self::Line(line.clone()),
+            Self::Bar(bar) => Self::Bar(bar.clone()),
+        }
+    }
+}
+
 impl<T, Y> Stack<T, Y> {
     /// Create a new empty stack.
     pub fn new() -> Self {
@@ -36,18 +61,24 @@ impl<T, Y> Stack<T, Y> {
 
     /// Adds a line to the stack.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
-        self.lines.push(line.into());
+        self.items.push(Stacked::Line(line.into()));
+        self
+    }
+
+    /// Adds a bar to the stack.
+    pub fn bar(mut self, bar: impl Into<Bar<T, Y>>) -> Self {
+        self.items.push(Stacked::Bar(bar.into()));
         self
     }
 
-    /// Gets the current number of lines in the stack.
+    /// Gets the current number of lines and bars in the stack.
     pub fn len(&self) -> usize {
-        self.lines.len()
+        self.items.len()
     }
 
-    /// Returns true if there are no lines in the stack.
+    /// Returns true if there are no lines or bars in the stack.
     pub fn is_empty(&self) -> bool {
-        self.lines.is_empty()
+        self.items.is_empty()
     }
 
     /// Sets the colour scheme for the stack.
@@ -60,7 +91,7 @@ impl<T, Y> Stack<T, Y> {
 impl<T, Y> Default for Stack<T, Y> {
     fn default() -> Self {
         Self {
-            lines: Vec::new(),
+            items: Vec::new(),
             colours: RwSignal::new(ColourScheme::from(STACK_COLOUR_SCHEME).invert()),
         }
     }
@@ -79,18 +110,36 @@ impl<T, Y, I: IntoIterator<Item = Line<T, Y>>> From<I> for Stack<T, Y> {
 impl<T: 'static> ApplyUseSeries<T, f64> for Stack<T, f64> {
     fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, f64>) {
         let colours = self.colours;
-        let total_lines = self.lines.len();
-        let mut previous = Vec::with_capacity(total_lines);
-        for (id, line) in self.lines.clone().into_iter().enumerate() {
-            let colour = Memo::new(move |_| colours.get().interpolate(id, total_lines));
-            let line = StackedLine {
-                line,
-                previous: previous.clone(),
-            };
-            // Add line
-            let get_y = series.push_line(colour, line);
-            // Sum next line with this one
-            previous.push(get_y);
+        let total = self.items.len();
+        let mut previous_lines = Vec::with_capacity(total);
+        let mut previous_bars = Vec::with_capacity(total);
+        // All bars in the stack share a group (X position)
+        let mut group_id = None;
+        for (id, item) in self.items.clone().into_iter().enumerate() {
+            let colour = Memo::new(move |_| colours.get().interpolate(id, total));
+            match item {
+                Stacked::Line(line) => {
+                    let line = StackedLine {
+                        fill: line.fill,
+                        line,
+                        previous: previous_lines.clone(),
+                    };
+                    // Add line
+                    let get_y = series.push_line(colour, line);
+                    // Sum next line with this one
+                    previous_lines.push(get_y);
+                }
+                Stacked::Bar(bar) => {
+                    let bar = StackedBar {
+                        bar,
+                        previous: previous_bars.clone(),
+                    };
+                    // Negative bars are distinguished by position so keep the stack colour
+                    let group_id = *group_id.get_or_insert_with(|| series.next_group_id());
+                    let get_y = series.push_bar_in_group(group_id, colour, colour, bar);
+                    previous_bars.push(get_y);
+                }
+            }
         }
     }
 }
@@ -98,12 +147,14 @@ impl<T: 'static> ApplyUseSeries<T, f64> for Stack<T, f64> {
 #[derive(Clone)]
 struct StackedLine<T, Y> {
     line: Line<T, Y>,
+    fill: RwSignal<AreaFill>,
     previous: Vec<Arc<dyn GetYValue<T, Y>>>,
 }
 
 #[derive(Clone)]
 struct UseStackLine<T, Y> {
     line: Arc<dyn GetYValue<T, Y>>,
+    fill: RwSignal<AreaFill>,
     previous: Vec<Arc<dyn GetYValue<T, Y>>>,
 }
 
@@ -112,6 +163,7 @@ impl<T: 'static> IntoUseLine<T, f64> for StackedLine<T, f64> {
         let (line, get_y) = self.line.into_use_line(id, colour);
         let get_y = Arc::new(UseStackLine {
             line: get_y,
+            fill: self.fill,
             previous: self.previous.clone(),
         });
         (line, get_y)
@@ -131,4 +183,168 @@ impl<T> GetYValue<T, f64> for UseStackLine<T, f64> {
             .filter(|v| v.is_normal())
             .sum()
     }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        self.line.point_values(t)
+    }
+
+    fn x_span(&self, t: &T) -> Option<(f64, f64)> {
+        self.line.x_span(t)
+    }
+
+    fn is_total(&self, t: &T) -> bool {
+        self.line.is_total(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<f64> {
+        self.line.extra_values(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<f64> {
+        // Filled lines are drawn from the line beneath
+        if self.fill.get() == AreaFill::None {
+            return None;
+        }
+        let base = self
+            .previous
+            .iter()
+            .map(|get_y| get_y.value(t))
+            .filter(|v| v.is_normal())
+            .sum();
+        Some(base)
+    }
+}
+
+#[derive(Clone)]
+struct StackedBar<T, Y> {
+    bar: Bar<T, Y>,
+    previous: Vec<Arc<dyn GetYValue<T, Y>>>,
+}
+
+#[derive(Clone)]
+struct UseStackBar<T, Y> {
+    bar: Arc<dyn GetYValue<T, Y>>,
+    previous: Vec<Arc<dyn GetYValue<T, Y>>>,
+}
+
+impl<T: 'static> IntoUseBar<T, f64> for StackedBar<T, f64> {
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+        negative_colour: Memo<Colour>,
+    ) -> (UseY, Arc<dyn GetYValue<T, f64>>) {
+        let (bar, get_y) = self.bar.into_use_bar(id, group_id, colour, negative_colour);
+        let get_y = Arc::new(UseStackBar {
+            bar: get_y,
+            previous: self.previous.clone(),
+        });
+        (bar, get_y)
+    }
+}
+
+impl<T> GetYValue<T, f64> for UseStackBar<T, f64> {
+    fn value(&self, t: &T) -> f64 {
+        self.bar.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> f64 {
+        self.stacked_base(t).unwrap_or_default() + self.value(t)
+    }
+
+    fn point_values(&self, t: &T) -> Option<(f64, f64)> {
+        self.bar.point_values(t)
+    }
+
+    fn x_span(&self, t: &T) -> Option<(f64, f64)> {
+        self.bar.x_span(t)
+    }
+
+    fn is_total(&self, t: &T) -> bool {
+        self.bar.is_total(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<f64> {
+        self.bar.extra_values(t)
+    }
+
+    fn stacked_base(&self, t: &T) -> Option<f64> {
+        // Positive and negative values are stacked separately either side of zero
+        let negative = self.value(t) < 0.0;
+        let base = self
+            .previous
+            .iter()
+            .map(|get_y| get_y.value(t))
+            .filter(|v| v.is_normal() && (*v < 0.0) == negative)
+            .sum();
+        Some(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        series::{use_data::UseData, WaterfallChange, WaterfallStep},
+        Series,
+    };
+
+    #[test]
+    fn test_stacked_bars() {
+        let series = Series::new(|d: &(f64, f64, f64, f64)| d.0).stack(
+            Stack::new()
+                .bar(Bar::new(|d: &(f64, f64, f64, f64)| d.1))
+                .bar(Bar::new(|d: &(f64, f64, f64, f64)| d.2))
+                .bar(Bar::new(|d: &(f64, f64, f64, f64)| d.3)),
+        );
+        let data = vec![(0.0, 2.0, -3.0, 4.0), (1.0, -1.0, f64::NAN, -2.0)];
+        let data = UseData::new(
+            series,
+            Signal::derive(move || data.clone()),
+            Signal::default(),
+        );
+        let ids = (data.series.get().iter()).map(|y| y.id).collect::<Vec<_>>();
+        let stacked = |id| {
+            let tops = data.series_positions(id).get();
+            let bases = data.series_bases(id).get();
+            (tops.iter().zip(bases))
+                .map(|(&(_, top), base)| (base, top))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(stacked(ids[0]), vec![(0.0, 2.0), (0.0, -1.0)]);
+        // Negative values stack below zero, skipping positive values
+        assert_eq!(stacked(ids[1])[0], (0.0, -3.0));
+        // Positive values stack above positive values, skipping negative values
+        assert_eq!(stacked(ids[2])[0], (2.0, 6.0));
+        // Missing values are skipped
+        assert_eq!(stacked(ids[2])[1], (-1.0, -3.0));
+    }
+
+    #[test]
+    fn test_stacked_bar_forwards() {
+        let steps = WaterfallStep::accumulate([
+            (0.0, WaterfallChange::Delta(3.0)),
+            (1.0, WaterfallChange::Total),
+        ]);
+        let series = Series::new(|step: &WaterfallStep<f64>| step.x).stack(
+            Stack::new().bar(Bar::waterfall()).bar(
+                Bar::new(|step: &WaterfallStep<f64>| step.end)
+                    .with_x_span(|step| (step.x, step.x + 0.5)),
+            ),
+        );
+        let data = UseData::new(
+            series,
+            Signal::derive(move || steps.clone()),
+            Signal::default(),
+        );
+        let ids = (data.series.get().iter()).map(|y| y.id).collect::<Vec<_>>();
+        // Waterfall totals are kept
+        assert_eq!(data.series_is_total(ids[0]).get(), vec![false, true]);
+        // Spans are kept
+        assert_eq!(
+            data.series_spans(ids[1]).get(),
+            vec![(0.0, 0.5), (1.0, 1.5)]
+        );
+    }
 }
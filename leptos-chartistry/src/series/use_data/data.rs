@@ -1,7 +1,7 @@
 use super::Range;
 use crate::{
-    series::{GetX, GetY},
-    Tick,
+    series::{GetX, GetY, YAxis},
+    Scale, Tick,
 };
 use std::collections::HashMap;
 
@@ -10,18 +10,60 @@ use std::collections::HashMap;
 pub struct Data<X, Y> {
     data_x: Vec<X>,
     data_y: Vec<HashMap<usize, Y>>,
+    // Values that Y is drawn from e.g., the lower value of a band
+    data_base: Vec<HashMap<usize, Y>>,
+    // Extra values e.g., the high and low of a candlestick
+    data_extra: Vec<HashMap<usize, Vec<Y>>>,
 
     // Data index: X position to data
     x_to_data: Vec<f64>,
     // Rendering data
     coords: HashMap<usize, Vec<(f64, f64)>>,
+    // Y positions that stacked values are drawn from. Only populated for stacked series.
+    bases: HashMap<usize, Vec<f64>>,
+    // Per-point values. Only populated for series with them e.g., scatter (size, colour).
+    point_values: HashMap<usize, Vec<(f64, f64)>>,
+    // (start, end) X positions of each value. Only populated for series with them e.g., spanned bars.
+    spans: HashMap<usize, Vec<(f64, f64)>>,
+    // Whether each value is a total. Only populated for series with totals e.g., waterfalls.
+    totals: HashMap<usize, Vec<bool>>,
+    // Y positions of extra values. Only populated for series with them e.g., candlesticks.
+    extras: HashMap<usize, Vec<Vec<f64>>>,
 
     range_x: Range<X>,
     range_y: Range<Y>,
+    secondary_range_y: Range<Y>,
+}
+
+/// Pushes the value at `index` onto a series' sparse vec. The vec is only created on the series' first value, filling earlier indexes with `missing`. Afterwards, missing values are pushed too so indexes line up with the data.
+fn push_sparse<V: Clone>(
+    map: &mut HashMap<usize, Vec<V>>,
+    id: usize,
+    index: usize,
+    value: Option<V>,
+    missing: V,
+) {
+    match value {
+        Some(value) => map
+            .entry(id)
+            .or_insert_with(|| vec![missing; index])
+            .push(value),
+        None => {
+            if let Some(values) = map.get_mut(&id) {
+                values.push(missing);
+            }
+        }
+    }
 }
 
 impl<X: Tick, Y: Tick> Data<X, Y> {
-    pub fn new<T>(get_x: GetX<T, X>, get_ys: HashMap<usize, GetY<T, Y>>, data: &[T]) -> Self {
+    /// Builds data from `T`. Each Y is assigned to an axis. Values that can't be shown on an axis' scale are left out of its range. Scales are given as (X, Y, secondary Y).
+    pub fn new<T>(
+        get_x: GetX<T, X>,
+        get_ys: HashMap<usize, (YAxis, GetY<T, Y>)>,
+        data: &[T],
+        (scale_x, scale_y, secondary_scale_y): (Scale, Scale, Scale),
+    ) -> Self {
         let cap = data.len();
         let y_cap = get_ys.len();
 
@@ -29,26 +71,86 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         let mut built = Self {
             data_x: Vec::with_capacity(cap),
             data_y: Vec::with_capacity(cap),
+            data_base: Vec::with_capacity(cap),
+            data_extra: Vec::with_capacity(cap),
             x_to_data: Vec::with_capacity(cap * y_cap),
             coords: HashMap::with_capacity(cap),
+            bases: HashMap::new(),
+            point_values: HashMap::new(),
+            spans: HashMap::new(),
+            totals: HashMap::new(),
+            extras: HashMap::new(),
             range_x: Range::default(),
             range_y: Range::default(),
+            secondary_range_y: Range::default(),
         };
 
-        for datum in data {
+        for (index, datum) in data.iter().enumerate() {
             // X
             let x = (get_x)(datum);
             let x_position = x.position();
-            built.range_x.update(&x);
+            if scale_x.contains(x_position) {
+                built.range_x.update(&x);
+            }
             built.x_to_data.push(x_position);
 
             // Y
             let mut y_data = HashMap::with_capacity(y_cap);
-            for (&id, get_y) in &get_ys {
-                let y = get_y.value(datum);
+            let mut base_data = HashMap::new();
+            let mut extra_data = HashMap::new();
+            for (&id, (axis, get_y)) in &get_ys {
+                let (scale_y, range_y) = match axis {
+                    YAxis::Primary => (scale_y, &mut built.range_y),
+                    YAxis::Secondary => (secondary_scale_y, &mut built.secondary_range_y),
+                };
                 // Note: cumulative can differ from Y when stacked
-                let y_stacked = get_y.stacked_value(datum);
-                built.range_y.update(&y_stacked);
+                let (y, y_stacked, extra) = get_y.y_values(datum);
+                if scale_y.contains(y_stacked.position()) {
+                    range_y.update(&y_stacked);
+                }
+                // Where the stacked value is drawn from
+                match get_y.stacked_base(datum) {
+                    Some(base) => {
+                        if scale_y.contains(base.position()) {
+                            range_y.update(&base);
+                        }
+                        push_sparse(&mut built.bases, id, index, Some(base.position()), f64::NAN);
+                        base_data.insert(id, base);
+                    }
+                    None => push_sparse(&mut built.bases, id, index, None, f64::NAN),
+                }
+
+                // Per-point values
+                let values = get_y.point_values(datum);
+                push_sparse(
+                    &mut built.point_values,
+                    id,
+                    index,
+                    values,
+                    (f64::NAN, f64::NAN),
+                );
+
+                // X spans
+                let span = get_y.x_span(datum);
+                push_sparse(&mut built.spans, id, index, span, (f64::NAN, f64::NAN));
+
+                // Totals
+                let total = get_y.is_total(datum).then_some(true);
+                push_sparse(&mut built.totals, id, index, total, false);
+
+                // Extra values
+                if !extra.is_empty() {
+                    for value in &extra {
+                        if scale_y.contains(value.position()) {
+                            range_y.update(value);
+                        }
+                    }
+                    let positions = extra.iter().map(Tick::position).collect();
+                    push_sparse(&mut built.extras, id, index, Some(positions), Vec::new());
+                    extra_data.insert(id, extra);
+                } else {
+                    push_sparse(&mut built.extras, id, index, None, Vec::new());
+                }
 
                 // Insert
                 y_data.insert(id, y);
@@ -62,6 +164,8 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
             // Insert
             built.data_x.push(x);
             built.data_y.push(y_data);
+            built.data_base.push(base_data);
+            built.data_extra.push(extra_data);
         }
 
         built
@@ -75,12 +179,73 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         self.range_x.clone()
     }
 
-    pub fn range_y(&self) -> Range<Y> {
-        self.range_y.clone()
+    pub fn range_y(&self, axis: YAxis) -> Range<Y> {
+        match axis {
+            YAxis::Primary => self.range_y.clone(),
+            YAxis::Secondary => self.secondary_range_y.clone(),
+        }
+    }
+
+    /// Counts the distinct X positions between data indexes (inclusive). Assumes data is sorted by X.
+    pub fn distinct_x(&self, first: usize, last: usize) -> usize {
+        let positions = self.x_to_data.get(first..=last).unwrap_or_default();
+        let changes = positions
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count();
+        positions.len().min(changes + 1)
+    }
+
+    /// Counts the distinct Y positions of the given series.
+    pub fn distinct_y(&self, ids: &[usize]) -> usize {
+        let mut positions = (ids.iter())
+            .flat_map(|id| self.coords.get(id).into_iter().flatten())
+            .map(|&(_, y)| y)
+            .filter(|y| !y.is_nan())
+            .collect::<Vec<_>>();
+        positions.sort_by(f64::total_cmp);
+        positions.dedup();
+        positions.len()
+    }
+
+    /// Finds the index of the data nearest to an (X, Y) position for the given series. Used where many data points share an X value e.g., cells of a heatmap. Returns the Y value and the per-point colour value.
+    pub fn nearest_cells(&self, ids: &[usize], pos_x: f64, pos_y: f64) -> HashMap<usize, (Y, f64)> {
+        // All data at the nearest X
+        let Some((first, last)) =
+            (self.nearest_position_x(pos_x)).and_then(|x| self.index_range(x, x))
+        else {
+            return HashMap::new();
+        };
+        (ids.iter())
+            .filter_map(|&id| {
+                let coords = self.coords.get(&id)?;
+                let index = (first..=last)
+                    .filter(|&index| !coords[index].1.is_nan())
+                    .min_by(|&a, &b| {
+                        let distance = |index: usize| (coords[index].1 - pos_y).abs();
+                        distance(a).total_cmp(&distance(b))
+                    })?;
+                let value = self.point_values.get(&id)?.get(index)?.1;
+                let y = self.data_y[index].get(&id)?.clone();
+                Some((id, (y, value)))
+            })
+            .collect()
+    }
+
+    /// Finds the indexes of the first and last data inside the X positions (inclusive). Returns None if no data is inside.
+    pub fn index_range(&self, min: f64, max: f64) -> Option<(usize, usize)> {
+        let first = self.x_to_data.partition_point(|&v| v < min);
+        let last = self.x_to_data.partition_point(|&v| v <= max);
+        (first < last).then(|| (first, last - 1))
+    }
+
+    /// Gets the X value at the index.
+    pub fn data_x(&self, index: usize) -> Option<X> {
+        self.data_x.get(index).cloned()
     }
 
     /// Finds the index of the _nearest_ position to the given X. Returns None if no data.
-    fn nearest_index(&self, pos_x: f64) -> Option<usize> {
+    pub fn nearest_index(&self, pos_x: f64) -> Option<usize> {
         // No values
         if self.x_to_data.is_empty() {
             return None;
@@ -116,6 +281,20 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
             .unwrap_or_default()
     }
 
+    /// Values that each Y is drawn from at the nearest X. Only set for series with a base such as stacks and bands.
+    pub fn nearest_data_base(&self, pos_x: f64) -> HashMap<usize, Y> {
+        self.nearest_index(pos_x)
+            .map(|index| self.data_base[index].clone())
+            .unwrap_or_default()
+    }
+
+    /// Extra values of each Y at the nearest X. Only set for series with them such as candlesticks.
+    pub fn nearest_data_extra(&self, pos_x: f64) -> HashMap<usize, Vec<Y>> {
+        self.nearest_index(pos_x)
+            .map(|index| self.data_extra[index].clone())
+            .unwrap_or_default()
+    }
+
     /// Given an arbitrary (unaligned to data) X position, find the nearest X position aligned to data. Returns `f64::NAN` if no data. Note a position covers a range dependent on the chart width.
     pub fn nearest_position_x(&self, pos_x: f64) -> Option<f64> {
         self.nearest_index(pos_x).map(|index| self.x_to_data[index])
@@ -124,11 +303,46 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
     pub fn series_positions(&self, id: usize) -> Vec<(f64, f64)> {
         self.coords.get(&id).cloned().unwrap_or_default()
     }
+
+    /// Y positions that each stacked value is drawn from. Empty if the series is not stacked. Positions may be `f64::NAN` when a base is missing.
+    pub fn series_bases(&self, id: usize) -> Vec<f64> {
+        self.bases.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Y positions of the extra values of a series. Empty if the series doesn't have them.
+    pub fn series_extras(&self, id: usize) -> Vec<Vec<f64>> {
+        self.extras.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Per-point values of a series e.g., (size, colour). Empty if the series doesn't have them.
+    pub fn series_point_values(&self, id: usize) -> Vec<(f64, f64)> {
+        self.point_values.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// (start, end) X positions of each value of a series. Empty if the series doesn't have them.
+    pub fn series_spans(&self, id: usize) -> Vec<(f64, f64)> {
+        self.spans.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Whether each value of a series is a total. Empty if the series has no totals.
+    pub fn series_is_total(&self, id: usize) -> Vec<bool> {
+        self.totals.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Sums the Y positions of a series. Missing values are skipped.
+    pub fn series_total(&self, id: usize) -> f64 {
+        (self.data_y.iter())
+            .filter_map(|y_data| y_data.get(&id))
+            .map(Tick::position)
+            .filter(|y| y.is_finite())
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::series::GetYValue;
     use std::sync::Arc;
 
     #[derive(Clone, Debug, PartialEq)]
@@ -144,6 +358,8 @@ mod tests {
         }
     }
 
+    const LINEAR: (Scale, Scale, Scale) = (Scale::Linear, Scale::Linear, Scale::Linear);
+
     const DATA: &[MyData] = &[
         MyData::new(1.0, 2.0, 3.0),
         MyData::new(4.0, 5.0, 6.0),
@@ -151,11 +367,11 @@ mod tests {
     ];
 
     fn test_data(data: &[MyData]) -> Data<f64, f64> {
-        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
-        get_ys.insert(66, Arc::new(|d: &MyData| d.y1));
-        get_ys.insert(5, Arc::new(|d: &MyData| d.y2));
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Primary, Arc::new(|d: &MyData| d.y2)));
 
-        Data::new(Arc::new(|d: &MyData| d.x), get_ys, data)
+        Data::new(Arc::new(|d: &MyData| d.x), get_ys, data, LINEAR)
     }
 
     #[test]
@@ -185,6 +401,195 @@ mod tests {
         assert_eq!(data.range_x.positions(), Some((1.0, 7.0)));
         assert_eq!(data.range_y.range(), Some((&2.0, &9.0)));
         assert_eq!(data.range_y.positions(), Some((2.0, 9.0)));
+        // Totals
+        assert_eq!(data.series_total(66), 15.0);
+        assert_eq!(data.series_total(1), 0.0);
+    }
+
+    struct Stacked;
+
+    impl GetYValue<MyData, f64> for Stacked {
+        fn value(&self, d: &MyData) -> f64 {
+            d.y2
+        }
+
+        fn stacked_value(&self, d: &MyData) -> f64 {
+            d.y1 + d.y2
+        }
+
+        fn stacked_base(&self, d: &MyData) -> Option<f64> {
+            Some(d.y1)
+        }
+    }
+
+    #[test]
+    fn test_data_stacked_bases() {
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Primary, Arc::new(Stacked)));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA, LINEAR);
+        // Only stacked series have bases
+        assert_eq!(data.series_bases(5), vec![2.0, 5.0, 8.0]);
+        assert_eq!(data.series_bases(66), Vec::<f64>::new());
+        assert_eq!(data.nearest_data_base(4.0), HashMap::from([(5, 5.0)]));
+        assert_eq!(
+            data.series_positions(5),
+            vec![(1.0, 5.0), (4.0, 11.0), (7.0, 17.0)]
+        );
+        assert_eq!(data.range_y.range(), Some((&2.0, &17.0)));
+    }
+
+    struct Extra;
+
+    impl GetYValue<MyData, f64> for Extra {
+        fn value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn stacked_value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn extra_values(&self, d: &MyData) -> Vec<f64> {
+            vec![d.y2, -d.y2]
+        }
+    }
+
+    #[test]
+    fn test_data_extra_values() {
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Primary, Arc::new(Extra)));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA, LINEAR);
+        // Only series with extra values have them
+        assert_eq!(data.series_extras(5)[1], vec![6.0, -6.0]);
+        assert_eq!(data.series_extras(66), Vec::<Vec<f64>>::new());
+        assert_eq!(
+            data.nearest_data_extra(7.0),
+            HashMap::from([(5, vec![9.0, -9.0])])
+        );
+        // Range includes extra values
+        assert_eq!(data.range_y.range(), Some((&-9.0, &9.0)));
+    }
+
+    struct Spanned;
+
+    impl GetYValue<MyData, f64> for Spanned {
+        fn value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn stacked_value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn x_span(&self, d: &MyData) -> Option<(f64, f64)> {
+            Some((d.x - 1.0, d.x + 1.0))
+        }
+    }
+
+    #[test]
+    fn test_data_spans() {
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Primary, Arc::new(Spanned)));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA, LINEAR);
+        // Spans are kept apart from per-point values
+        assert_eq!(
+            data.series_spans(5),
+            vec![(0.0, 2.0), (3.0, 5.0), (6.0, 8.0)]
+        );
+        assert_eq!(data.series_spans(66), Vec::<(f64, f64)>::new());
+        assert_eq!(data.series_point_values(5), Vec::<(f64, f64)>::new());
+    }
+
+    struct Cell;
+
+    impl GetYValue<MyData, f64> for Cell {
+        fn value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn stacked_value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn point_values(&self, d: &MyData) -> Option<(f64, f64)> {
+            Some((f64::NAN, d.y2))
+        }
+    }
+
+    #[test]
+    fn test_push_sparse() {
+        let mut map = HashMap::new();
+        // Not created until the first value
+        push_sparse(&mut map, 0, 0, None, false);
+        assert!(map.is_empty());
+        push_sparse(&mut map, 0, 1, Some(true), false);
+        push_sparse(&mut map, 0, 2, None, false);
+        assert_eq!(map[&0], vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_data_cells() {
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(5, (YAxis::Primary, Arc::new(Cell)));
+        let cells = &[
+            MyData::new(1.0, 0.0, 10.0),
+            MyData::new(1.0, 1.0, 11.0),
+            MyData::new(2.0, 0.0, 20.0),
+            MyData::new(2.0, 1.0, 21.0),
+            MyData::new(2.0, 2.0, 22.0),
+        ];
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, cells, LINEAR);
+        assert_eq!(data.distinct_x(0, 4), 2);
+        assert_eq!(data.distinct_x(1, 2), 2);
+        assert_eq!(data.distinct_x(3, 4), 1);
+        assert_eq!(test_data(&[]).distinct_x(0, 0), 0);
+        assert_eq!(data.distinct_y(&[5]), 3);
+        assert_eq!(data.distinct_y(&[66]), 0);
+        // Nearest by X then Y
+        assert_eq!(
+            data.nearest_cells(&[5], 1.9, 0.8),
+            HashMap::from([(5, (1.0, 21.0))])
+        );
+        assert_eq!(
+            data.nearest_cells(&[5], 0.0, 5.0),
+            HashMap::from([(5, (1.0, 11.0))])
+        );
+        assert_eq!(data.nearest_cells(&[66], 0.0, 5.0), HashMap::new());
+    }
+
+    #[test]
+    fn test_data_log_scale() {
+        let data = [MyData::new(0.0, 0.0, 3.0), MyData::new(4.0, 5.0, -6.0)];
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Primary, Arc::new(|d: &MyData| d.y2)));
+        let scales = (Scale::Log10, Scale::Log2, Scale::Linear);
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, &data, scales);
+        // Zero and negative values are left out of the range
+        assert_eq!(data.range_x.range(), Some((&4.0, &4.0)));
+        assert_eq!(data.range_y.range(), Some((&3.0, &5.0)));
+    }
+
+    #[test]
+    fn test_data_secondary_axis() {
+        let mut get_ys = HashMap::<usize, (YAxis, GetY<_, _>)>::new();
+        get_ys.insert(66, (YAxis::Primary, Arc::new(|d: &MyData| d.y1)));
+        get_ys.insert(5, (YAxis::Secondary, Arc::new(|d: &MyData| d.y2 * 100.0)));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA, LINEAR);
+        // Each axis has its own range
+        assert_eq!(data.range_y(YAxis::Primary).range(), Some((&2.0, &8.0)));
+        assert_eq!(
+            data.range_y(YAxis::Secondary).range(),
+            Some((&300.0, &900.0))
+        );
+        // Positions are unscaled
+        assert_eq!(
+            data.series_positions(5),
+            vec![(1.0, 300.0), (4.0, 600.0), (7.0, 900.0)]
+        );
     }
 
     #[test]
@@ -208,6 +613,16 @@ mod tests {
         assert_eq!(data.nearest_index(0.5), None);
     }
 
+    #[test]
+    fn test_index_range() {
+        let data = test_data(DATA);
+        assert_eq!(data.index_range(0.0, 10.0), Some((0, 2)));
+        assert_eq!(data.index_range(1.0, 4.0), Some((0, 1)));
+        assert_eq!(data.index_range(2.0, 6.0), Some((1, 1)));
+        assert_eq!(data.index_range(5.0, 6.0), None);
+        assert_eq!(test_data(&[]).index_range(0.0, 10.0), None);
+    }
+
     #[test]
     fn test_nearest_data_x() {
         let data = test_data(DATA);
@@ -4,59 +4,119 @@ mod range;
 pub use range::Range;
 
 use crate::{
-    series::{use_y::RenderUseY, UseY},
+    bounds::Bounds,
+    series::{use_y::RenderUseY, Orientation, SeriesAcc, UseY, YAxis},
     state::State,
-    Series, Tick,
+    Scale, Series, Tick,
 };
 use data::Data;
 use leptos::prelude::*;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct UseData<X: Tick, Y: Tick> {
     data: Memo<Data<X, Y>>,
-    pub len: Memo<usize>,
+    /// Number of bands (X widths) to divide the X axis into. Usually the number of distinct X values.
+    pub x_bands: Memo<usize>,
     pub series: Memo<Vec<UseY>>,
+    /// Includes series that take up the width of an X band e.g., bars and candlesticks.
     pub includes_bars: Memo<bool>,
+    /// Number of bands (Y heights) to divide the Y axis into e.g., rows of a heatmap. Zero if no series take up the height of a Y band.
+    pub y_bands: Memo<usize>,
+    pub orientation: Orientation,
+    pub x_scale: RwSignal<Scale>,
+    pub y_scale: RwSignal<Scale>,
+    pub secondary_y_scale: RwSignal<Scale>,
+    /// Zoomed in to a window of X values. See [Zoom](crate::Zoom).
+    pub zoomed: Signal<bool>,
     pub range_x: Memo<Range<X>>,
     pub range_y: Memo<Range<Y>>,
+    /// Range of the [secondary Y axis](YAxis::Secondary). Empty if no series use it.
+    pub secondary_range_y: Memo<Range<Y>>,
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
+    /// Builds our data. The X window (if set) restricts the X range to a zoomed in view.
     pub fn new<T: Send + Sync + 'static>(
         series: Series<T, X, Y>,
         data: Signal<Vec<T>>,
+        x_window: Signal<Option<(X, X)>>,
     ) -> UseData<X, Y> {
-        let lines = series.to_use_lines();
+        let SeriesAcc {
+            lines,
+            range_y: extend_range_y,
+            ..
+        } = series.to_use_series();
+        let orientation = series.orientation;
 
         // Data values
         let data = {
             let lines = lines.clone();
             Memo::new(move |_| {
                 let get_x = series.get_x.clone();
+                let scales = (
+                    series.x_scale.get(),
+                    series.y_scale.get(),
+                    series.secondary_y_scale.get(),
+                );
                 data.with(|data| {
                     Data::new(
                         get_x,
                         lines
                             .clone()
                             .into_iter()
-                            .map(|(use_y, get_y)| (use_y.id, get_y))
+                            .map(|(use_y, get_y)| (use_y.id, (use_y.y_axis.get(), get_y)))
                             .collect(),
                         data,
+                        scales,
                     )
                 })
             })
         };
 
         // Range signals
+        let (x_scale, y_scale) = (series.x_scale, series.y_scale);
         let range_x: Memo<Range<X>> = Memo::new(move |_| {
-            data.with(|data| data.range_x())
-                .maybe_update(vec![series.min_x.get(), series.max_x.get()])
-        });
-        let range_y: Memo<Range<Y>> = Memo::new(move |_| {
-            data.with(|data| data.range_y())
-                .maybe_update(vec![series.min_y.get(), series.max_y.get()])
+            // Zoomed in: only show the window
+            if let Some((first, last)) = x_window.get() {
+                return Range::default().maybe_update(vec![Some(first), Some(last)]);
+            }
+            let scale = x_scale.get();
+            data.with(|data| data.range_x()).maybe_update(
+                [series.min_x.get(), series.max_x.get()]
+                    .into_iter()
+                    .map(|x| x.filter(|x| scale.contains(x.position())))
+                    .collect(),
+            )
         });
+        let mk_range_y = move |axis: YAxis,
+                               scale: RwSignal<Scale>,
+                               min_y: RwSignal<Option<Y>>,
+                               max_y: RwSignal<Option<Y>>| {
+            let extend_range_y = extend_range_y.clone();
+            Memo::new(move |_| {
+                let scale = scale.get();
+                let extend = (extend_range_y.iter())
+                    .filter(|(y_axis, _)| y_axis.get() == axis)
+                    .map(|(_, y)| y());
+                data.with(|data| data.range_y(axis)).maybe_update(
+                    [min_y.get(), max_y.get()]
+                        .into_iter()
+                        .chain(extend)
+                        .map(|y| y.filter(|y| scale.contains(y.position())))
+                        .collect(),
+                )
+            })
+        };
+        let secondary_y_scale = series.secondary_y_scale;
+        let range_y = mk_range_y(YAxis::Primary, y_scale, series.min_y, series.max_y);
+        let secondary_range_y = mk_range_y(
+            YAxis::Secondary,
+            secondary_y_scale,
+            series.min_secondary_y,
+            series.max_secondary_y,
+        );
 
         // Sort series by name
         let series = {
@@ -68,20 +128,152 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
             })
         };
         let includes_bars =
-            Memo::new(move |_| series.get().iter().any(|use_y| use_y.bar().is_some()));
+            Memo::new(move |_| series.get().iter().any(|use_y| use_y.takes_x_width()));
+
+        // Rows of heatmap cells
+        let y_bands = Memo::new(move |_| {
+            let ids = (series.read().iter())
+                .filter(|use_y| use_y.is_heatmap())
+                .map(|use_y| use_y.id)
+                .collect::<Vec<_>>();
+            if ids.is_empty() {
+                return 0;
+            }
+            (range_y.read().range())
+                .and_then(|(first, last)| Y::band_count(first, last))
+                .unwrap_or_else(|| data.with(|data| data.distinct_y(&ids)))
+        });
+
+        // Number of X values shown. Data can share an X value e.g., cells of a heatmap
+        let len = Memo::new(move |_| {
+            data.with(|data| {
+                let window = match x_window.get() {
+                    Some((first, last)) => data.index_range(first.position(), last.position()),
+                    None => data.len().checked_sub(1).map(|last| (0, last)),
+                };
+                window.map_or(0, |(first, last)| data.distinct_x(first, last))
+            })
+        });
+        let zoomed = Signal::derive(move || x_window.with(Option::is_some));
+        let x_bands = Memo::new(move |_| {
+            range_x
+                .read()
+                .range()
+                .and_then(|(first, last)| X::band_count(first, last))
+                .unwrap_or_else(|| len.get())
+        });
 
         UseData {
             data,
-            len: Memo::new(move |_| data.with(|data| data.len())),
+            x_bands,
             series,
             includes_bars,
+            y_bands,
+            orientation,
+            x_scale,
+            y_scale,
+            secondary_y_scale,
+            zoomed,
             range_x,
             range_y,
+            secondary_range_y,
         }
     }
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
+    /// Gets the range of the given Y axis.
+    pub fn range_y(&self, axis: YAxis) -> Memo<Range<Y>> {
+        match axis {
+            YAxis::Primary => self.range_y,
+            YAxis::Secondary => self.secondary_range_y,
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseData<X, Y> {
+    /// Gets the number of data points. Untracked.
+    pub fn len_untracked(&self) -> usize {
+        self.data.with_untracked(|data| data.len())
+    }
+
+    /// Finds the indexes of the first and last data inside the X positions (inclusive). Untracked.
+    pub fn index_range_untracked(&self, min: f64, max: f64) -> Option<(usize, usize)> {
+        self.data.with_untracked(|data| data.index_range(min, max))
+    }
+
+    /// Finds the index of the nearest data to the X position. Untracked.
+    pub fn nearest_index_untracked(&self, pos_x: f64) -> Option<usize> {
+        self.data.with_untracked(|data| data.nearest_index(pos_x))
+    }
+
+    /// Finds the window of data indexes inside an X range. `None` covers all data. Untracked.
+    pub fn window_untracked(&self, range: Option<(X, X)>) -> Option<(usize, usize)> {
+        match range {
+            Some((first, last)) => self.index_range_untracked(first.position(), last.position()),
+            None => self.len_untracked().checked_sub(1).map(|last| (0, last)),
+        }
+    }
+
+    /// Gets the X range of a window of data indexes. Returns `None` when the window covers all data. Untracked.
+    pub fn window_range_untracked(&self, (first, last): (usize, usize)) -> Option<(X, X)> {
+        self.data.with_untracked(|data| {
+            if first == 0 && last + 1 >= data.len() {
+                return None;
+            }
+            Some((data.data_x(first)?, data.data_x(last)?))
+        })
+    }
+
+    /// Positions of each (X, Y) of a series.
+    pub fn series_positions(&self, id: usize) -> Signal<Vec<(f64, f64)>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_positions(id)))
+    }
+
+    /// Y positions that each value of a series is drawn from e.g., the value beneath in a stack. Empty if the series is not stacked.
+    pub fn series_bases(&self, id: usize) -> Signal<Vec<f64>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_bases(id)))
+    }
+
+    /// Per-point (size, colour) values of a series. Empty if the series doesn't have them.
+    pub fn series_point_values(&self, id: usize) -> Signal<Vec<(f64, f64)>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_point_values(id)))
+    }
+
+    /// (start, end) X positions of each value of a series e.g., a histogram bin. Empty if the series doesn't have them.
+    pub fn series_spans(&self, id: usize) -> Signal<Vec<(f64, f64)>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_spans(id)))
+    }
+
+    /// Whether each value of a series is a total e.g., a waterfall total. Empty if the series has no totals.
+    pub fn series_is_total(&self, id: usize) -> Signal<Vec<bool>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_is_total(id)))
+    }
+
+    /// Y positions of the extra values of a series. Empty if the series doesn't have them.
+    pub fn series_extras(&self, id: usize) -> Signal<Vec<Vec<f64>>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_extras(id)))
+    }
+
+    /// Sum of each series' Y values e.g., the size of a pie slice.
+    pub fn series_totals(&self) -> Memo<HashMap<usize, f64>> {
+        let series = self.series;
+        let data = self.data;
+        Memo::new(move |_| {
+            data.with(|data| {
+                (series.read().iter())
+                    .map(|use_y| (use_y.id, data.series_total(use_y.id)))
+                    .collect()
+            })
+        })
+    }
+
     pub fn nearest_data_x(&self, pos_x: Memo<f64>) -> Memo<Option<X>> {
         let data = self.data;
         Memo::new(move |_| data.with(|data| data.nearest_data_x(pos_x.get())))
@@ -92,6 +284,35 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
         Memo::new(move |_| data.with(|data| data.nearest_position_x(pos_x.get())))
     }
 
+    /// Values that each Y is drawn from at the nearest X e.g., the lower value of a [Band](crate::Band).
+    pub fn nearest_data_base(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Y>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.nearest_data_base(pos_x.get())))
+    }
+
+    /// Extra values of each Y at the nearest X e.g., the high and low of a [Candlestick](crate::Candlestick).
+    pub fn nearest_data_extra(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Vec<Y>>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.nearest_data_extra(pos_x.get())))
+    }
+
+    /// Finds the heatmap cells nearest to the (X, Y) position. Returns the Y value and cell value of each heatmap.
+    pub fn nearest_cells(
+        &self,
+        pos_x: Memo<f64>,
+        pos_y: Memo<f64>,
+    ) -> Memo<HashMap<usize, (Y, f64)>> {
+        let series = self.series;
+        let data = self.data;
+        Memo::new(move |_| {
+            let ids = (series.read().iter())
+                .filter(|use_y| use_y.is_heatmap())
+                .map(|use_y| use_y.id)
+                .collect::<Vec<_>>();
+            data.with(|data| data.nearest_cells(&ids, pos_x.get(), pos_y.get()))
+        })
+    }
+
     // TODO: this can never be None
     pub fn nearest_data_y(&self, pos_x: Memo<f64>) -> Memo<Vec<(UseY, Option<Y>)>> {
         let series = self.series;
@@ -113,9 +334,10 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
 #[component]
 pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
     let data = state.pre.data.clone();
-    let mk_svg_coords = move |id| {
+    let mk_svg_coords = move |id, state: &State<X, Y>| {
+        let projection = state.projection;
         Signal::derive(move || {
-            let proj = state.projection.get();
+            let proj = projection.get();
             data.data.with(|data| {
                 data.series_positions(id)
                     .into_iter()
@@ -125,14 +347,64 @@ pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
         })
     };
 
+    let mk_svg_bases = move |id, state: &State<X, Y>| {
+        let projection = state.projection;
+        Signal::derive(move || {
+            let proj = projection.get();
+            data.data.with(|data| {
+                data.series_bases(id)
+                    .into_iter()
+                    .map(|y| proj.y_to_svg(y))
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    // Hide data outside the X axis when zoomed in. A nested SVG clips without needing a unique clip path ID.
+    let zoomed = data.zoomed;
+    let (inner, outer) = (state.layout.inner, state.layout.outer);
+    let viewport = Memo::new(move |_| {
+        let (inner, outer) = (inner.get(), outer.get());
+        match data.orientation {
+            Orientation::Vertical => Bounds::from_points(
+                inner.left_x(),
+                outer.top_y(),
+                inner.right_x(),
+                outer.bottom_y(),
+            ),
+            Orientation::Horizontal => Bounds::from_points(
+                outer.left_x(),
+                inner.top_y(),
+                outer.right_x(),
+                inner.bottom_y(),
+            ),
+        }
+    });
+    let overflow = move || if zoomed.get() { "hidden" } else { "visible" };
+
     view! {
-        <g class="_chartistry_series">
-            <For
-                each=move || data.series.get()
-                key=|use_y| use_y.id
-                let:use_y>
-                <RenderUseY use_y=use_y.clone() state=state.clone() positions=mk_svg_coords(use_y.id) />
-            </For>
-        </g>
+        <svg
+            x=move || viewport.get().left_x()
+            y=move || viewport.get().top_y()
+            width=move || viewport.get().width()
+            height=move || viewport.get().height()
+            viewBox=move || viewport.with(|v| format!("{} {} {} {}", v.left_x(), v.top_y(), v.width(), v.height()))
+            overflow=overflow>
+            <g class="_chartistry_series">
+                <For
+                    each=move || data.series.get()
+                    key=|use_y| use_y.id
+                    children=move |use_y| {
+                        // Draw against the series' Y axis
+                        let state = state.on_y_axis(use_y.y_axis);
+                        let positions = mk_svg_coords(use_y.id, &state);
+                        let bases = mk_svg_bases(use_y.id, &state);
+                        view! {
+                            <RenderUseY use_y=use_y state=state positions=positions bases=bases />
+                        }
+                    }
+                />
+            </g>
+        </svg>
     }
 }
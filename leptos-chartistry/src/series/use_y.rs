@@ -1,33 +1,119 @@
 use super::{
-    bar::{RenderBar, UseBar},
+    band::{BandTaster, RenderBand, UseBand},
+    bar::{BarTaster, RenderBar, UseBar},
+    box_plot::{BoxPlotTaster, RenderBoxPlot, UseBoxPlot},
+    candlestick::{CandlestickTaster, RenderCandlestick, UseCandlestick},
+    error_bars::error_coords,
+    heatmap::{HeatmapRange, HeatmapTaster, RenderHeatmap, UseHeatmap},
     line::{RenderLine, UseLine},
+    scatter::{RenderScatter, ScatterTaster, UseScatter},
+    YAxis,
 };
-use crate::{bounds::Bounds, debug::DebugRect, state::State, Tick};
-use leptos::{either::Either, prelude::*};
+use crate::{bounds::Bounds, debug::DebugRect, state::State, Colour, Tick};
+use leptos::{either::EitherOf7, prelude::*};
 
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct UseY {
     pub id: usize,
     pub name: RwSignal<String>,
+    pub y_axis: RwSignal<YAxis>,
     desc: UseYDesc,
 }
 
 #[derive(Clone, Debug, PartialEq)]
-enum UseYDesc {
+pub(super) enum UseYDesc {
     Line(UseLine),
     Bar(UseBar),
+    Band(UseBand),
+    Scatter(UseScatter),
+    Candlestick(UseCandlestick),
+    BoxPlot(UseBoxPlot),
+    Heatmap(UseHeatmap),
+}
+
+impl From<UseLine> for UseYDesc {
+    fn from(desc: UseLine) -> Self {
+        Self::Line(desc)
+    }
+}
+
+impl From<UseBar> for UseYDesc {
+    fn from(desc: UseBar) -> Self {
+        Self::Bar(desc)
+    }
+}
+
+impl From<UseBand> for UseYDesc {
+    fn from(desc: UseBand) -> Self {
+        Self::Band(desc)
+    }
+}
+
+impl From<UseScatter> for UseYDesc {
+    fn from(desc: UseScatter) -> Self {
+        Self::Scatter(desc)
+    }
+}
+
+impl From<UseCandlestick> for UseYDesc {
+    fn from(desc: UseCandlestick) -> Self {
+        Self::Candlestick(desc)
+    }
+}
+
+impl From<UseBoxPlot> for UseYDesc {
+    fn from(desc: UseBoxPlot) -> Self {
+        Self::BoxPlot(desc)
+    }
+}
+
+impl From<UseHeatmap> for UseYDesc {
+    fn from(desc: UseHeatmap) -> Self {
+        Self::Heatmap(desc)
+    }
 }
 
 impl UseY {
-    pub(super) fn new_line(id: usize, name: RwSignal<String>, line: UseLine) -> Self {
-        let desc = UseYDesc::Line(line);
-        Self { id, name, desc }
+    pub(super) fn new(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        desc: impl Into<UseYDesc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            y_axis,
+            desc: desc.into(),
+        }
+    }
+
+    /// Returns true if the series is a [Band](crate::Band) with a lower and upper value.
+    pub(crate) fn is_band(&self) -> bool {
+        matches!(self.desc, UseYDesc::Band(_))
+    }
+
+    /// Returns true if the series is a [Candlestick](crate::Candlestick) with open, high, low, and close values.
+    pub(crate) fn is_candlestick(&self) -> bool {
+        matches!(self.desc, UseYDesc::Candlestick(_))
+    }
+
+    /// Returns true if the series is a [BoxPlot](crate::BoxPlot) with a five number summary.
+    pub(crate) fn is_box_plot(&self) -> bool {
+        matches!(self.desc, UseYDesc::BoxPlot(_))
     }
 
-    pub(super) fn new_bar(id: usize, name: RwSignal<String>, bar: UseBar) -> Self {
-        let desc = UseYDesc::Bar(bar);
-        Self { id, name, desc }
+    /// Returns true if the series is a [Heatmap](crate::Heatmap) of cells.
+    pub(crate) fn is_heatmap(&self) -> bool {
+        matches!(self.desc, UseYDesc::Heatmap(_))
+    }
+
+    pub(crate) fn line(&self) -> Option<&UseLine> {
+        match &self.desc {
+            UseYDesc::Line(line) => Some(line),
+            _ => None,
+        }
     }
 
     pub(crate) fn bar(&self) -> Option<&UseBar> {
@@ -37,6 +123,43 @@ impl UseY {
         }
     }
 
+    pub(crate) fn heatmap(&self) -> Option<&UseHeatmap> {
+        match &self.desc {
+            UseYDesc::Heatmap(heatmap) => Some(heatmap),
+            _ => None,
+        }
+    }
+
+    /// Colour of series drawn in a single colour e.g., lines and bars.
+    pub(crate) fn colour(&self) -> Option<Signal<Colour>> {
+        match &self.desc {
+            UseYDesc::Line(line) => Some(line.colour),
+            UseYDesc::Bar(bar) => Some(bar.colour),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the series takes up the width of an X band e.g., bars.
+    pub(crate) fn takes_x_width(&self) -> bool {
+        matches!(
+            self.desc,
+            UseYDesc::Bar(_)
+                | UseYDesc::Candlestick(_)
+                | UseYDesc::BoxPlot(_)
+                | UseYDesc::Heatmap(_)
+        )
+    }
+
+    /// Group (X position) of series that are drawn side by side e.g., bars.
+    pub(crate) fn group_id(&self) -> Option<usize> {
+        match &self.desc {
+            UseYDesc::Bar(bar) => Some(bar.group_id),
+            UseYDesc::BoxPlot(box_plot) => Some(box_plot.group_id),
+            UseYDesc::Candlestick(candlestick) => Some(candlestick.group_id),
+            _ => None,
+        }
+    }
+
     fn taster_bounds(font_height: Memo<f64>, font_width: Memo<f64>) -> Memo<Bounds> {
         Memo::new(move |_| Bounds::new(font_width.get() * 2.5, font_height.get()))
     }
@@ -52,19 +175,65 @@ pub(super) fn RenderUseY<X: Tick, Y: Tick>(
     use_y: UseY,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
+    bases: Signal<Vec<f64>>,
 ) -> impl IntoView {
     let desc = use_y.desc.clone();
+    // Error bars in SVG coords along the Y axis
+    let errors = {
+        let extras = state.pre.data.series_extras(use_y.id);
+        let proj = state.projection;
+        Signal::derive(move || {
+            let proj = proj.get();
+            extras.with(|extras| error_coords(extras, |y| proj.y_to_svg(y)))
+        })
+    };
     match desc {
-        UseYDesc::Line(line) => Either::Left(view! {
-            <RenderLine
+        UseYDesc::Line(line) => {
+            let bases = line.area_bases(&state, positions, bases);
+            EitherOf7::A(view! {
+                <RenderLine
+                    use_y=use_y
+                    line=line
+                    data=state.pre.data
+                    positions=positions
+                    markers=positions
+                    bases=bases
+                    errors=errors />
+            })
+        }
+        UseYDesc::Bar(bar) => {
+            let spans = state.pre.data.series_spans(use_y.id);
+            let totals = state.pre.data.series_is_total(use_y.id);
+            EitherOf7::B(view! {
+                <RenderBar
+                    bar=bar
+                    state=state
+                    positions=positions
+                    bases=bases
+                    errors=errors
+                    spans=spans
+                    totals=totals />
+            })
+        }
+        UseYDesc::Band(band) => EitherOf7::C(view! {
+            <RenderBand band=band state=state positions=positions bases=bases />
+        }),
+        UseYDesc::Scatter(scatter) => EitherOf7::D(view! {
+            <RenderScatter use_y=use_y scatter=scatter state=state positions=positions />
+        }),
+        UseYDesc::Candlestick(candlestick) => EitherOf7::E(view! {
+            <RenderCandlestick
                 use_y=use_y
-                line=line
-                data=state.pre.data
+                candlestick=candlestick
+                state=state
                 positions=positions
-                markers=positions />
+                bases=bases />
+        }),
+        UseYDesc::BoxPlot(box_plot) => EitherOf7::F(view! {
+            <RenderBoxPlot use_y=use_y box_plot=box_plot state=state positions=positions />
         }),
-        UseYDesc::Bar(bar) => Either::Right(view! {
-            <RenderBar bar=bar state=state positions=positions />
+        UseYDesc::Heatmap(heatmap) => EitherOf7::G(view! {
+            <RenderHeatmap use_y=use_y heatmap=heatmap state=state positions=positions />
         }),
     }
 }
@@ -73,11 +242,16 @@ pub(super) fn RenderUseY<X: Tick, Y: Tick>(
 pub fn Snippet<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
     let debug = state.pre.debug;
     let name = series.name;
+    // Heatmaps show their range of values next to their colour bar
+    let range = series.heatmap().cloned().map(|heatmap| {
+        view! { <HeatmapRange use_y=series.clone() heatmap=heatmap state=state.clone() /> }
+    });
     view! {
         <div class="_chartistry_snippet" style="white-space: nowrap;">
             <DebugRect label="snippet" debug=debug />
             <Taster series=series state=state />
             {name}
+            {range}
         </div>
     }
 }
@@ -103,17 +277,46 @@ fn Taster<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
                 let bounds = bounds.get();
                 vec![(bounds.centre_x(), bounds.centre_y() + Y_OFFSET)]
             });
-            Either::Left(view! {
+            // Fill down to the bottom
+            let bases = Signal::derive(move || {
+                let bounds = bounds.get();
+                vec![
+                    (bounds.left_x(), bounds.bottom_y()),
+                    (bounds.right_x(), bounds.bottom_y()),
+                ]
+            });
+            EitherOf7::A(view! {
                 <RenderLine
                     use_y=series.clone()
                     line=line.clone()
                     data=state.pre.data
                     positions=positions
-                    markers=markers />
+                    markers=markers
+                    bases=bases
+                    errors=Vec::new() />
             })
         }
-        UseYDesc::Bar(bar) => Either::Right(view! {
-            <RenderBar bar=bar.clone() state=state positions=positions />
+        UseYDesc::Bar(bar) => EitherOf7::B(view! {
+            <BarTaster bar=bar.clone() bounds=bounds />
+        }),
+        UseYDesc::Band(band) => EitherOf7::C(view! {
+            <BandTaster band=band.clone() bounds=bounds />
+        }),
+        UseYDesc::Scatter(scatter) => EitherOf7::D(view! {
+            <ScatterTaster scatter=scatter.clone() bounds=bounds />
+        }),
+        UseYDesc::Candlestick(candlestick) => EitherOf7::E(view! {
+            <CandlestickTaster candlestick=candlestick.clone() bounds=bounds />
+        }),
+        UseYDesc::BoxPlot(box_plot) => EitherOf7::F(view! {
+            <BoxPlotTaster box_plot=box_plot.clone() bounds=bounds />
+        }),
+        UseYDesc::Heatmap(heatmap) => EitherOf7::G(view! {
+            <HeatmapTaster
+                use_y=series.clone()
+                heatmap=heatmap.clone()
+                state=state.clone()
+                bounds=bounds />
         }),
     };
 
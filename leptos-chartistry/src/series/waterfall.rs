@@ -0,0 +1,160 @@
+use super::{Bar, GetYValue};
+use crate::{Colour, Tick};
+use std::sync::Arc;
+
+/// Default colour of increases in a waterfall.
+pub const WATERFALL_INCREASE_COLOUR: Colour = Colour::from_rgb(0x22, 0xC5, 0x5E);
+/// Default colour of totals in a waterfall.
+pub const WATERFALL_TOTAL_COLOUR: Colour = Colour::from_rgb(0x64, 0x74, 0x8B);
+
+/// A change in a waterfall. See [WaterfallStep::accumulate].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WaterfallChange {
+    /// Adds to (or subtracts from) the running total.
+    Delta(f64),
+    /// Shows the running total as a bar from the baseline.
+    Total,
+}
+
+/// A bar of a waterfall chart. Change bars start where the previous running total ended. Total bars reset to the baseline.
+///
+/// # Example
+/// Steps are used as chart data and drawn with [Bar::waterfall]:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let labels = Categories::new(["Revenue", "Costs", "Gross", "Tax", "Net"]);
+/// let data = WaterfallStep::accumulate([
+///     (labels.category("Revenue"), WaterfallChange::Delta(420.0)),
+///     (labels.category("Costs"), WaterfallChange::Delta(-180.0)),
+///     (labels.category("Gross"), WaterfallChange::Total),
+///     (labels.category("Tax"), WaterfallChange::Delta(-40.0)),
+///     (labels.category("Net"), WaterfallChange::Total),
+/// ]);
+/// let series = Series::new(|step: &WaterfallStep<Category>| step.x.clone())
+///     .bar(Bar::waterfall().with_name("budget"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct WaterfallStep<X> {
+    /// X value of the step.
+    pub x: X,
+    /// Running total before the step. Zero for totals.
+    pub start: f64,
+    /// Running total after the step.
+    pub end: f64,
+    /// Whether the step is a total.
+    pub total: bool,
+}
+
+impl<X> WaterfallStep<X> {
+    /// Accumulates changes into steps. Non-finite deltas are treated as zero.
+    pub fn accumulate(changes: impl IntoIterator<Item = (X, WaterfallChange)>) -> Vec<Self> {
+        let mut running = 0.0;
+        (changes.into_iter())
+            .map(|(x, change)| match change {
+                WaterfallChange::Delta(delta) => {
+                    let start = running;
+                    if delta.is_finite() {
+                        running += delta;
+                    }
+                    Self {
+                        x,
+                        start,
+                        end: running,
+                        total: false,
+                    }
+                }
+                WaterfallChange::Total => Self {
+                    x,
+                    start: 0.0,
+                    end: running,
+                    total: true,
+                },
+            })
+            .collect()
+    }
+
+    /// Value of the step: the change for deltas or the running total for totals.
+    pub fn value(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+impl<X: Tick> Bar<WaterfallStep<X>, f64> {
+    /// Create a new bar that draws [WaterfallStep]s. Increases use [WATERFALL_INCREASE_COLOUR], decreases use the bar's negative colour, and totals use [WATERFALL_TOTAL_COLOUR]. Change these with [with_colour](Self::with_colour), [with_negative_colour](Self::with_negative_colour), and [with_total_colour](Self::with_total_colour).
+    ///
+    /// See [WaterfallStep] for an example.
+    pub fn waterfall() -> Self {
+        Bar::from_get_y(Arc::new(GetStep))
+            .with_colour(WATERFALL_INCREASE_COLOUR)
+            .with_total_colour(WATERFALL_TOTAL_COLOUR)
+    }
+}
+
+/// Draws steps from their start. Totals are drawn from the bar's placement.
+struct GetStep;
+
+impl<X> GetYValue<WaterfallStep<X>, f64> for GetStep {
+    fn value(&self, step: &WaterfallStep<X>) -> f64 {
+        step.value()
+    }
+
+    fn stacked_value(&self, step: &WaterfallStep<X>) -> f64 {
+        step.end
+    }
+
+    fn stacked_base(&self, step: &WaterfallStep<X>) -> Option<f64> {
+        (!step.total).then_some(step.start)
+    }
+
+    fn is_total(&self, step: &WaterfallStep<X>) -> bool {
+        step.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulate() {
+        let steps = WaterfallStep::accumulate([
+            (1, WaterfallChange::Delta(10.0)),
+            (2, WaterfallChange::Delta(-4.0)),
+            (3, WaterfallChange::Total),
+            (4, WaterfallChange::Delta(f64::NAN)),
+            (5, WaterfallChange::Delta(-8.0)),
+            (6, WaterfallChange::Total),
+        ]);
+        let spans = (steps.iter())
+            .map(|step| (step.start, step.end, step.total))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![
+                (0.0, 10.0, false),
+                (10.0, 6.0, false),
+                (0.0, 6.0, true),
+                (6.0, 6.0, false),
+                (6.0, -2.0, false),
+                (0.0, -2.0, true),
+            ]
+        );
+        assert_eq!(steps[1].value(), -4.0);
+        assert_eq!(steps[5].value(), -2.0);
+    }
+
+    #[test]
+    fn test_get_step() {
+        let steps = WaterfallStep::accumulate([
+            (1, WaterfallChange::Delta(f64::NAN)),
+            (2, WaterfallChange::Total),
+        ]);
+        // Totals are flagged rather than inferred from a missing base
+        assert_eq!(GetStep.stacked_base(&steps[0]), Some(0.0));
+        assert!(!GetStep.is_total(&steps[0]));
+        assert_eq!(GetStep.stacked_base(&steps[1]), None);
+        assert!(GetStep.is_total(&steps[1]));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    bounds::Bounds,
+    projection::Projection,
+    series::{SparklinePath, UseData},
+    Colour, Series, Tick, YAxis,
+};
+use leptos::prelude::*;
+
+/// Default colour of the minimum point marker on a sparkline.
+pub const SPARKLINE_MIN_COLOUR: Colour = Colour::from_rgb(0xEF, 0x44, 0x44);
+/// Default colour of the maximum point marker on a sparkline.
+pub const SPARKLINE_MAX_COLOUR: Colour = Colour::from_rgb(0x22, 0xC5, 0x5E);
+/// Default diameter of sparkline markers in pixels.
+pub const SPARKLINE_MARKER_SIZE: f64 = 4.0;
+
+/// Which points of a [Sparkline] to mark. Markers are drawn on each line.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let markers = SparklineMarkers::default().with_min(true).with_max(true);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct SparklineMarkers {
+    /// Mark the lowest point. Default is false.
+    pub min: RwSignal<bool>,
+    /// Mark the highest point. Default is false.
+    pub max: RwSignal<bool>,
+    /// Mark the last point. Default is false.
+    pub last: RwSignal<bool>,
+    /// Diameter of the markers in pixels. Default is [SPARKLINE_MARKER_SIZE].
+    pub size: RwSignal<f64>,
+    /// Colour of the lowest point. Default is [SPARKLINE_MIN_COLOUR].
+    pub min_colour: RwSignal<Colour>,
+    /// Colour of the highest point. Default is [SPARKLINE_MAX_COLOUR].
+    pub max_colour: RwSignal<Colour>,
+}
+
+impl SparklineMarkers {
+    /// Marks the lowest, highest, and last points.
+    pub fn all() -> Self {
+        Self::default()
+            .with_min(true)
+            .with_max(true)
+            .with_last(true)
+    }
+
+    /// Sets whether to mark the lowest point.
+    pub fn with_min(self, min: bool) -> Self {
+        self.min.set(min);
+        self
+    }
+
+    /// Sets whether to mark the highest point.
+    pub fn with_max(self, max: bool) -> Self {
+        self.max.set(max);
+        self
+    }
+
+    /// Sets whether to mark the last point. Drawn in the line's colour.
+    pub fn with_last(self, last: bool) -> Self {
+        self.last.set(last);
+        self
+    }
+
+    /// Sets the diameter of the markers in pixels.
+    pub fn with_size(self, size: f64) -> Self {
+        self.size.set(size);
+        self
+    }
+
+    /// Sets the colours of the lowest and highest points.
+    pub fn with_colours(self, min: impl Into<Colour>, max: impl Into<Colour>) -> Self {
+        self.min_colour.set(min.into());
+        self.max_colour.set(max.into());
+        self
+    }
+}
+
+impl Default for SparklineMarkers {
+    fn default() -> Self {
+        Self {
+            min: RwSignal::new(false),
+            max: RwSignal::new(false),
+            last: RwSignal::new(false),
+            size: RwSignal::new(SPARKLINE_MARKER_SIZE),
+            min_colour: RwSignal::new(SPARKLINE_MIN_COLOUR),
+            max_colour: RwSignal::new(SPARKLINE_MAX_COLOUR),
+        }
+    }
+}
+
+/// Finds the indexes of the lowest, highest, and last (X, Y) positions. Missing points are skipped. The first of equal points is used.
+fn extremes(positions: &[(f64, f64)]) -> Option<(usize, usize, usize)> {
+    let mut points =
+        (positions.iter().enumerate()).filter(|(_, (x, y))| !(x.is_nan() || y.is_nan()));
+    let (first, &(_, y)) = points.next()?;
+    let ((min, _), (max, _), last) = points.fold(
+        ((first, y), (first, y), first),
+        |(min, max, _), (index, &(_, y))| {
+            let min = if y < min.1 { (index, y) } else { min };
+            let max = if y > max.1 { (index, y) } else { max };
+            (min, max, index)
+        },
+    );
+    Some((min, max, last))
+}
+
+/// Renders a small, fixed size line chart e.g., a trend inside a table cell.
+///
+/// Sparklines are lightweight: there's no resize observer, mouse tracking, or edge layout. They draw immediately at the given pixel size. Only the [lines](crate::Line) of the [Series] are drawn using their colour, width, and [interpolation](crate::Interpolation). Series ranges (e.g., [Series::with_min_y]) and scales apply as they do on a [Chart](crate::Chart).
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_chartistry::*;
+///
+/// # struct Price { day: f64, close: f64 }
+/// # #[component]
+/// # fn SparklineComponent() -> impl IntoView {
+/// let prices: Signal<Vec<Price>> = Signal::default();
+/// view! {
+///     <td>
+///         <Sparkline
+///             width=120.0
+///             height=24.0
+///             markers=SparklineMarkers::all()
+///             series=Series::new(|p: &Price| p.day).line(|p: &Price| p.close)
+///             data=prices
+///         />
+///     </td>
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Sparkline<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+    /// Width of the sparkline in pixels.
+    #[prop(into)]
+    width: Signal<f64>,
+    /// Height of the sparkline in pixels.
+    #[prop(into)]
+    height: Signal<f64>,
+    /// Points to mark on each line. Default is none.
+    #[prop(into, optional)]
+    markers: SparklineMarkers,
+    /// Series to render. Only lines are drawn. See [Series] for details.
+    #[prop(into)]
+    series: Series<T, X, Y>,
+    /// Data to render. Must be sorted.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let data = UseData::new(series, data, Signal::default());
+
+    // Keep markers inside the SVG
+    let size = markers.size;
+    let inner = Memo::new(move |_| {
+        let pad = size.get().max(0.0) / 2.0;
+        Bounds::new(width.get(), height.get()).shrink(pad, pad, pad, pad)
+    });
+
+    let lines = move || {
+        let data = data.clone();
+        (data.series.get().into_iter())
+            .filter_map(|use_y| {
+                let line = use_y.line()?.clone();
+                let colour = use_y.colour()?;
+                let (range_x, x_scale) = (data.range_x, data.x_scale);
+                let (range_y, y_scale) = match use_y.y_axis.get() {
+                    YAxis::Primary => (data.range_y, data.y_scale),
+                    YAxis::Secondary => (data.secondary_range_y, data.secondary_y_scale),
+                };
+                let proj = Memo::new(move |_| {
+                    let range_x = (range_x.read().positions(), x_scale.get());
+                    let range_y = (range_y.read().positions(), y_scale.get());
+                    Projection::scaled(inner.get(), false, range_x, range_y)
+                });
+                let data_positions = data.series_positions(use_y.id);
+                let positions = Signal::derive(move || {
+                    let proj = proj.get();
+                    data_positions.with(|positions| {
+                        (positions.iter())
+                            .map(|&(x, y)| proj.position_to_svg(x, y))
+                            .collect::<Vec<_>>()
+                    })
+                });
+                let markers = move || {
+                    // Find extremes in data space: SVG's Y axis is inverted
+                    let (min, max, last) = data_positions.with(|positions| extremes(positions))?;
+                    let positions = positions.get();
+                    let marks = [
+                        (markers.min.get(), min, markers.min_colour.get()),
+                        (markers.max.get(), max, markers.max_colour.get()),
+                        (markers.last.get(), last, colour.get()),
+                    ];
+                    let view = (marks.into_iter())
+                        .filter(|&(show, _, _)| show)
+                        .map(|(_, index, colour)| {
+                            let (x, y) = positions[index];
+                            view! {
+                                <circle
+                                    cx=x
+                                    cy=y
+                                    r=size.get() / 2.0
+                                    fill=colour.to_string() />
+                            }
+                        })
+                        .collect_view();
+                    Some(view)
+                };
+                Some(view! {
+                    <SparklinePath line=line positions=positions />
+                    <g class="_chartistry_sparkline_markers">{markers}</g>
+                })
+            })
+            .collect_view()
+    };
+
+    view! {
+        <svg
+            class="_chartistry_sparkline"
+            width=move || format!("{}px", width.get())
+            height=move || format!("{}px", height.get())
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            style="display: inline-block; overflow: visible; vertical-align: middle;">
+            {lines}
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extremes() {
+        let nan = f64::NAN;
+        let positions = [
+            (0.0, 5.0),
+            (1.0, 2.0),
+            (2.0, nan),
+            (3.0, 9.0),
+            (4.0, 2.0),
+            (5.0, 4.0),
+            (nan, 1.0),
+        ];
+        assert_eq!(extremes(&positions), Some((1, 3, 5)));
+        assert_eq!(extremes(&positions[..1]), Some((0, 0, 0)));
+        assert_eq!(extremes(&[]), None);
+        assert_eq!(extremes(&[(0.0, nan)]), None);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     layout::Layout, projection::Projection, series::UseData, use_watched_node::UseWatchedNode,
-    Padding, Tick,
+    Padding, Tick, YAxis,
 };
 use leptos::prelude::*;
 
@@ -20,6 +20,8 @@ pub struct State<X: Tick, Y: Tick> {
     pub pre: PreState<X, Y>,
     pub layout: Layout,
     pub projection: Memo<Projection>,
+    /// Projection of the secondary Y axis. Shares the X axis with `projection`.
+    pub secondary_projection: Memo<Projection>,
 
     pub svg_zero: Memo<(f64, f64)>,
 
@@ -31,6 +33,8 @@ pub struct State<X: Tick, Y: Tick> {
     pub hover_inner: Signal<bool>,
     /// X mouse coord in data position space
     pub hover_position_x: Memo<f64>,
+    /// Y mouse coord in data position space
+    pub hover_position_y: Memo<f64>,
 }
 
 impl<X: Tick, Y: Tick> PreState<X, Y> {
@@ -57,6 +61,7 @@ impl<X: Tick, Y: Tick> State<X, Y> {
         node: &UseWatchedNode,
         layout: Layout,
         proj: Memo<Projection>,
+        secondary_proj: Memo<Projection>,
     ) -> Self {
         // Mouse
         let mouse_chart = node.mouse_chart;
@@ -68,17 +73,34 @@ impl<X: Tick, Y: Tick> State<X, Y> {
             proj.get().svg_to_position(mouse_x, mouse_y)
         });
         let hover_position_x = Memo::new(move |_| hover_position.get().0);
+        let hover_position_y = Memo::new(move |_| hover_position.get().1);
 
         Self {
             pre,
             layout,
             projection: proj,
-            svg_zero: Memo::new(move |_| proj.get().position_to_svg(0.0, 0.0)),
+            secondary_projection: secondary_proj,
+            svg_zero: Memo::new(move |_| proj.get().svg_zero()),
 
             mouse_page: node.mouse_page,
             mouse_chart,
             hover_inner,
             hover_position_x,
+            hover_position_y,
+        }
+    }
+
+    /// Returns state that projects Y values on to the given axis.
+    pub fn on_y_axis(&self, axis: RwSignal<YAxis>) -> Self {
+        let (primary, secondary) = (self.projection, self.secondary_projection);
+        let proj = Memo::new(move |_| match axis.get() {
+            YAxis::Primary => primary.get(),
+            YAxis::Secondary => secondary.get(),
+        });
+        Self {
+            projection: proj,
+            svg_zero: Memo::new(move |_| proj.get().svg_zero()),
+            ..self.clone()
         }
     }
 }
@@ -0,0 +1,169 @@
+use super::{CategoryTicks, Tick, TickGen};
+use std::{cmp::Ordering, fmt, sync::Arc};
+
+/// An ordered set of category labels e.g., regions or services. Use it to create [Category] ticks for a categorical axis.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Sales { region: &'static str, total: f64 }
+/// let regions = Categories::new(["North", "East", "South", "West"]);
+/// let series = Series::new(move |s: &Sales| regions.category(s.region))
+///     .bar(|s: &Sales| s.total);
+/// ```
+/// Data must still be sorted by X i.e., in the order of the categories.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Categories {
+    labels: Arc<[String]>,
+}
+
+/// A single category on an axis. Created from [Categories]. Positioned by its index in the set.
+#[derive(Clone)]
+pub struct Category {
+    set: Categories,
+    index: Option<usize>,
+}
+
+impl Categories {
+    /// Creates a new set of categories. Labels are placed on the axis in the order given.
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Gets the number of categories.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns true if there are no categories.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Gets the category with the given label. Unknown labels are treated as missing data.
+    pub fn category(&self, label: &str) -> Category {
+        let index = self.labels.iter().position(|l| l == label);
+        Category {
+            set: self.clone(),
+            index,
+        }
+    }
+
+    /// Gets the category at the given index. Indexes outside of the set are treated as missing data.
+    pub fn index(&self, index: usize) -> Category {
+        Category {
+            set: self.clone(),
+            index: (index < self.len()).then_some(index),
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Categories {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl Category {
+    /// Gets the category's label. Returns "-" for missing data.
+    pub fn label(&self) -> &str {
+        self.index.map_or("-", |index| &self.set.labels[index])
+    }
+
+    /// Gets the category's index in its set. None for missing data.
+    pub fn get_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Gets the set of categories this category belongs to.
+    pub fn categories(&self) -> &Categories {
+        &self.set
+    }
+}
+
+impl fmt::Debug for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Category").field(&self.label()).finish()
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Note: compares the index only.
+impl PartialEq for Category {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl PartialOrd for Category {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.index, other.index) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            (None, None) => Some(Ordering::Equal),
+            // Missing data is unordered
+            _ => None,
+        }
+    }
+}
+
+impl Tick for Category {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        CategoryTicks::default()
+    }
+
+    fn position(&self) -> f64 {
+        self.index.map_or(f64::NAN, |index| index as f64)
+    }
+
+    fn band_count(first: &Self, last: &Self) -> Option<usize> {
+        let (first, last) = (first.index?, last.index?);
+        Some(last.saturating_sub(first) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category() {
+        let set = Categories::new(["North", "East", "South"]);
+        assert_eq!(set.category("East").label(), "East");
+        assert_eq!(set.category("East").position(), 1.0);
+        // Unknown labels and indexes are missing data
+        assert_eq!(set.category("West").get_index(), None);
+        assert_eq!(set.index(3).label(), "-");
+        assert!(set.index(3).position().is_nan());
+    }
+
+    #[test]
+    fn test_band_count() {
+        let set = Categories::new(["North", "East", "South", "West"]);
+        // Inclusive of the first and last categories
+        assert_eq!(Category::band_count(&set.index(0), &set.index(3)), Some(4));
+        assert_eq!(Category::band_count(&set.index(1), &set.index(1)), Some(1));
+        // Reversed categories saturate to one band
+        assert_eq!(Category::band_count(&set.index(3), &set.index(1)), Some(1));
+        assert_eq!(Category::band_count(&set.index(0), &set.index(9)), None);
+    }
+
+    #[test]
+    fn test_eq_by_index() {
+        let regions = Categories::new(["North", "East"]);
+        let services = Categories::new(["API", "Web"]);
+        assert_eq!(regions.category("East"), regions.index(1));
+        assert_ne!(regions.category("North"), regions.category("East"));
+        // Only the index is compared so categories from different sets with different labels are equal
+        assert_eq!(regions.category("North"), services.category("API"));
+        assert_eq!(regions.index(5), services.category("Mobile"));
+        // Missing data is unordered against categories
+        assert_eq!(regions.index(5).partial_cmp(&regions.index(0)), None);
+    }
+}
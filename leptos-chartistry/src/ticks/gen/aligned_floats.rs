@@ -1,9 +1,12 @@
 use super::{Format, GeneratedTicks, Generator, Span};
+use leptos::prelude::*;
 
-/// Generates f64 ticks. Aligned to nice values (powers of 10).
+/// Generates f64 ticks. Aligned to nice values (powers of 10) or to a set of edges. See [AlignedFloats::with_edges].
 #[derive(Clone, Debug, Default, PartialEq)]
 #[non_exhaustive]
-pub struct AlignedFloats {}
+pub struct AlignedFloats {
+    edges: Option<Signal<Vec<f64>>>,
+}
 
 #[derive(Clone, Debug, PartialEq)]
 struct State {
@@ -19,6 +22,12 @@ impl Generator for AlignedFloats {
         &last: &Self::Tick,
         span: &dyn Span<Self::Tick>,
     ) -> GeneratedTicks<Self::Tick> {
+        if let Some(edges) = self.edges {
+            if let Some(ticks) = edges.with(|edges| Self::generate_edges(first, last, edges, span))
+            {
+                return ticks;
+            }
+        }
         let (scale, count) = Self::find_precision(first, last, span);
         let (scale, ticks) = Self::generate_count(first, last, scale, count);
         let state = State::new(scale);
@@ -27,6 +36,37 @@ impl Generator for AlignedFloats {
 }
 
 impl AlignedFloats {
+    /// Aligns ticks to edges instead of nice values e.g., the boundaries of histogram bins from [HistogramBin::edges](crate::HistogramBin::edges). Edges are thinned out (every 2nd, 3rd, etc.) to fit. The nearest edge either side of the range is included so bins straddling it are labelled. Falls back to nice values if fewer than two edges are found.
+    pub fn with_edges(mut self, edges: impl Into<Signal<Vec<f64>>>) -> Self {
+        self.edges = Some(edges.into());
+        self
+    }
+
+    /// Generates ticks on edges around the range. Returns None if there are too few edges.
+    fn generate_edges(
+        first: f64,
+        last: f64,
+        edges: &[f64],
+        span: &dyn Span<f64>,
+    ) -> Option<GeneratedTicks<f64>> {
+        let mut edges = (edges.iter().copied())
+            .filter(|edge| edge.is_finite())
+            .collect::<Vec<_>>();
+        edges.sort_by(f64::total_cmp);
+        edges.dedup();
+        // Include the nearest edge either side of the range
+        let start = edges.iter().rposition(|&edge| edge <= first).unwrap_or(0);
+        let end =
+            (edges.iter().position(|&edge| edge >= last)).unwrap_or(edges.len().checked_sub(1)?);
+        let edges = edges.get(start..=end).filter(|edges| edges.len() >= 2)?;
+        // Thin out edges to fit
+        let scale = edge_scale(edges);
+        let count = Self::mock_value_count(edges[0], edges[edges.len() - 1], scale, span).max(2);
+        let step = (edges.len() - 1).div_ceil(count - 1);
+        let ticks = edges.iter().step_by(step).copied().collect();
+        Some(GeneratedTicks::new(State::new(scale), ticks))
+    }
+
     /// Returns the scale and count to use for the given range and span
     fn find_precision(first: f64, last: f64, span: &dyn Span<f64>) -> (isize, usize) {
         // Determine scale e.g., are we in the 100s, 10s, 0.1s, etc. Then display one more (-1)
@@ -114,6 +154,21 @@ fn scale10(range: f64) -> isize {
     }
 }
 
+/// Finds the largest scale that shows every edge. Searches up to 6 powers of 10 below the smallest gap between edges.
+fn edge_scale(edges: &[f64]) -> isize {
+    let gap = (edges.windows(2))
+        .map(|pair| pair[1] - pair[0])
+        .fold(f64::INFINITY, f64::min);
+    let coarsest = scale10(gap);
+    (coarsest - 6..=coarsest)
+        .rev()
+        .find(|&scale| {
+            let unit = 10_f64.powi(scale as i32);
+            (edges.iter()).all(|edge| (edge / unit - (edge / unit).round()).abs() < 1e-6)
+        })
+        .unwrap_or(coarsest - 6)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::HorizontalSpan;
@@ -213,6 +268,31 @@ mod tests {
         assert_ticks(1.234_567, 2.987_654, -1, 1, vec!["2.1"]);
     }
 
+    #[test]
+    fn test_generate_edges() {
+        let edges = (0..=10).map(|i| i as f64 * 2.5).collect::<Vec<_>>();
+        let assert_edges = |first: f64, last: f64, width: f64, expected: Vec<&'static str>| {
+            let span = mk_span(width);
+            let gen = AlignedFloats::generate_edges(first, last, &edges, span.as_ref()).unwrap();
+            let ticks = (gen.ticks.iter())
+                .map(|tick| gen.state.format(tick))
+                .collect::<Vec<_>>();
+            assert_eq!(ticks, expected);
+        };
+        // Every edge fits. Nearest edges outside of the range are included
+        let exp = vec![
+            "0.0", "2.5", "5.0", "7.5", "10.0", "12.5", "15.0", "17.5", "20.0", "22.5", "25.0",
+        ];
+        assert_edges(1.25, 23.75, 100.0, exp);
+        // Thinned out
+        assert_edges(1.25, 23.75, 20.0, vec!["0.0", "7.5", "15.0", "22.5"]);
+        // Inside of the range
+        assert_edges(6.0, 9.0, 100.0, vec!["5.0", "7.5", "10.0"]);
+        // Too few edges
+        let span = mk_span(100.0);
+        assert!(AlignedFloats::generate_edges(0.0, 1.0, &[0.5], span.as_ref()).is_none());
+    }
+
     #[test]
     fn test_nil() {
         assert_ticks(f64::NAN, f64::NAN, 1, 3, vec!["-", "-", "-"])
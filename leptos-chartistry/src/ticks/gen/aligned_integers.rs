@@ -0,0 +1,111 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use std::marker::PhantomData;
+
+/// Generates integer ticks. Aligned to nice steps (1, 2, 5, 10, 20, 50, etc.).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct AlignedIntegers<I> {
+    int: PhantomData<I>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State<I>(PhantomData<I>);
+
+/// Finds aligned ticks between first and last (inclusive). Picks the smallest step that `fits` the span.
+fn aligned_steps(
+    first: i128,
+    last: i128,
+    length: f64,
+    fits: impl Fn(&[i128]) -> bool,
+) -> Vec<i128> {
+    // Nice steps: 1, 2, 5, 10, 20, 50, ...
+    let steps = (0..38).flat_map(|scale| [1, 2, 5].map(|step| step * 10_i128.pow(scale)));
+    for step in steps {
+        let start = first.div_euclid(step) * step;
+        let start = if start < first { start + step } else { start };
+        if start > last {
+            break;
+        }
+        let count = (last - start) / step + 1;
+        // Skip steps that couldn't possibly fit
+        if count as f64 > length && count > 1 {
+            continue;
+        }
+        let ticks = (0..count).map(|i| start + i * step).collect::<Vec<_>>();
+        if ticks.len() <= 1 || fits(&ticks) {
+            return ticks;
+        }
+    }
+    vec![first]
+}
+
+macro_rules! impl_aligned_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Generator for AlignedIntegers<$ty> {
+                type Tick = $ty;
+
+                fn generate(
+                    &self,
+                    &first: &Self::Tick,
+                    &last: &Self::Tick,
+                    span: &dyn Span<Self::Tick>,
+                ) -> GeneratedTicks<Self::Tick> {
+                    let state = State::<$ty>(PhantomData);
+                    let fits = |ticks: &[i128]| {
+                        let ticks = ticks.iter().map(|&t| t as $ty).collect::<Vec<_>>();
+                        span.consumed(&state, &ticks) <= span.length()
+                    };
+                    let ticks = aligned_steps(first as i128, last as i128, span.length(), fits);
+                    let ticks = ticks.into_iter().map(|t| t as $ty).collect();
+                    GeneratedTicks::new(state, ticks)
+                }
+            }
+
+            impl Format for State<$ty> {
+                type Tick = $ty;
+
+                fn format(&self, value: &Self::Tick) -> String {
+                    value.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_aligned_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(first: i64, last: i64, width: f64, expected: Vec<&'static str>) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let GeneratedTicks { ticks, state } =
+            AlignedIntegers::<i64>::default().generate(&first, &last, &span);
+        let labels = (ticks.iter())
+            .map(|tick| state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_ticks(0, 4, 100.0, vec!["0", "1", "2", "3", "4"]);
+        // Steps of 2 then 5
+        assert_ticks(0, 4, 3.0, vec!["0", "2", "4"]);
+        assert_ticks(0, 20, 10.0, vec!["0", "5", "10", "15", "20"]);
+        // Aligned to step
+        assert_ticks(3, 21, 10.0, vec!["5", "10", "15", "20"]);
+        assert_ticks(-7, 7, 8.0, vec!["-5", "0", "5"]);
+        // Large numbers
+        assert_ticks(0, 3000, 20.0, vec!["0", "1000", "2000", "3000"]);
+    }
+
+    #[test]
+    fn test_no_range() {
+        assert_ticks(7, 7, 100.0, vec!["7"]);
+        assert_ticks(7, 7, 0.0, vec!["7"]);
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use crate::ticks::Category;
+
+/// Generates [Category] ticks. Emits one label per category between the first and last (inclusive). If the labels don't fit then every Nth category is labelled instead.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct CategoryTicks {}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State;
+
+impl Generator for CategoryTicks {
+    type Tick = Category;
+
+    fn generate(
+        &self,
+        first: &Self::Tick,
+        last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let (Some(first_index), Some(last_index)) = (first.get_index(), last.get_index()) else {
+            return GeneratedTicks::none();
+        };
+        let set = first.categories();
+        let all = (first_index..=last_index)
+            .map(|index| set.index(index))
+            .collect::<Vec<_>>();
+        // Increase the step until our labels fit
+        let mut step = 1;
+        let ticks = loop {
+            let ticks = all.iter().step_by(step).cloned().collect::<Vec<_>>();
+            if ticks.len() <= 1 || span.consumed(&State, &ticks) <= span.length() {
+                break ticks;
+            }
+            step += 1;
+        };
+        GeneratedTicks::new(State, ticks)
+    }
+}
+
+impl Format for State {
+    type Tick = Category;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        value.label().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+    use crate::ticks::Categories;
+
+    fn assert_ticks(first: usize, last: usize, width: f64, expected: Vec<&'static str>) {
+        let set = Categories::new(["North", "East", "South", "West", "Centre"]);
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let GeneratedTicks { ticks, state } =
+            CategoryTicks::default().generate(&set.index(first), &set.index(last), &span);
+        let labels = (ticks.iter())
+            .map(|tick| state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_all_categories() {
+        let exp = vec!["North", "East", "South", "West", "Centre"];
+        assert_ticks(0, 4, 6.0 * 5.0, exp);
+        assert_ticks(1, 2, 100.0, vec!["East", "South"]);
+    }
+
+    #[test]
+    fn test_sampled_categories() {
+        // Every second category
+        assert_ticks(0, 4, 6.0 * 3.0, vec!["North", "South", "Centre"]);
+        // Always show at least one
+        assert_ticks(0, 4, 1.0, vec!["North"]);
+    }
+
+    #[test]
+    fn test_missing_categories() {
+        let set = Categories::new(["North"]);
+        let span = HorizontalSpan::new(1.0, 0, 0.0, 100.0, HorizontalSpan::identity_format());
+        let ticks = CategoryTicks::default().generate(&set.index(0), &set.category("?"), &span);
+        assert!(ticks.ticks.is_empty());
+    }
+}
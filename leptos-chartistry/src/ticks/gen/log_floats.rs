@@ -0,0 +1,179 @@
+use super::{AlignedFloats, Format, GeneratedTicks, Generator, Span};
+
+/// Generates f64 ticks for a [log scale](crate::Scale). Aligned to powers of the base (decades for base 10) with sub-decade ticks (2s and 5s) when there's room.
+///
+/// Ranges that cross zero (e.g., on a [symlog scale](crate::Scale::Symlog)) mirror ticks around zero. Ranges too narrow to include a power of the base fall back to [AlignedFloats].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct LogFloats {
+    base: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State;
+
+impl Default for LogFloats {
+    fn default() -> Self {
+        Self { base: 10.0 }
+    }
+}
+
+impl LogFloats {
+    /// Creates a new generator using powers of 2. Pairs with [Scale::Log2](crate::Scale::Log2).
+    pub fn base2() -> Self {
+        Self { base: 2.0 }
+    }
+
+    /// Multiples of each power to try. From most to least ticks.
+    fn multiples(&self) -> &'static [&'static [f64]] {
+        if self.base == 10.0 {
+            &[&[1.0, 2.0, 5.0], &[1.0, 5.0], &[1.0]]
+        } else {
+            &[&[1.0]]
+        }
+    }
+
+    /// Generates powers (and multiples of) the base between min and max inclusive. Expects positive values.
+    fn magnitudes(&self, min: f64, max: f64, multiples: &[f64]) -> Vec<f64> {
+        let first = min.log(self.base).floor() as i32;
+        let last = max.log(self.base).ceil() as i32;
+        (first..=last)
+            .flat_map(|power| {
+                let at = self.base.powi(power);
+                multiples.iter().map(move |m| m * at)
+            })
+            .filter(|&v| min <= v && v <= max)
+            .collect()
+    }
+
+    /// Generates all candidate ticks between first and last.
+    fn candidates(&self, first: f64, last: f64, multiples: &[f64]) -> Vec<f64> {
+        let mut ticks = Vec::new();
+        // Negative values: mirrored magnitudes
+        if first < 0.0 {
+            let min = if last < 0.0 { -last } else { 1.0 };
+            let mut negative = self.magnitudes(min, -first, multiples);
+            negative.reverse();
+            ticks.extend(negative.into_iter().map(|v| -v));
+        }
+        if first <= 0.0 && 0.0 <= last {
+            ticks.push(0.0);
+        }
+        if last > 0.0 {
+            let min = if first > 0.0 { first } else { 1.0 };
+            ticks.extend(self.magnitudes(min, last, multiples));
+        }
+        ticks
+    }
+}
+
+impl Generator for LogFloats {
+    type Tick = f64;
+
+    fn generate(
+        &self,
+        &first: &Self::Tick,
+        &last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let (first, last) = (first.min(last), first.max(last));
+        let fits = |ticks: &[f64]| span.consumed(&State, ticks) <= span.length();
+        for multiples in self.multiples() {
+            let ticks = self.candidates(first, last, multiples);
+            // Too narrow for a log scale
+            if ticks.len() <= 1 {
+                return AlignedFloats::default().generate(&first, &last, span);
+            }
+            if fits(&ticks) {
+                return GeneratedTicks::new(State, ticks);
+            }
+        }
+        // Sample powers until they fit
+        let powers = self.candidates(first, last, &[1.0]);
+        let ticks = (1..=powers.len())
+            .map(|step| powers.iter().step_by(step).copied().collect::<Vec<_>>())
+            .find(|ticks| ticks.len() <= 1 || fits(ticks))
+            .unwrap_or_default();
+        GeneratedTicks::new(State, ticks)
+    }
+}
+
+impl Format for State {
+    type Tick = f64;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        if value.is_nan() {
+            return "-".to_string();
+        }
+        // Use scientific notation for very large or small values
+        let abs = value.abs();
+        if abs == 0.0 || (1e-4..1e6).contains(&abs) {
+            format!("{value}")
+        } else {
+            format!("{value:e}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(gen: LogFloats, first: f64, last: f64, width: f64, expected: Vec<&str>) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let GeneratedTicks { ticks, state } = gen.generate(&first, &last, &span);
+        let labels = (ticks.iter())
+            .map(|tick| state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_decades() {
+        let exp = vec!["1", "10", "100", "1000", "10000"];
+        assert_ticks(LogFloats::default(), 1.0, 10_000.0, 25.0, exp);
+        // Sub-decades when there's room
+        let exp = vec!["1", "2", "5", "10", "20", "50", "100"];
+        assert_ticks(LogFloats::default(), 1.0, 100.0, 100.0, exp);
+        let exp = vec!["1", "5", "10", "50", "100"];
+        assert_ticks(LogFloats::default(), 1.0, 100.0, 15.0, exp);
+        // Small and large values
+        let exp = vec!["0.001", "0.01", "0.1", "1"];
+        assert_ticks(LogFloats::default(), 0.001, 1.0, 20.0, exp);
+        assert_ticks(
+            LogFloats::default(),
+            1e6,
+            1e7,
+            100.0,
+            vec!["1e6", "2e6", "5e6", "1e7"],
+        );
+    }
+
+    #[test]
+    fn test_sampled() {
+        let exp = vec!["1", "100", "10000"];
+        assert_ticks(LogFloats::default(), 1.0, 10_000.0, 15.0, exp);
+    }
+
+    #[test]
+    fn test_base2() {
+        let exp = vec!["1", "2", "4", "8", "16"];
+        assert_ticks(LogFloats::base2(), 1.0, 16.0, 100.0, exp);
+    }
+
+    #[test]
+    fn test_symlog() {
+        let exp = vec!["-100", "-10", "-1", "0", "1", "10"];
+        assert_ticks(LogFloats::default(), -100.0, 10.0, 24.0, exp);
+    }
+
+    #[test]
+    fn test_narrow_range() {
+        // Falls back to aligned floats
+        let span = HorizontalSpan::new(1.0, 0, 0.0, 20.0, HorizontalSpan::identity_format());
+        let log = LogFloats::default().generate(&2.0, &3.0, &span);
+        let aligned = AlignedFloats::default().generate(&2.0, &3.0, &span);
+        assert_eq!(log.ticks, aligned.ticks);
+    }
+}
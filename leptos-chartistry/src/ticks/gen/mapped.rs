@@ -0,0 +1,148 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use std::sync::Arc;
+
+/// Adapts a tick generator to another tick type by converting ticks to and from the generator's type. Useful for implementing [Tick](crate::Tick) on your own types.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// #[derive(Clone, PartialEq, PartialOrd)]
+/// struct Kelvin(f64);
+///
+/// impl Tick for Kelvin {
+///     fn tick_label_generator() -> impl TickGen<Tick = Self> {
+///         MappedTicks::new(AlignedFloats::default(), |k: &Kelvin| k.0, |f: &f64| Kelvin(*f))
+///     }
+///
+///     fn position(&self) -> f64 {
+///         self.0
+///     }
+/// }
+/// ```
+pub struct MappedTicks<G: Generator, T> {
+    gen: G,
+    to: fn(&T) -> G::Tick,
+    from: fn(&G::Tick) -> T,
+}
+
+impl<G: Generator, T> MappedTicks<G, T> {
+    /// Creates a new mapped generator. `to` converts our ticks to the generator's ticks and `from` converts them back.
+    pub fn new(gen: G, to: fn(&T) -> G::Tick, from: fn(&G::Tick) -> T) -> Self {
+        Self { gen, to, from }
+    }
+}
+
+impl<G, T> Generator for MappedTicks<G, T>
+where
+    G: Generator,
+    G::Tick: 'static,
+    T: 'static,
+{
+    type Tick = T;
+
+    fn generate(
+        &self,
+        first: &Self::Tick,
+        last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let span = MappedSpan {
+            span,
+            to: self.to,
+            from: self.from,
+        };
+        let GeneratedTicks { state, ticks } =
+            self.gen
+                .generate(&(self.to)(first), &(self.to)(last), &span);
+        let ticks = ticks.iter().map(self.from).collect();
+        let state = MappedFormat { state, to: self.to };
+        GeneratedTicks::new(state, ticks)
+    }
+}
+
+struct MappedSpan<'a, T, U> {
+    span: &'a dyn Span<T>,
+    to: fn(&T) -> U,
+    from: fn(&U) -> T,
+}
+
+impl<T, U> Span<U> for MappedSpan<'_, T, U> {
+    fn length(&self) -> f64 {
+        self.span.length()
+    }
+
+    fn consumed(&self, state: &dyn Format<Tick = U>, ticks: &[U]) -> f64 {
+        let state = MappedFormatRef { state, to: self.to };
+        let ticks = ticks.iter().map(self.from).collect::<Vec<_>>();
+        self.span.consumed(&state, &ticks)
+    }
+}
+
+struct MappedFormat<T, U> {
+    state: Arc<dyn Format<Tick = U> + Send + Sync>,
+    to: fn(&T) -> U,
+}
+
+impl<T, U> Format for MappedFormat<T, U> {
+    type Tick = T;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        self.state.format(&(self.to)(value))
+    }
+}
+
+struct MappedFormatRef<'a, T, U> {
+    state: &'a dyn Format<Tick = U>,
+    to: fn(&T) -> U,
+}
+
+impl<T, U> Format for MappedFormatRef<'_, T, U> {
+    type Tick = T;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        self.state.format(&(self.to)(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{AlignedFloats, HorizontalSpan};
+    use super::*;
+    use crate::Tick;
+
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Kelvin(f64);
+
+    impl Tick for Kelvin {
+        fn tick_label_generator() -> impl Generator<Tick = Self> {
+            MappedTicks::new(
+                AlignedFloats::default(),
+                |k: &Kelvin| k.0,
+                |f: &f64| Kelvin(*f),
+            )
+        }
+
+        fn position(&self) -> f64 {
+            self.0
+        }
+    }
+
+    fn labels<T: Tick>(first: T, last: T, width: f64) -> (Vec<T>, Vec<String>) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let GeneratedTicks { state, ticks } =
+            T::tick_label_generator().generate(&first, &last, &span);
+        let labels = ticks.iter().map(|tick| state.format(tick)).collect();
+        (ticks, labels)
+    }
+
+    #[test]
+    fn test_mapped_ticks() {
+        let (ticks, mapped) = labels(Kelvin(0.0), Kelvin(10.0), 20.0);
+        // Same ticks and labels as the generator being mapped
+        let (floats, expected) = labels(0.0, 10.0, 20.0);
+        assert_eq!(mapped, expected);
+        assert_eq!(ticks, floats.into_iter().map(Kelvin).collect::<Vec<_>>());
+        // Span is consumed by the mapped labels
+        assert!(mapped.len() < 11);
+    }
+}
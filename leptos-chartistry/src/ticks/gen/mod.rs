@@ -1,16 +1,27 @@
 mod aligned_floats;
+mod aligned_integers;
+mod categories;
+mod log_floats;
+mod mapped;
 mod span;
 mod timestamps;
 
 pub use aligned_floats::AlignedFloats;
+pub use aligned_integers::AlignedIntegers;
+pub use categories::CategoryTicks;
+pub use log_floats::LogFloats;
+pub use mapped::MappedTicks;
 pub use span::{HorizontalSpan, TickFormatFn, VerticalSpan};
 pub use timestamps::{Period, Timestamps};
 
 use std::sync::Arc;
 
+/// Generates ticks for an axis. Implement this to create your own tick generator. See [AlignedFloats] or [Timestamps] for examples.
 pub trait Generator: Send + Sync {
+    /// Tick type generated.
     type Tick;
 
+    /// Generates ticks between `first` and `last` (inclusive) that fit in the given span. Use [Span::consumed] to check whether a candidate set of ticks fits within [Span::length].
     fn generate(
         &self,
         first: &Self::Tick,
@@ -19,8 +30,11 @@ pub trait Generator: Send + Sync {
     ) -> GeneratedTicks<Self::Tick>;
 }
 
+/// The space available to render ticks in e.g., the width of the X axis.
 pub trait Span<Tick> {
+    /// Available length.
     fn length(&self) -> f64;
+    /// Length consumed by rendering the ticks using the given format.
     fn consumed(&self, state: &dyn Format<Tick = Tick>, ticks: &[Tick]) -> f64;
 }
 
@@ -33,14 +47,18 @@ pub trait Format {
     fn format(&self, value: &Self::Tick) -> String;
 }
 
+/// Ticks produced by a [Generator] along with the format used to render them.
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct GeneratedTicks<Tick> {
+    /// Format used to render each tick.
     pub state: Arc<dyn Format<Tick = Tick> + Send + Sync>,
+    /// Generated ticks.
     pub ticks: Vec<Tick>,
 }
 
 impl<Tick> GeneratedTicks<Tick> {
+    /// Creates a new set of generated ticks from a format and ticks.
     pub fn new(state: impl Format<Tick = Tick> + Send + Sync + 'static, ticks: Vec<Tick>) -> Self {
         GeneratedTicks {
             state: Arc::new(state),
@@ -50,6 +68,7 @@ impl<Tick> GeneratedTicks<Tick> {
 }
 
 impl<Tick: Send + Sync + 'static> GeneratedTicks<Tick> {
+    /// No ticks generated.
     pub fn none() -> GeneratedTicks<Tick> {
         Self::new(NilState(std::marker::PhantomData), vec![])
     }
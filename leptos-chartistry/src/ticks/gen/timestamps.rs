@@ -96,6 +96,12 @@ where
         self
     }
 
+    /// Sets the long format without a time zone. Used for naive timestamps that lack one.
+    pub(crate) fn with_naive_long_format(mut self) -> Self {
+        self.format = Arc::new(NaiveLongFormat);
+        self
+    }
+
     /// Sets a fixed strftime format for timestamps. See [chrono::strftime](https://docs.rs/chrono/0.4.33/chrono/format/strftime/index.html).
     pub fn with_strftime(mut self, format: impl Into<String>) -> Self {
         self.format = Arc::new(StrftimeFormat(format.into()));
@@ -258,6 +264,18 @@ where
     }
 }
 
+struct NaiveLongFormat;
+impl<Tz> TimestampFormat<Tz> for NaiveLongFormat
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn format(&self, period: Period, at: &DateTime<Tz>) -> String {
+        let fmt = period.long_format().trim_end_matches(" %Z");
+        at.format(fmt).to_string()
+    }
+}
+
 struct StrftimeFormat(String);
 impl<Tz> TimestampFormat<Tz> for StrftimeFormat
 where
@@ -322,7 +340,7 @@ impl Period {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct AlignedPeriodRange<Tz: TimeZone> {
+pub(crate) struct AlignedPeriodRange<Tz: TimeZone> {
     next: DateTime<Tz>,
     advance: Period,
     not_after: DateTime<Tz>,
@@ -344,7 +362,8 @@ impl<Tz: TimeZone> Iterator for AlignedPeriodRange<Tz> {
 }
 
 impl Period {
-    fn iter_aligned_range<Tz: TimeZone>(
+    /// Iterates over each period aligned value from `from` (inclusive) to `to` (exclusive).
+    pub(crate) fn iter_aligned_range<Tz: TimeZone>(
         self,
         from: DateTime<Tz>,
         to: DateTime<Tz>,
@@ -362,7 +381,8 @@ impl Period {
         }
     }
 
-    fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    /// Truncates to the start of the period e.g., midnight for a day. Returns None if the local time doesn't exist.
+    pub(crate) fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
         let duration = match self {
             Period::Nanosecond => Duration::nanoseconds(1),
             Period::Microsecond => Duration::microseconds(1),
@@ -1,18 +1,38 @@
+mod category;
 mod gen;
 
+pub use category::{Categories, Category};
 pub use gen::{
-    AlignedFloats, Format as TickFormat, GeneratedTicks, Generator as TickGen, HorizontalSpan,
-    Period, TickFormatFn, Timestamps, VerticalSpan,
+    AlignedFloats, AlignedIntegers, CategoryTicks, Format as TickFormat, GeneratedTicks,
+    Generator as TickGen, HorizontalSpan, LogFloats, MappedTicks, Period, Span as TickSpan,
+    TickFormatFn, Timestamps, VerticalSpan,
 };
 
 use chrono::prelude::*;
+use std::time::Duration;
 
-mod private {
-    pub trait Sealed {}
-}
-
-/// A type that can be used as a tick on an axis. Try to rely on provided implementations.
-pub trait Tick: Clone + PartialEq + PartialOrd + Send + Sync + 'static + private::Sealed {
+/// A type that can be used as a tick on an axis.
+///
+/// Implemented for floats, integers, [categories](Category), [Duration], and chrono's `DateTime`, `NaiveDateTime`, and `NaiveDate`. You can implement it for your own types by describing their position on the axis and how to generate tick labels. [MappedTicks] reuses an existing generator:
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// #[derive(Clone, PartialEq, PartialOrd)]
+/// struct UserId(u32);
+///
+/// impl Tick for UserId {
+///     fn tick_label_generator() -> impl TickGen<Tick = Self> {
+///         MappedTicks::new(AlignedIntegers::<u32>::default(), |id: &UserId| id.0, |id: &u32| UserId(*id))
+///     }
+///
+///     fn position(&self) -> f64 {
+///         self.0 as f64
+///     }
+/// }
+/// ```
+///
+/// For full control implement [TickGen] directly: generate ticks that fit in a [TickSpan] and return them as [GeneratedTicks] with a [TickFormat].
+pub trait Tick: Clone + PartialEq + PartialOrd + Send + Sync + 'static {
     /// Default tick generator used in tick labels.
     fn tick_label_generator() -> impl TickGen<Tick = Self>;
 
@@ -23,10 +43,12 @@ pub trait Tick: Clone + PartialEq + PartialOrd + Send + Sync + 'static + private
 
     /// Maps the tick to a position on the axis. Must be uniform. May return `f64::NAN` for missing data.
     fn position(&self) -> f64;
-}
 
-impl private::Sealed for f64 {}
-impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {}
+    /// Number of bands (e.g., bar widths) to divide the axis into between the first and last ticks inclusive. Returns None to use the number of data points which is the default.
+    fn band_count(_first: &Self, _last: &Self) -> Option<usize> {
+        None
+    }
+}
 
 impl Tick for f64 {
     fn tick_label_generator() -> impl TickGen<Tick = Self> {
@@ -55,3 +77,126 @@ where
         self.timestamp() as f64 + (self.timestamp_subsec_nanos() as f64 / 1e9)
     }
 }
+
+impl Tick for f32 {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        MappedTicks::new(AlignedFloats::default(), |&f| f as f64, |&f| f as f32)
+    }
+
+    fn position(&self) -> f64 {
+        *self as f64
+    }
+}
+
+macro_rules! impl_tick_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Tick for $ty {
+                fn tick_label_generator() -> impl TickGen<Tick = Self> {
+                    AlignedIntegers::<$ty>::default()
+                }
+
+                fn position(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_tick_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl Tick for NaiveDateTime {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        MappedTicks::new(Timestamps::default(), NaiveDateTime::and_utc, |at| {
+            at.naive_utc()
+        })
+    }
+
+    fn tooltip_generator() -> impl TickGen<Tick = Self> {
+        let gen = Timestamps::default().with_naive_long_format();
+        MappedTicks::new(gen, NaiveDateTime::and_utc, |at| at.naive_utc())
+    }
+
+    fn position(&self) -> f64 {
+        self.and_utc().position()
+    }
+}
+
+impl Tick for NaiveDate {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        MappedTicks::new(naive_date_timestamps(), naive_date_to_utc, |at| {
+            at.date_naive()
+        })
+    }
+
+    fn tooltip_generator() -> impl TickGen<Tick = Self> {
+        let gen = naive_date_timestamps().with_naive_long_format();
+        MappedTicks::new(gen, naive_date_to_utc, |at| at.date_naive())
+    }
+
+    fn position(&self) -> f64 {
+        naive_date_to_utc(self).position()
+    }
+}
+
+/// Durations are positioned and labelled in seconds.
+impl Tick for Duration {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        MappedTicks::new(AlignedFloats::default(), Duration::as_secs_f64, |&secs| {
+            Duration::try_from_secs_f64(secs).unwrap_or_default()
+        })
+    }
+
+    fn position(&self) -> f64 {
+        self.as_secs_f64()
+    }
+}
+
+fn naive_date_timestamps() -> Timestamps<Utc> {
+    Timestamps::from_periods([Period::Year, Period::Month, Period::Day])
+}
+
+fn naive_date_to_utc(date: &NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
+/// Formats a value with at most two decimal places. Used for values that aren't formatted by ticks e.g., heatmap cells or Sankey flows.
+pub(crate) fn format_value(value: f64) -> String {
+    let value = format!("{value:.2}");
+    value
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(10.0), "10");
+        assert_eq!(format_value(100.0), "100");
+        assert_eq!(format_value(0.0), "0");
+        assert_eq!(format_value(0.5), "0.5");
+        assert_eq!(format_value(1.0 / 3.0), "0.33");
+        assert_eq!(format_value(-2.125), "-2.12");
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(Duration::from_millis(1500).position(), 1.5);
+        let span = HorizontalSpan::new(1.0, 0, 0.0, 12.0, HorizontalSpan::identity_format());
+        let (first, last) = (Duration::ZERO, Duration::from_secs(10));
+        let GeneratedTicks { state, ticks } =
+            Duration::tick_label_generator().generate(&first, &last, &span);
+        let labels = ticks
+            .iter()
+            .map(|tick| state.format(tick))
+            .collect::<Vec<_>>();
+        // Labelled in seconds
+        assert_eq!(labels, vec!["0", "2", "4", "6", "8", "10"]);
+        assert_eq!(ticks[1], Duration::from_secs(2));
+    }
+}